@@ -0,0 +1,82 @@
+//! AI artifact cleaning on a book-length article
+//!
+//! `single_pass` is the current cleaner; `multi_pass` is the previous implementation
+//! (one full-string pass and allocation per kind of artifact), kept here for comparison.
+//!
+//! Run with `cargo bench --bench cleaner`.
+
+use article_cross_poster::parsers::clean_ai_artifacts;
+use article_cross_poster::parsers::segments::map_prose;
+use criterion::{black_box, criterion_group, criterion_main, Criterion, Throughput};
+
+/// A chapter with the usual mix: mostly plain prose, some typography, emojis and code
+const CHAPTER: &str = "## Chapter\n\n\
+    Error handling in Rust is explicit. Functions that can fail return a `Result`, and \
+    the caller decides what to do with the error. This keeps failure paths visible in \
+    the code, which makes reviews easier and surprises rarer.\n\n\
+    \u{201C}Why not exceptions?\u{201D} you might ask \u{2014} and it\u{2019}s a fair \
+    question\u{2026} The short answer: control flow you can see. \u{1F680}\n\n\
+    ```rust\nfn read(path: &str) -> Result<String, io::Error> {\n    fs::read_to_string(path)\n}\n```\n\n\
+    - Pages 10\u{2013}20 cover the basics\n- The rest covers\u{00A0}libraries\u{200B}\n\n";
+
+/// About 2 MB of markdown, the size of a short book
+fn book() -> String {
+    CHAPTER.repeat(2_000_000 / CHAPTER.len())
+}
+
+fn multi_pass(text: &str) -> String {
+    map_prose(text, |prose| {
+        let mut result = prose.to_string();
+        result = remove_emojis(&result);
+        result = replace_typography(&result);
+        clean_whitespace(&result)
+    })
+}
+
+fn remove_emojis(text: &str) -> String {
+    text.chars()
+        .filter(|&c| {
+            !matches!(c as u32,
+                0x1F600..=0x1F64F | 0x1F300..=0x1F5FF | 0x1F680..=0x1F6FF | 0x1F1E0..=0x1F1FF |
+                0x2600..=0x26FF | 0x2700..=0x27BF | 0xFE00..=0xFE0F | 0x1F900..=0x1F9FF |
+                0x1F018..=0x1F270 | 0x238C..=0x2454 | 0x20D0..=0x20FF
+            )
+        })
+        .collect()
+}
+
+fn replace_typography(text: &str) -> String {
+    text.replace('\u{2014}', "--")
+        .replace('\u{2013}', "-")
+        .replace(['\u{201C}', '\u{201D}'], "\"")
+        .replace(['\u{2018}', '\u{2019}'], "'")
+        .replace('\u{2026}', "...")
+}
+
+fn clean_whitespace(text: &str) -> String {
+    text.chars()
+        .filter(|&c| {
+            !matches!(
+                c,
+                '\u{00A0}' | '\u{200B}' | '\u{200C}' | '\u{200D}' | '\u{FEFF}'
+            )
+        })
+        .collect()
+}
+
+fn bench_cleaner(c: &mut Criterion) {
+    let book = book();
+    assert_eq!(clean_ai_artifacts(&book), multi_pass(&book));
+
+    let mut group = c.benchmark_group("clean_ai_artifacts");
+    group.throughput(Throughput::Bytes(book.len() as u64));
+    group.sample_size(20);
+    group.bench_function("single_pass", |b| {
+        b.iter(|| clean_ai_artifacts(black_box(&book)))
+    });
+    group.bench_function("multi_pass", |b| b.iter(|| multi_pass(black_box(&book))));
+    group.finish();
+}
+
+criterion_group!(benches, bench_cleaner);
+criterion_main!(benches);
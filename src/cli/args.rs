@@ -1,10 +1,42 @@
-use clap::{Parser, Subcommand};
+use anyhow::Result;
+use clap::{Args, Parser, Subcommand};
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+
+use crate::cli::config::DefaultsConfig;
+use crate::llm::SuggestField;
+use crate::models::{Article, MediumLicense};
 
 /// Cross-post articles to dev.to and Medium
 #[derive(Parser, Debug)]
 #[command(name = "article-cross-poster")]
 #[command(about = "Cross-post articles to dev.to and Medium", long_about = None)]
 pub struct Cli {
+    /// CI mode: GitHub Actions annotations, credentials from DEVTO_API_KEY and
+    /// MEDIUM_ACCESS_TOKEN, non-zero exit on any failure, summary in
+    /// $GITHUB_STEP_SUMMARY (enabled automatically when CI=true)
+    #[arg(long, global = true)]
+    pub ci: bool,
+
+    /// Write a results summary to FILE (markdown table, or JSON for .json files)
+    #[arg(long, global = true, value_name = "FILE")]
+    pub summary: Option<PathBuf>,
+
+    /// Always download dev.to articles instead of revalidating the local cache
+    #[arg(long, global = true)]
+    pub no_cache: bool,
+
+    /// Log every HTTP request and response (platform APIs, `doctor` checks and cover
+    /// image downloads; headers and bodies, credentials redacted) to http-debug.log in
+    /// the config directory. The log contains article content and API responses
+    #[arg(long, global = true)]
+    pub debug_http: bool,
+
+    /// Record every platform API exchange to FILE as a JSON fixture that tests can
+    /// replay against a mock server (request headers, and so credentials, are not recorded)
+    #[arg(long, global = true, value_name = "FILE")]
+    pub record_http: Option<PathBuf>,
+
     #[command(subcommand)]
     pub command: Commands,
 }
@@ -13,43 +45,126 @@ pub struct Cli {
 #[derive(Subcommand, Debug)]
 pub enum Commands {
     /// Post an article to one or more platforms
-    Post {
-        /// Path to markdown file or dev.to URL
-        input: String,
-
-        /// Target platforms (comma-separated: devto,medium)
-        #[arg(short = 't', long = "to", value_delimiter = ',', required = true)]
-        platforms: Vec<Platform>,
+    Post(PostArgs),
+
+    /// Create a new article from a template
+    #[command(long_about = "Create a new markdown article with frontmatter.\n\n\
+        The title is quoted, the article is created as a draft (published: false)\n\
+        and dated today. With --template NAME, the body is taken from\n\
+        <config dir>/templates/NAME.md, where {{title}}, {{date}} and {{tags}}\n\
+        are replaced with the article values.")]
+    New {
+        /// Article title
+        title: String,
+
+        /// Tags (comma-separated)
+        #[arg(long, value_delimiter = ',')]
+        tags: Vec<String>,
 
-        /// Apply AI artifact cleaning to content
+        /// Template name from the config templates directory
         #[arg(long)]
-        clean_ai: bool,
+        template: Option<String>,
 
-        /// Override tags from frontmatter (comma-separated)
-        #[arg(long, value_delimiter = ',')]
-        tags: Option<Vec<String>>,
+        /// Output file path (default: title in kebab-case with .md extension)
+        #[arg(short, long)]
+        output: Option<String>,
+    },
 
-        /// Set canonical URL
+    /// Publish all parts of a series in order
+    #[command(long_about = "Publish the parts of a series one after another.\n\n\
+        Takes a directory (all articles sharing one `series` frontmatter value, ordered\n\
+        by `series_part`) or a TOML manifest with `series = \"Name\"` and\n\
+        `parts = [\"a.md\", \"b.md\"]`. Each part is sent with the dev.to series name and\n\
+        links to the parts before it; after a part is published, the previous part's\n\
+        dev.to copy is updated to link forward. If a part fails, the parts published\n\
+        during the run are turned back into drafts on dev.to (Medium posts cannot be\n\
+        unpublished through its API and are reported instead).")]
+    PostSeries(PostSeriesArgs),
+
+    /// Update existing dev.to copies with local changes
+    #[command(
+        long_about = "Update articles already posted to dev.to with their local content.\n\n\
+        Takes a markdown file or a directory. Each article with a recorded dev.to copy\n\
+        (in state.json or its devto_id frontmatter field) is rebuilt and, if the payload\n\
+        changed since the last post, sent as an update. This also refreshes series\n\
+        previous/next links after later parts are published. Medium posts cannot be\n\
+        edited through its API and are skipped."
+    )]
+    Sync {
+        /// Markdown file or directory to sync
+        path: String,
+
+        /// Apply AI artifact cleaning to content (use if the articles were posted with it)
         #[arg(long)]
-        canonical: Option<String>,
+        clean_ai: bool,
 
-        /// Dry run - show what would be posted without actually posting
+        /// Show what would be updated without sending anything
         #[arg(long)]
         dry_run: bool,
-
-        /// Content format for Medium (markdown or html)
-        #[arg(long, default_value = "markdown")]
-        format: ContentFormat,
     },
 
     /// Preview processed content without posting
     Preview {
-        /// Path to markdown file or dev.to URL
+        /// Path to markdown file, dev.to URL, or dev.to draft (`devto-draft:<id>`)
         input: String,
 
         /// Apply AI artifact cleaning to content
         #[arg(long)]
         clean_ai: bool,
+
+        /// Render the article as an HTML page and open it in the default browser
+        #[arg(long)]
+        open: bool,
+
+        /// Render the content with terminal styling and syntax highlighting instead of raw markdown
+        #[arg(long, conflicts_with = "open")]
+        render: bool,
+
+        /// Show exactly what would be sent to these platforms (comma-separated: devto,medium)
+        #[arg(
+            short = 't',
+            long = "to",
+            value_delimiter = ',',
+            conflicts_with = "open"
+        )]
+        platforms: Vec<Platform>,
+
+        /// Content format for Medium and for --output (markdown or html)
+        #[arg(long, default_value = "markdown")]
+        format: ContentFormat,
+
+        /// Write the processed article to a file in --format instead of printing it
+        #[arg(short, long, value_name = "FILE", conflicts_with_all = ["open", "render"])]
+        output: Option<String>,
+    },
+
+    /// Validate articles without publishing
+    #[command(
+        long_about = "Validate a markdown file, or all markdown files in a directory.\n\n\
+        Runs frontmatter parsing and the full per-platform preparation (sanitization,\n\
+        tag handling, format conversion) without any network access. Exits with a\n\
+        non-zero status if any errors are found, so it can be used as a pre-commit hook."
+    )]
+    Validate {
+        /// Markdown file or directory to validate
+        path: String,
+
+        /// Platforms to validate against (comma-separated: devto,medium)
+        #[arg(
+            short = 't',
+            long = "to",
+            value_delimiter = ',',
+            default_value = "devto,medium"
+        )]
+        platforms: Vec<Platform>,
+
+        /// Content format for Medium (markdown or html)
+        #[arg(long, default_value = "markdown")]
+        format: ContentFormat,
+
+        /// Print the report as JSON
+        #[arg(long)]
+        json: bool,
     },
 
     /// List published articles from a platform
@@ -74,6 +189,98 @@ pub enum Commands {
         state: ArticleState,
     },
 
+    /// Download all your articles from a platform as markdown files
+    #[command(
+        long_about = "Download all your articles from a platform as markdown files.\n\n\
+        dev.to: pages through all published and draft articles.\n\
+        Medium: the 10 most recent posts via RSS, or every post (and draft) from\n\
+        Medium's export archive with --archive; HTML is converted to markdown.\n\n\
+        Each article is written to <output>/<title-slug>.md with frontmatter rebuilt\n\
+        from the platform (title, tags, canonical URL, cover image, published flag and\n\
+        date, platform URL and ID), and recorded in the state file so it is not posted\n\
+        to that platform again. Existing files are never overwritten."
+    )]
+    Import {
+        /// Platform to import from
+        #[arg(long = "from", alias = "to", required = true)]
+        platform: Platform,
+
+        /// Directory to write the markdown files to
+        #[arg(short, long, default_value = ".")]
+        output: String,
+
+        /// Medium export archive (.zip), its extracted directory, or one exported post (.html)
+        #[arg(long, value_name = "PATH")]
+        archive: Option<String>,
+
+        /// List the files that would be written without writing anything
+        #[arg(long)]
+        dry_run: bool,
+    },
+
+    /// Download every article from the platforms into a timestamped local archive
+    #[command(
+        long_about = "Download every article from the platforms into a timestamped local archive.\n\n\
+        Creates <output>/backup-<YYYYMMDD-HHMMSS>/ with a markdown file per article under\n\
+        devto/ and medium/ (frontmatter rebuilt from the platform, as with import) and a\n\
+        manifest.json listing each article's ID, URL, title, status, dates, tags, file and\n\
+        SHA-256. dev.to: all published and draft articles. Medium: the 10 most recent posts\n\
+        via RSS, or every post from Medium's export archive with --medium-archive.\n\n\
+        Nothing is recorded in the state file, and a platform that fails does not stop the\n\
+        others; the command exits non-zero if any platform failed."
+    )]
+    Backup {
+        /// Directory to create the backup in
+        #[arg(short, long, default_value = ".")]
+        output: String,
+
+        /// Platforms to back up (comma-separated: devto,medium; default: both)
+        #[arg(long = "from", value_delimiter = ',')]
+        platforms: Vec<Platform>,
+
+        /// Medium export archive (.zip or extracted directory) to back up instead of the RSS feed
+        #[arg(long, value_name = "PATH")]
+        medium_archive: Option<String>,
+    },
+
+    /// Suggest a title, description and tags for an article with an LLM
+    #[command(
+        long_about = "Suggest a title, description and tags for an article with an LLM.\n\n\
+        Sends the article's title, tags and body (up to 12,000 characters) to the\n\
+        OpenAI-compatible chat completions endpoint set in the [llm] section of the\n\
+        config file; without one, nothing is sent. The suggestions are printed; with\n\
+        --accept, the chosen ones are written into the article's frontmatter."
+    )]
+    Suggest {
+        /// Markdown file to suggest metadata for
+        input: String,
+
+        /// Write these suggestions into the frontmatter (comma-separated: title,description,tags)
+        #[arg(long, value_delimiter = ',')]
+        accept: Vec<SuggestField>,
+    },
+
+    /// Print a short announcement (blurb, link and hashtags) for an article
+    #[command(
+        long_about = "Print a short announcement (blurb, link and hashtags) for an article.\n\n\
+        The blurb is the description, or else the first sentences of the body; hashtags\n\
+        come from the tags. If the [llm] section of the config file sets an endpoint, the\n\
+        model writes the blurb and hashtags instead (falling back to the rules on failure).\n\
+        The link is the canonical URL, or else the dev.to or Medium URL from the frontmatter."
+    )]
+    Blurb {
+        /// Markdown file to announce
+        input: String,
+
+        /// Longest post to print, in characters
+        #[arg(long, default_value_t = 280)]
+        max_chars: usize,
+
+        /// Use the rule-based summary even if an LLM is configured
+        #[arg(long)]
+        no_llm: bool,
+    },
+
     /// Fetch a single article by ID
     #[command(long_about = "Fetch a single article by ID.\n\n\
         Only dev.to is supported. Medium does not provide an article fetch API.")]
@@ -86,11 +293,207 @@ pub enum Commands {
         platform: Platform,
     },
 
+    /// Check the setup and print fixes for common problems
+    #[command(
+        long_about = "Check the setup and print fixes for common problems.\n\n\
+        Checks that the config file exists, parses and is readable only by you; that the\n\
+        dev.to and Medium credentials (from the config or DEVTO_API_KEY /\n\
+        MEDIUM_ACCESS_TOKEN) are set and not the example placeholders; that proxy and\n\
+        CA certificate variables are valid; that both platform APIs answer; and that the\n\
+        system clock agrees with theirs. Exits non-zero if any check fails."
+    )]
+    Doctor,
+
     /// Manage configuration
     Config {
         #[command(subcommand)]
         action: ConfigAction,
     },
+
+    /// Queue posts for later publishing
+    #[command(long_about = "Queue posts for later publishing.\n\n\
+        `queue add` stores a post with its target platforms and publish time in\n\
+        queue.json next to the config file. `queue run` publishes the posts that are\n\
+        due, spacing posts to the same platform to stay under rate limits, and logs\n\
+        each result to queue.log. With --daemon it keeps running and checks the queue\n\
+        every --interval seconds. Failed posts are retried up to 3 times.")]
+    Queue {
+        #[command(subcommand)]
+        action: QueueAction,
+    },
+}
+
+/// Publishing queue actions
+#[derive(Subcommand, Debug)]
+pub enum QueueAction {
+    /// Add a post to the queue
+    Add {
+        /// Path to markdown file, dev.to URL, or dev.to draft (`devto-draft:<id>`)
+        input: String,
+
+        /// Target platforms (comma-separated: devto,medium; default: `[defaults] platforms`)
+        #[arg(short = 't', long = "to", value_delimiter = ',')]
+        platforms: Vec<Platform>,
+
+        /// Publish time: YYYY-MM-DD, YYYY-MM-DD HH:MM (local time) or RFC 3339 (default: now)
+        #[arg(long)]
+        at: Option<String>,
+
+        /// Apply AI artifact cleaning to content
+        #[arg(long)]
+        clean_ai: bool,
+
+        /// Don't apply AI artifact cleaning, even if `[defaults] clean_ai` is set
+        #[arg(long, conflicts_with = "clean_ai")]
+        no_clean_ai: bool,
+
+        /// Content format for Medium (markdown or html; default: `[defaults] format` or markdown)
+        #[arg(long)]
+        format: Option<ContentFormat>,
+    },
+
+    /// List queued posts
+    List,
+
+    /// Remove a post from the queue
+    Remove {
+        /// Queue item ID (see `queue list`)
+        id: u32,
+    },
+
+    /// Publish the posts that are due
+    Run {
+        /// Keep running and check the queue periodically
+        #[arg(long)]
+        daemon: bool,
+
+        /// Seconds between queue checks in daemon mode
+        #[arg(long, default_value = "60")]
+        interval: u64,
+    },
+}
+
+/// Arguments for the post command
+#[derive(Args, Debug, Clone)]
+pub struct PostArgs {
+    /// Path to markdown file, dev.to URL, dev.to draft (`devto-draft:<id>`), or a directory
+    /// to post every markdown file in it
+    pub input: String,
+
+    /// Target platforms (comma-separated: devto,medium); overrides the article's
+    /// frontmatter `platforms` / `exclude_platforms` and `[defaults] platforms`
+    #[arg(short = 't', long = "to", value_delimiter = ',')]
+    pub platforms: Vec<Platform>,
+
+    /// Platforms for articles without `platforms` in their frontmatter, when `--to`
+    /// is not given (from `[defaults]`)
+    #[arg(skip)]
+    pub default_platforms: Vec<Platform>,
+
+    /// Apply AI artifact cleaning to content
+    #[arg(long)]
+    pub clean_ai: bool,
+
+    /// Don't apply AI artifact cleaning, even if `[defaults] clean_ai` is set
+    #[arg(long, conflicts_with = "clean_ai")]
+    pub no_clean_ai: bool,
+
+    /// Override tags from frontmatter (comma-separated)
+    #[arg(long, value_delimiter = ',')]
+    pub tags: Option<Vec<String>>,
+
+    /// Set canonical URL
+    #[arg(long)]
+    pub canonical: Option<String>,
+
+    /// Dry run - show what would be posted without actually posting
+    #[arg(long)]
+    pub dry_run: bool,
+
+    /// Content format for Medium (markdown or html; default: `[defaults] format` or markdown)
+    #[arg(long)]
+    pub format: Option<ContentFormat>,
+
+    /// Publish even if an article with the same title or canonical URL already exists,
+    /// or the content is unchanged since the last post
+    #[arg(long)]
+    pub force: bool,
+
+    /// Post every markdown file under INPUT changed since this git revision; articles
+    /// already on dev.to are updated in place, ones already on Medium are left alone
+    #[arg(long, value_name = "REF", conflicts_with_all = ["tags", "canonical"])]
+    pub changed_since: Option<String>,
+
+    /// Continue the last unfinished directory run for INPUT, skipping the files and
+    /// platforms its journal shows as already done
+    #[arg(long, conflicts_with_all = ["tags", "canonical", "changed_since"])]
+    pub resume: bool,
+
+    /// Update this existing dev.to article (PUT) instead of creating a new one;
+    /// only valid with `--to devto` and a single article
+    #[arg(long, value_name = "ARTICLE_ID", conflicts_with_all = ["changed_since", "resume"])]
+    pub update: Option<String>,
+
+    /// License of the Medium post (e.g. cc-40-by, public-domain); overrides the
+    /// frontmatter `medium_license` and `[medium] license`
+    #[arg(long, value_name = "LICENSE")]
+    pub medium_license: Option<MediumLicense>,
+
+    /// Don't notify Medium followers about the post
+    #[arg(long)]
+    pub no_notify_followers: bool,
+
+    /// Also publish machine translations into these languages (comma-separated: de,es),
+    /// each as a separate article with the original as canonical; translations are
+    /// kept next to the article as `<name>.<lang>.md` and reused on later runs
+    #[arg(long, value_name = "LANGS", value_delimiter = ',', conflicts_with_all = ["changed_since", "resume", "update"])]
+    pub translate: Vec<String>,
+
+    /// Write the outcome, URL, ID, time and warnings of every article on every
+    /// platform to FILE as JSON, for downstream automation
+    #[arg(long, value_name = "FILE")]
+    pub manifest: Option<PathBuf>,
+}
+
+impl PostArgs {
+    /// Fill in the AI cleaning and Medium format not given on the command line from
+    /// the config's `[defaults]`, and keep its platforms for articles that don't name any
+    pub fn apply_defaults(&mut self, defaults: &DefaultsConfig) {
+        self.default_platforms = defaults.platforms.clone();
+        self.clean_ai = defaults.clean_ai(self.clean_ai, self.no_clean_ai);
+        self.format = Some(defaults.format(self.format.take()));
+    }
+}
+
+/// Arguments for the post-series command
+#[derive(Args, Debug)]
+pub struct PostSeriesArgs {
+    /// Directory containing the series, or a series manifest (TOML)
+    pub path: String,
+
+    /// Target platforms (comma-separated: devto,medium; default: `[defaults] platforms`)
+    #[arg(short = 't', long = "to", value_delimiter = ',')]
+    pub platforms: Vec<Platform>,
+
+    /// Apply AI artifact cleaning to content
+    #[arg(long)]
+    pub clean_ai: bool,
+
+    /// Don't apply AI artifact cleaning, even if `[defaults] clean_ai` is set
+    #[arg(long, conflicts_with = "clean_ai")]
+    pub no_clean_ai: bool,
+
+    /// Show the publishing order and check every payload without posting
+    #[arg(long)]
+    pub dry_run: bool,
+
+    /// Content format for Medium (markdown or html; default: `[defaults] format` or markdown)
+    #[arg(long)]
+    pub format: Option<ContentFormat>,
+
+    /// Publish even if a part looks like a duplicate or is unchanged since the last post
+    #[arg(long)]
+    pub force: bool,
 }
 
 /// Configuration management actions
@@ -107,7 +510,8 @@ pub enum ConfigAction {
 }
 
 /// Supported platforms
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
 pub enum Platform {
     DevTo,
     Medium,
@@ -122,8 +526,10 @@ pub enum ArticleState {
 }
 
 /// Content format for Medium posts
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
 pub enum ContentFormat {
+    #[default]
     Markdown,
     Html,
 }
@@ -143,6 +549,50 @@ impl std::str::FromStr for Platform {
     }
 }
 
+impl Platform {
+    /// Stable identifier used in state files and manifests
+    pub fn id(&self) -> &'static str {
+        match self {
+            Platform::DevTo => "devto",
+            Platform::Medium => "medium",
+        }
+    }
+
+    /// Where to post an article when `--to` is not given
+    ///
+    /// Its frontmatter `platforms`, else `defaults`, minus its `exclude_platforms`.
+    /// Empty if the article excludes every one of them.
+    pub fn targets_for(article: &Article, defaults: &[Platform]) -> Result<Vec<Platform>> {
+        let platforms = if article.platforms.is_empty() {
+            defaults.to_vec()
+        } else {
+            article
+                .platforms
+                .iter()
+                .map(|id| id.parse::<Platform>().map_err(anyhow::Error::msg))
+                .collect::<Result<Vec<_>>>()?
+        };
+
+        if platforms.is_empty() {
+            anyhow::bail!(
+                "No target platforms for '{}' - pass --to devto,medium, or set `platforms` in \
+                 its frontmatter or in the [defaults] section of the config file",
+                article.title
+            );
+        }
+
+        Ok(platforms
+            .into_iter()
+            .filter(|platform| {
+                !article
+                    .exclude_platforms
+                    .iter()
+                    .any(|id| id == platform.id())
+            })
+            .collect())
+    }
+}
+
 impl std::fmt::Display for Platform {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
@@ -221,6 +671,41 @@ mod tests {
         assert_eq!(Platform::Medium.to_string(), "Medium");
     }
 
+    #[test]
+    fn test_platform_id_roundtrip() {
+        for platform in [Platform::DevTo, Platform::Medium] {
+            assert_eq!(platform.id().parse::<Platform>().unwrap(), platform);
+        }
+    }
+
+    #[test]
+    fn test_platform_targets_for() {
+        let both = [Platform::DevTo, Platform::Medium];
+        let article = Article::new("Title".to_string(), String::new());
+
+        assert_eq!(Platform::targets_for(&article, &both).unwrap(), both);
+        assert!(Platform::targets_for(&article, &[]).is_err());
+
+        let devto_only = article.clone().with_platforms(vec!["devto".to_string()]);
+        assert_eq!(
+            Platform::targets_for(&devto_only, &both).unwrap(),
+            vec![Platform::DevTo]
+        );
+        assert_eq!(
+            Platform::targets_for(&devto_only, &[]).unwrap(),
+            vec![Platform::DevTo]
+        );
+
+        let no_medium = article.with_excluded_platforms(vec!["medium".to_string()]);
+        assert_eq!(
+            Platform::targets_for(&no_medium, &both).unwrap(),
+            vec![Platform::DevTo]
+        );
+        assert!(Platform::targets_for(&no_medium, &[Platform::Medium])
+            .unwrap()
+            .is_empty());
+    }
+
     #[test]
     fn test_content_format_from_str() {
         assert_eq!(
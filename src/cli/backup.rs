@@ -0,0 +1,401 @@
+use anyhow::{Context, Result};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::cli::{content_hash, Platform};
+use crate::parsers::{default_file_name, render_article};
+use crate::platforms::devto::DevToComment;
+use crate::platforms::ImportedArticle;
+
+/// Offline copy of the articles on the platforms (`backup` command)
+///
+/// Written as `<output>/backup-<timestamp>/`, with one directory of markdown files per
+/// platform and a `manifest.json` describing every article and where it came from.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct BackupManifest {
+    /// When the backup was taken
+    pub created_at: DateTime<Utc>,
+
+    /// Version of the tool that wrote the backup
+    pub tool_version: String,
+
+    /// What was downloaded from each platform
+    pub sources: Vec<BackupSource>,
+
+    /// Every backed-up article
+    pub articles: Vec<BackupEntry>,
+}
+
+/// Download result for one platform
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct BackupSource {
+    /// Platform identifier (`devto`, `medium`)
+    pub platform: String,
+
+    /// Where the articles came from (`api`, `rss`, `archive`), or `comments` for
+    /// the articles' discussions
+    pub source: String,
+
+    /// Number of articles (or discussions) written
+    pub article_count: usize,
+
+    /// Why the platform could not be backed up, if it failed
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+/// One backed-up article
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct BackupEntry {
+    /// Platform identifier (`devto`, `medium`)
+    pub platform: String,
+
+    /// Article ID on the platform
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+
+    /// Article URL on the platform
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+
+    pub title: String,
+    pub published: bool,
+
+    /// Publication date (ISO 8601), if published
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub published_at: Option<String>,
+
+    #[serde(default)]
+    pub tags: Vec<String>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub canonical_url: Option<String>,
+
+    /// Markdown file, relative to the backup directory
+    pub file: String,
+
+    /// SHA-256 of the markdown file
+    pub sha256: String,
+
+    /// Comments and reactions file (`<name>.comments.json`), relative to the backup directory
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub discussion: Option<String>,
+
+    /// Why the comments could not be downloaded, if they failed
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub discussion_error: Option<String>,
+}
+
+/// Reader discussion of a backed-up article, stored next to its markdown
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct ArticleDiscussion {
+    /// Article ID on the platform
+    pub article_id: String,
+
+    /// When the comments were downloaded
+    pub fetched_at: DateTime<Utc>,
+
+    /// Public reaction count
+    pub reactions: u64,
+
+    /// Comment count, including replies, as reported by the platform
+    pub comments_count: u64,
+
+    /// Comments, threaded
+    pub comments: Vec<DevToComment>,
+}
+
+impl BackupManifest {
+    /// Start an empty manifest
+    pub fn new() -> Self {
+        Self {
+            created_at: Utc::now(),
+            tool_version: env!("CARGO_PKG_VERSION").to_string(),
+            sources: Vec::new(),
+            articles: Vec::new(),
+        }
+    }
+
+    /// Directory name of the backup (`backup-20250102-030405`)
+    pub fn dir_name(&self) -> String {
+        format!("backup-{}", self.created_at.format("%Y%m%d-%H%M%S"))
+    }
+
+    /// Write a platform's articles as markdown files into `<dir>/<platform id>/`
+    ///
+    /// Articles with the same title get numbered file names instead of overwriting
+    /// each other. Returns the number of files written.
+    pub fn add_platform(
+        &mut self,
+        dir: &Path,
+        platform: &Platform,
+        source: &str,
+        articles: &[ImportedArticle],
+    ) -> Result<usize> {
+        let platform_dir = dir.join(platform.id());
+        fs::create_dir_all(&platform_dir).context(format!(
+            "Failed to create directory {}",
+            platform_dir.display()
+        ))?;
+
+        let mut used = HashSet::new();
+        for imported in articles {
+            let article = &imported.article;
+            let file_name = unique_file_name(&mut used, &article.title);
+            let path = platform_dir.join(&file_name);
+
+            // Keep the date part of an ISO 8601 timestamp
+            let date = imported.published_at.as_deref().and_then(|d| d.get(..10));
+            let content = render_article(article, date);
+            fs::write(&path, &content).context(format!("Failed to write {}", path.display()))?;
+
+            let syndication = &article.syndication;
+            let (url, id) = match platform {
+                Platform::DevTo => (&syndication.devto_url, &syndication.devto_id),
+                Platform::Medium => (&syndication.medium_url, &syndication.medium_id),
+            };
+            self.articles.push(BackupEntry {
+                platform: platform.id().to_string(),
+                id: id.clone(),
+                url: url.clone(),
+                title: article.title.clone(),
+                published: article.published,
+                published_at: imported.published_at.clone(),
+                tags: article.tags.clone(),
+                canonical_url: article.canonical_url.clone(),
+                file: format!("{}/{}", platform.id(), file_name),
+                sha256: content_hash(&content),
+                discussion: None,
+                discussion_error: None,
+            });
+        }
+
+        self.sources.push(BackupSource {
+            platform: platform.id().to_string(),
+            source: source.to_string(),
+            article_count: articles.len(),
+            error: None,
+        });
+
+        Ok(articles.len())
+    }
+
+    /// Write each article's discussion next to its markdown file
+    /// (`<name>.comments.json`)
+    ///
+    /// The articles must already have been added with `add_platform`. `failures` are
+    /// the articles whose comments could not be downloaded (ID and error): each is noted
+    /// on its entry, and the `comments` source is marked failed. Returns the number of
+    /// files written.
+    pub fn add_discussions(
+        &mut self,
+        dir: &Path,
+        platform: &Platform,
+        discussions: &[ArticleDiscussion],
+        failures: &[(String, String)],
+    ) -> Result<usize> {
+        for (article_id, error) in failures {
+            self.entry_mut(platform, article_id)?.discussion_error = Some(error.clone());
+        }
+
+        for discussion in discussions {
+            let entry = self.entry_mut(platform, &discussion.article_id)?;
+            let file = format!("{}.comments.json", entry.file.trim_end_matches(".md"));
+            let path = dir.join(&file);
+            let json = serde_json::to_string_pretty(discussion)
+                .context("Failed to serialize article discussion")?;
+            fs::write(&path, json).context(format!("Failed to write {}", path.display()))?;
+            entry.discussion = Some(file);
+        }
+
+        self.sources.push(BackupSource {
+            platform: platform.id().to_string(),
+            source: "comments".to_string(),
+            article_count: discussions.len(),
+            error: (!failures.is_empty()).then(|| {
+                format!(
+                    "comments of {} article(s) could not be downloaded",
+                    failures.len()
+                )
+            }),
+        });
+
+        Ok(discussions.len())
+    }
+
+    /// The backed-up article with an ID on a platform
+    fn entry_mut(&mut self, platform: &Platform, article_id: &str) -> Result<&mut BackupEntry> {
+        self.articles
+            .iter_mut()
+            .find(|entry| {
+                entry.platform == platform.id() && entry.id.as_deref() == Some(article_id)
+            })
+            .context(format!("Article {} is not part of the backup", article_id))
+    }
+
+    /// Record a platform that could not be backed up
+    pub fn add_failure(&mut self, platform: &Platform, source: &str, error: String) {
+        self.sources.push(BackupSource {
+            platform: platform.id().to_string(),
+            source: source.to_string(),
+            article_count: 0,
+            error: Some(error),
+        });
+    }
+
+    /// Write `manifest.json` into the backup directory
+    pub fn save(&self, dir: &Path) -> Result<PathBuf> {
+        let path = dir.join("manifest.json");
+        let json =
+            serde_json::to_string_pretty(self).context("Failed to serialize backup manifest")?;
+        fs::write(&path, json).context(format!("Failed to write {}", path.display()))?;
+
+        Ok(path)
+    }
+}
+
+impl Default for BackupManifest {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// File name for a title, numbered (`title-2.md`) if already used in this directory
+fn unique_file_name(used: &mut HashSet<String>, title: &str) -> String {
+    let base = default_file_name(title);
+    let stem = base.trim_end_matches(".md").to_string();
+
+    let mut name = base;
+    let mut n = 2;
+    while !used.insert(name.clone()) {
+        name = format!("{}-{}.md", stem, n);
+        n += 1;
+    }
+
+    name
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{Article, Syndication};
+
+    #[test]
+    fn test_backup_writes_files_and_manifest() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let imported = |id: &str| ImportedArticle {
+            article: Article::new("Same Title".to_string(), "Body".to_string()).with_syndication(
+                Syndication {
+                    devto_id: Some(id.to_string()),
+                    ..Default::default()
+                },
+            ),
+            published_at: Some("2025-01-02T03:04:05Z".to_string()),
+            engagement: None,
+        };
+
+        let mut manifest = BackupManifest::new();
+        let count = manifest
+            .add_platform(
+                dir.path(),
+                &Platform::DevTo,
+                "api",
+                &[imported("1"), imported("2")],
+            )
+            .unwrap();
+        manifest.add_failure(&Platform::Medium, "rss", "status 401".to_string());
+        let path = manifest.save(dir.path()).unwrap();
+
+        assert_eq!(count, 2);
+        assert_eq!(manifest.articles[0].file, "devto/same-title.md");
+        assert_eq!(manifest.articles[1].file, "devto/same-title-2.md");
+        assert_eq!(manifest.articles[1].id.as_deref(), Some("2"));
+
+        let written = fs::read_to_string(dir.path().join("devto/same-title-2.md")).unwrap();
+        assert!(written.contains("date: 2025-01-02"));
+        assert_eq!(manifest.articles[1].sha256, content_hash(&written));
+
+        let parsed: BackupManifest =
+            serde_json::from_str(&fs::read_to_string(path).unwrap()).unwrap();
+        assert_eq!(parsed, manifest);
+        assert_eq!(parsed.sources[1].error.as_deref(), Some("status 401"));
+    }
+
+    #[test]
+    fn test_backup_writes_discussions_next_to_markdown() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let imported = |id: &str, title: &str| ImportedArticle {
+            article: Article::new(title.to_string(), "Body".to_string()).with_syndication(
+                Syndication {
+                    devto_id: Some(id.to_string()),
+                    ..Default::default()
+                },
+            ),
+            published_at: None,
+            engagement: None,
+        };
+        let discussion = ArticleDiscussion {
+            article_id: "7".to_string(),
+            fetched_at: Utc::now(),
+            reactions: 12,
+            comments_count: 1,
+            comments: vec![DevToComment {
+                id_code: "abc1".to_string(),
+                created_at: Some("2025-01-03T10:00:00Z".to_string()),
+                body_html: "<p>Nice!</p>".to_string(),
+                user: None,
+                children: Vec::new(),
+            }],
+        };
+
+        let mut manifest = BackupManifest::new();
+        manifest
+            .add_platform(
+                dir.path(),
+                &Platform::DevTo,
+                "api",
+                &[imported("7", "Rust Errors"), imported("8", "Gone")],
+            )
+            .unwrap();
+        let failures = vec![("8".to_string(), "status 404".to_string())];
+        let count = manifest
+            .add_discussions(
+                dir.path(),
+                &Platform::DevTo,
+                std::slice::from_ref(&discussion),
+                &failures,
+            )
+            .unwrap();
+
+        assert_eq!(count, 1);
+        assert_eq!(
+            manifest.articles[0].discussion.as_deref(),
+            Some("devto/rust-errors.comments.json")
+        );
+        assert_eq!(manifest.articles[1].discussion, None);
+        assert_eq!(
+            manifest.articles[1].discussion_error.as_deref(),
+            Some("status 404")
+        );
+        assert_eq!(manifest.sources[1].source, "comments");
+        assert_eq!(
+            manifest.sources[1].error.as_deref(),
+            Some("comments of 1 article(s) could not be downloaded")
+        );
+        let written =
+            fs::read_to_string(dir.path().join("devto/rust-errors.comments.json")).unwrap();
+        let parsed: ArticleDiscussion = serde_json::from_str(&written).unwrap();
+        assert_eq!(parsed, discussion);
+
+        let unknown = ArticleDiscussion {
+            article_id: "9".to_string(),
+            ..discussion
+        };
+        assert!(manifest
+            .add_discussions(dir.path(), &Platform::DevTo, &[unknown], &[])
+            .is_err());
+    }
+}
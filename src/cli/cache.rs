@@ -0,0 +1,94 @@
+use anyhow::{Context, Result};
+use once_cell::sync::OnceCell;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::cli::Config;
+use crate::platforms::devto::CachedArticleResponse;
+
+/// Whether the fetch cache is used; set once at startup
+static CACHE_ENABLED: OnceCell<bool> = OnceCell::new();
+
+/// Turn the fetch cache off for this run (`--no-cache`)
+pub fn init(no_cache: bool) {
+    let _ = CACHE_ENABLED.set(!no_cache);
+}
+
+/// True unless `--no-cache` was given
+pub fn is_enabled() -> bool {
+    CACHE_ENABLED.get().copied().unwrap_or(true)
+}
+
+/// Directory holding cached dev.to article responses (`cache/devto` next to the config file)
+pub fn devto_cache_dir() -> Result<PathBuf> {
+    let config_path = Config::config_path()?;
+    let config_dir = config_path
+        .parent()
+        .context("Failed to get config directory")?;
+
+    Ok(config_dir.join("cache").join("devto"))
+}
+
+/// Cached response for a dev.to article, if caching is on and a readable copy exists
+pub fn load_devto_article(article_id: &str) -> Option<CachedArticleResponse> {
+    if !is_enabled() {
+        return None;
+    }
+
+    read_entry(&devto_cache_dir().ok()?, article_id)
+}
+
+/// Store a dev.to article response (no-op with `--no-cache`)
+pub fn save_devto_article(article_id: &str, response: &CachedArticleResponse) -> Result<()> {
+    if !is_enabled() {
+        return Ok(());
+    }
+
+    write_entry(&devto_cache_dir()?, article_id, response)
+}
+
+/// Path of a cache entry; IDs are reduced to alphanumerics so they stay inside `dir`
+fn entry_path(dir: &Path, article_id: &str) -> PathBuf {
+    let name: String = article_id
+        .chars()
+        .filter(|c| c.is_ascii_alphanumeric())
+        .collect();
+    dir.join(format!("{}.json", name))
+}
+
+fn read_entry(dir: &Path, article_id: &str) -> Option<CachedArticleResponse> {
+    let content = fs::read_to_string(entry_path(dir, article_id)).ok()?;
+    serde_json::from_str(&content).ok()
+}
+
+fn write_entry(dir: &Path, article_id: &str, response: &CachedArticleResponse) -> Result<()> {
+    fs::create_dir_all(dir).context(format!(
+        "Failed to create cache directory {}",
+        dir.display()
+    ))?;
+
+    let json = serde_json::to_string(response).context("Failed to serialize cache entry")?;
+    let path = entry_path(dir, article_id);
+    fs::write(&path, json).context(format!("Failed to write cache file {}", path.display()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cache_entry_round_trip() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let response = CachedArticleResponse {
+            etag: Some("W/\"abc\"".to_string()),
+            last_modified: None,
+            body: "{\"title\":\"Hello\"}".to_string(),
+        };
+
+        assert_eq!(read_entry(dir.path(), "1a2b"), None);
+        write_entry(dir.path(), "1a2b", &response).unwrap();
+
+        assert_eq!(read_entry(dir.path(), "1a2b"), Some(response));
+        assert_eq!(entry_path(dir.path(), "../x"), dir.path().join("x.json"));
+    }
+}
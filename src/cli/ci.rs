@@ -0,0 +1,396 @@
+use anyhow::{Context, Result};
+use chrono::{DateTime, Utc};
+use once_cell::sync::{Lazy, OnceCell};
+use serde::Serialize;
+use std::fmt;
+use std::fs::OpenOptions;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+
+use crate::cli::PlatformRecord;
+
+/// Environment variable holding the dev.to API key (overrides the config file)
+pub const DEVTO_API_KEY_VAR: &str = "DEVTO_API_KEY";
+
+/// Environment variable holding the Medium access token (overrides the config file)
+pub const MEDIUM_TOKEN_VAR: &str = "MEDIUM_ACCESS_TOKEN";
+
+/// Whether CI mode is on; set once at startup
+static CI_MODE: OnceCell<bool> = OnceCell::new();
+
+/// Per-article results collected for the summary file
+static RESULTS: Lazy<Mutex<Vec<RunResult>>> = Lazy::new(|| Mutex::new(Vec::new()));
+
+/// Warnings printed since the last recorded result, attached to the next one
+static PENDING_WARNINGS: Lazy<Mutex<Vec<String>>> = Lazy::new(|| Mutex::new(Vec::new()));
+
+/// Outcome of one article on one platform
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum RunStatus {
+    Published,
+    Updated,
+    Unchanged,
+    Skipped,
+    Failed,
+}
+
+impl fmt::Display for RunStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let label = match self {
+            RunStatus::Published => "published",
+            RunStatus::Updated => "updated",
+            RunStatus::Unchanged => "unchanged",
+            RunStatus::Skipped => "skipped",
+            RunStatus::Failed => "failed",
+        };
+        write!(f, "{}", label)
+    }
+}
+
+/// One row of the results summary
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct RunResult {
+    /// Source file or URL of the article
+    pub article: String,
+
+    /// Platform name
+    pub platform: String,
+
+    /// What happened
+    pub status: RunStatus,
+
+    /// Published URL, when known
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+
+    /// Article ID on the platform, when known
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+
+    /// Error or skip reason
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+
+    /// When the result was recorded
+    pub finished_at: DateTime<Utc>,
+
+    /// Warnings printed while preparing and posting (since the previous result)
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub warnings: Vec<String>,
+}
+
+/// Turn CI mode on if requested or if the environment looks like a CI runner
+pub fn init(requested: bool) {
+    let _ = CI_MODE.set(requested || detect());
+}
+
+/// True if CI mode is on
+pub fn is_enabled() -> bool {
+    CI_MODE.get().copied().unwrap_or(false)
+}
+
+/// Detect a CI runner from the `CI` or `GITHUB_ACTIONS` environment variables
+pub fn detect() -> bool {
+    ["CI", "GITHUB_ACTIONS"].iter().any(|var| {
+        std::env::var(var)
+            .map(|value| value == "true" || value == "1")
+            .unwrap_or(false)
+    })
+}
+
+/// Print a warning: a GitHub Actions annotation in CI mode, a plain line otherwise
+pub fn warning(file: Option<&str>, message: &str) {
+    if let Ok(mut pending) = PENDING_WARNINGS.lock() {
+        pending.push(message.to_string());
+    }
+
+    if is_enabled() {
+        println!("{}", annotation("warning", file, message));
+    } else {
+        eprintln!("⚠️  Warning: {}", message);
+    }
+}
+
+/// Format a GitHub Actions workflow command (`::warning file=posts/a.md::message`)
+pub fn annotation(level: &str, file: Option<&str>, message: &str) -> String {
+    match file {
+        Some(file) => format!(
+            "::{} file={}::{}",
+            level,
+            escape_property(file),
+            escape_data(message)
+        ),
+        None => format!("::{}::{}", level, escape_data(message)),
+    }
+}
+
+/// Escape an annotation message (workflow commands are single-line)
+fn escape_data(value: &str) -> String {
+    value
+        .replace('%', "%25")
+        .replace('\r', "%0D")
+        .replace('\n', "%0A")
+}
+
+/// Escape an annotation property value
+fn escape_property(value: &str) -> String {
+    escape_data(value).replace(':', "%3A").replace(',', "%2C")
+}
+
+/// Add a row to the results summary
+pub fn record(
+    article: &str,
+    platform: impl fmt::Display,
+    status: RunStatus,
+    url: Option<&str>,
+    message: Option<String>,
+) {
+    push_result(article, platform, status, url, None, message);
+}
+
+/// Add a row for an article copy whose publication record (URL and ID) is known
+pub fn record_copy(
+    article: &str,
+    platform: impl fmt::Display,
+    status: RunStatus,
+    record: &PlatformRecord,
+    message: Option<String>,
+) {
+    let id = record.id.as_deref();
+    push_result(article, platform, status, Some(&record.url), id, message);
+}
+
+fn push_result(
+    article: &str,
+    platform: impl fmt::Display,
+    status: RunStatus,
+    url: Option<&str>,
+    id: Option<&str>,
+    message: Option<String>,
+) {
+    let warnings = PENDING_WARNINGS
+        .lock()
+        .map(|mut pending| std::mem::take(&mut *pending))
+        .unwrap_or_default();
+    let result = RunResult {
+        article: article.to_string(),
+        platform: platform.to_string(),
+        status,
+        url: url.map(str::to_string),
+        id: id.map(str::to_string),
+        message,
+        finished_at: Utc::now(),
+        warnings,
+    };
+
+    if let Ok(mut results) = RESULTS.lock() {
+        results.push(result);
+    }
+}
+
+/// Results recorded so far
+pub fn results() -> Vec<RunResult> {
+    RESULTS
+        .lock()
+        .map(|results| results.clone())
+        .unwrap_or_default()
+}
+
+/// Where to write the results summary: the given path or, in CI mode,
+/// the GitHub Actions job summary (`GITHUB_STEP_SUMMARY`)
+pub fn summary_path(explicit: Option<PathBuf>) -> Option<PathBuf> {
+    explicit.or_else(|| {
+        is_enabled()
+            .then(|| std::env::var_os("GITHUB_STEP_SUMMARY"))
+            .flatten()
+            .map(PathBuf::from)
+    })
+}
+
+/// Write the results summary: JSON for `.json` paths, otherwise a markdown table
+///
+/// Markdown is appended, since the GitHub job summary is shared by all steps.
+pub fn write_summary(path: &Path, results: &[RunResult]) -> Result<()> {
+    if path.extension().is_some_and(|ext| ext == "json") {
+        let json = serde_json::to_string_pretty(results).context("Failed to serialize results")?;
+        return std::fs::write(path, json)
+            .context(format!("Failed to write summary file {}", path.display()));
+    }
+
+    let mut file = OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(path)
+        .context(format!("Failed to open summary file {}", path.display()))?;
+    file.write_all(summary_markdown(results).as_bytes())
+        .context(format!("Failed to write summary file {}", path.display()))
+}
+
+/// Render results as a markdown table
+pub fn summary_markdown(results: &[RunResult]) -> String {
+    let mut out = String::from("## Cross-posting results\n\n");
+
+    if results.is_empty() {
+        out.push_str("Nothing was posted.\n");
+        return out;
+    }
+
+    out.push_str("| Article | Platform | Status | Details |\n|---|---|---|---|\n");
+    for result in results {
+        let details = result
+            .url
+            .as_deref()
+            .or(result.message.as_deref())
+            .unwrap_or("");
+        out.push_str(&format!(
+            "| {} | {} | {} | {} |\n",
+            table_cell(&result.article),
+            result.platform,
+            result.status,
+            table_cell(details)
+        ));
+    }
+
+    out
+}
+
+/// Render the results of an interrupted run as plain text for the terminal
+pub fn partial_summary(results: &[RunResult]) -> String {
+    let mut out = String::from("--- INTERRUPTED ---\n");
+
+    if results.is_empty() {
+        out.push_str("Nothing was posted before the interruption.\n");
+        return out;
+    }
+
+    out.push_str("Finished before the interruption:\n");
+    for result in results {
+        let mark = match result.status {
+            RunStatus::Published | RunStatus::Updated => '✓',
+            RunStatus::Unchanged | RunStatus::Skipped => '=',
+            RunStatus::Failed => '✗',
+        };
+        let details = result
+            .url
+            .as_deref()
+            .or(result.message.as_deref())
+            .and_then(|details| details.lines().next())
+            .unwrap_or("");
+        out.push_str(&format!(
+            "  {} {} on {}: {} {}\n",
+            mark, result.article, result.platform, result.status, details
+        ));
+    }
+
+    out
+}
+
+/// Keep a value on one table line
+fn table_cell(value: &str) -> String {
+    value.lines().next().unwrap_or("").replace('|', "\\|")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_annotation() {
+        assert_eq!(
+            annotation("warning", Some("posts/a,b.md"), "Too many tags\nkept 4"),
+            "::warning file=posts/a%2Cb.md::Too many tags%0Akept 4"
+        );
+        assert_eq!(
+            annotation("error", None, "100% broken"),
+            "::error::100%25 broken"
+        );
+    }
+
+    #[test]
+    fn test_summary_markdown() {
+        let results = vec![
+            RunResult {
+                article: "posts/a.md".to_string(),
+                platform: "dev.to".to_string(),
+                status: RunStatus::Published,
+                url: Some("https://dev.to/u/a".to_string()),
+                id: None,
+                message: None,
+                finished_at: Utc::now(),
+                warnings: Vec::new(),
+            },
+            RunResult {
+                article: "posts/b.md".to_string(),
+                platform: "Medium".to_string(),
+                status: RunStatus::Failed,
+                url: None,
+                id: None,
+                message: Some("API error | 401\ndetails".to_string()),
+                finished_at: Utc::now(),
+                warnings: Vec::new(),
+            },
+        ];
+
+        let markdown = summary_markdown(&results);
+
+        assert!(markdown.contains("| posts/a.md | dev.to | published | https://dev.to/u/a |"));
+        assert!(markdown.contains("| posts/b.md | Medium | failed | API error \\| 401 |"));
+    }
+
+    #[test]
+    fn test_partial_summary() {
+        assert!(partial_summary(&[]).contains("Nothing was posted"));
+
+        let results = vec![
+            RunResult {
+                article: "posts/a.md".to_string(),
+                platform: "dev.to".to_string(),
+                status: RunStatus::Published,
+                url: Some("https://dev.to/u/a".to_string()),
+                id: None,
+                message: None,
+                finished_at: Utc::now(),
+                warnings: Vec::new(),
+            },
+            RunResult {
+                article: "posts/a.md".to_string(),
+                platform: "Medium".to_string(),
+                status: RunStatus::Failed,
+                url: None,
+                id: None,
+                message: Some("API error\ndetails".to_string()),
+                finished_at: Utc::now(),
+                warnings: Vec::new(),
+            },
+        ];
+
+        let summary = partial_summary(&results);
+
+        assert!(summary.contains("✓ posts/a.md on dev.to: published https://dev.to/u/a\n"));
+        assert!(summary.contains("✗ posts/a.md on Medium: failed API error\n"));
+        assert!(!summary.contains("details"));
+    }
+
+    #[test]
+    fn test_write_summary_json() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let path = dir.path().join("results.json");
+        let results = vec![RunResult {
+            article: "a.md".to_string(),
+            platform: "dev.to".to_string(),
+            status: RunStatus::Unchanged,
+            url: None,
+            id: None,
+            message: None,
+            finished_at: Utc::now(),
+            warnings: Vec::new(),
+        }];
+
+        write_summary(&path, &results).unwrap();
+
+        let json = std::fs::read_to_string(&path).unwrap();
+        assert!(json.contains("\"status\": \"unchanged\""));
+    }
+}
@@ -1,28 +1,460 @@
 use anyhow::{Context, Result};
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use std::fs;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
+
+use crate::cli::ci::{self, DEVTO_API_KEY_VAR, MEDIUM_TOKEN_VAR};
+use crate::cli::{ContentFormat, Platform};
+use crate::images::{parse_hex_color, CoverStyle};
+use crate::models::{Article, MediumLicense};
+use crate::parsers::{
+    canonical_url, slug_from_path, slugify, CleanerRule, FootnoteStyle, SanitizeOptions,
+    SlugSource, TableStyle, TaskListStyle, TaskMarkers, UtmOptions,
+};
 
 #[cfg(unix)]
 use std::os::unix::fs::PermissionsExt;
 
 /// Configuration structure for the cross-poster tool
-#[derive(Debug, Serialize, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
 pub struct Config {
     pub dev_to: DevToConfig,
     pub medium: MediumConfig,
+
+    #[serde(default, skip_serializing_if = "CleanerConfig::is_empty")]
+    pub cleaner: CleanerConfig,
+
+    #[serde(default, skip_serializing_if = "CoverConfig::is_default")]
+    pub cover: CoverConfig,
+
+    #[serde(default, skip_serializing_if = "CanonicalConfig::is_default")]
+    pub canonical: CanonicalConfig,
+
+    #[serde(default, skip_serializing_if = "FrontmatterConfig::is_default")]
+    pub frontmatter: FrontmatterConfig,
+
+    #[serde(default, skip_serializing_if = "DefaultsConfig::is_default")]
+    pub defaults: DefaultsConfig,
+
+    #[serde(default, skip_serializing_if = "TranslateConfig::is_default")]
+    pub translate: TranslateConfig,
+
+    #[serde(default, skip_serializing_if = "LlmConfig::is_default")]
+    pub llm: LlmConfig,
+
+    #[serde(default, skip_serializing_if = "UtmConfig::is_default")]
+    pub utm: UtmConfig,
+
+    #[serde(default, skip_serializing_if = "PublishConfig::is_default")]
+    pub publish: PublishConfig,
+}
+
+/// Order of the platforms in a `post` run, and which ones need another published first
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq)]
+pub struct PublishConfig {
+    /// Platforms in publish order; targets not listed follow in `--to` order
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub order: Vec<Platform>,
+
+    /// Platforms that are only published once others succeeded, e.g. `medium = ["devto"]`
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub depends_on: HashMap<Platform, Vec<Platform>>,
+}
+
+impl PublishConfig {
+    /// True if nothing differs from the defaults
+    pub fn is_default(&self) -> bool {
+        *self == Self::default()
+    }
+}
+
+/// UTM parameters added to links back to your own site in each syndicated copy
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq)]
+pub struct UtmConfig {
+    /// Tag links (opt-in)
+    #[serde(default)]
+    pub enabled: bool,
+
+    /// Domains whose links are tagged, subdomains included (default: the canonical URL's)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub domains: Vec<String>,
+
+    /// `utm_medium` (default: referral)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub medium: Option<String>,
+
+    /// `utm_source` on dev.to (default: devto)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub devto_source: Option<String>,
+
+    /// `utm_source` on Medium (default: medium)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub medium_source: Option<String>,
+}
+
+impl UtmConfig {
+    /// True if nothing differs from the defaults
+    pub fn is_default(&self) -> bool {
+        *self == Self::default()
+    }
+
+    /// Tagging settings for a platform, if enabled
+    pub fn options(&self, platform: &Platform) -> Option<UtmOptions> {
+        if !self.enabled {
+            return None;
+        }
+
+        let source = match platform {
+            Platform::DevTo => self.devto_source.as_deref().unwrap_or("devto"),
+            Platform::Medium => self.medium_source.as_deref().unwrap_or("medium"),
+        };
+        Some(UtmOptions {
+            domains: self.domains.clone(),
+            source: source.to_string(),
+            medium: self.medium.as_deref().unwrap_or("referral").to_string(),
+        })
+    }
+}
+
+/// LLM used by `suggest` (off unless an endpoint and model are set)
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq)]
+pub struct LlmConfig {
+    /// OpenAI-compatible chat completions URL, e.g. https://api.openai.com/v1/chat/completions
+    /// or http://localhost:11434/v1/chat/completions (Ollama)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub endpoint: Option<String>,
+
+    /// Model name, e.g. gpt-4o-mini or llama3.1
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub model: Option<String>,
+
+    /// Bearer token, if the endpoint needs one
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub api_key: Option<String>,
+}
+
+impl LlmConfig {
+    /// True if nothing differs from the defaults
+    pub fn is_default(&self) -> bool {
+        *self == Self::default()
+    }
+}
+
+/// Machine translation for `post --translate` (DeepL)
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq)]
+pub struct TranslateConfig {
+    /// DeepL API key (free keys end in `:fx`)
+    #[serde(default)]
+    pub api_key: String,
+
+    /// API base URL (default: the DeepL free or paid endpoint, matching the key)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub base_url: Option<String>,
+}
+
+impl TranslateConfig {
+    /// True if nothing differs from the defaults
+    pub fn is_default(&self) -> bool {
+        *self == Self::default()
+    }
+}
+
+/// Defaults for the posting commands (`post`, `post-series`, `queue add`);
+/// flags given on the command line win
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq)]
+pub struct DefaultsConfig {
+    /// Target platforms when `--to` is not given
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub platforms: Vec<Platform>,
+
+    /// Apply AI artifact cleaning unless `--no-clean-ai` is given
+    #[serde(default)]
+    pub clean_ai: bool,
+
+    /// Medium content format when `--format` is not given
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub format: Option<ContentFormat>,
+
+    /// Post articles without `published` in their frontmatter as drafts
+    #[serde(default)]
+    pub draft: bool,
+}
+
+impl DefaultsConfig {
+    /// True if nothing differs from the defaults
+    pub fn is_default(&self) -> bool {
+        *self == Self::default()
+    }
+
+    /// Target platforms: the `--to` flag, else the configured default
+    pub fn platforms(&self, flag: Vec<Platform>) -> Result<Vec<Platform>> {
+        if !flag.is_empty() {
+            return Ok(flag);
+        }
+        if self.platforms.is_empty() {
+            anyhow::bail!(
+                "No target platforms - pass --to devto,medium or set `platforms` in the \
+                 [defaults] section of the config file"
+            );
+        }
+
+        Ok(self.platforms.clone())
+    }
+
+    /// AI cleaning: on with `--clean-ai`, off with `--no-clean-ai`, else the configured default
+    pub fn clean_ai(&self, clean_ai: bool, no_clean_ai: bool) -> bool {
+        clean_ai || (self.clean_ai && !no_clean_ai)
+    }
+
+    /// Medium content format: the `--format` flag, else the configured default, else markdown
+    pub fn format(&self, flag: Option<ContentFormat>) -> ContentFormat {
+        flag.or_else(|| self.format.clone()).unwrap_or_default()
+    }
+}
+
+/// Source file frontmatter updates made while posting
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq)]
+pub struct FrontmatterConfig {
+    /// Write a `slug` field into articles that don't have one on first post
+    #[serde(default)]
+    pub write_slug: bool,
+
+    /// Write `devto_url`/`devto_id` and `medium_url`/`medium_id` after posting
+    #[serde(default)]
+    pub write_urls: bool,
+}
+
+impl FrontmatterConfig {
+    /// True if nothing differs from the defaults
+    pub fn is_default(&self) -> bool {
+        *self == Self::default()
+    }
+}
+
+/// Canonical URL derivation for articles without `canonical_url`
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq)]
+pub struct CanonicalConfig {
+    /// Base URL of the original blog posts (may contain a `{slug}` placeholder)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub base_url: Option<String>,
+
+    /// Slug source: filename (default) or title
+    #[serde(default)]
+    pub slug_from: SlugSource,
+}
+
+impl CanonicalConfig {
+    /// True if nothing differs from the defaults
+    pub fn is_default(&self) -> bool {
+        *self == Self::default()
+    }
+
+    /// Slug for an article: its frontmatter `slug`, or one derived per `slug_from`
+    ///
+    /// Falls back to the title when the slug should come from a file name but the
+    /// article has no source file (or its name yields an empty slug).
+    pub fn slug_for(&self, article: &Article, source: Option<&Path>) -> String {
+        if let Some(slug) = &article.slug {
+            return slug.clone();
+        }
+
+        let slug = match (self.slug_from, source) {
+            (SlugSource::Filename, Some(path)) => slug_from_path(path),
+            _ => String::new(),
+        };
+
+        if slug.is_empty() {
+            slugify(&article.title)
+        } else {
+            slug
+        }
+    }
+
+    /// Derive a canonical URL for an article, if a base URL is configured
+    pub fn derive_url(&self, article: &Article, source: Option<&Path>) -> Result<Option<String>> {
+        let Some(base_url) = &self.base_url else {
+            return Ok(None);
+        };
+
+        if !base_url.starts_with("https://") && !base_url.starts_with("http://") {
+            anyhow::bail!(
+                "Invalid canonical base_url '{}' (must start with http:// or https://)",
+                base_url
+            );
+        }
+
+        let slug = self.slug_for(article, source);
+        if slug.is_empty() {
+            anyhow::bail!(
+                "Cannot derive a canonical URL: no usable slug in '{}'",
+                article.title
+            );
+        }
+
+        Ok(Some(canonical_url(base_url, &slug)))
+    }
+}
+
+/// Generated cover image settings
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq)]
+pub struct CoverConfig {
+    /// Generate a cover for articles without `cover_image` (opt-in)
+    #[serde(default)]
+    pub generate: bool,
+
+    /// Author line shown on the cover
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub author: Option<String>,
+
+    /// Background color (`#rrggbb`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub background: Option<String>,
+
+    /// Title and author color (`#rrggbb`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub text_color: Option<String>,
+
+    /// Accent bar color (`#rrggbb`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub accent_color: Option<String>,
+
+    /// PNG background template (relative paths are resolved against the config directory)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub template: Option<String>,
+}
+
+impl CoverConfig {
+    /// True if nothing differs from the defaults
+    pub fn is_default(&self) -> bool {
+        *self == Self::default()
+    }
+
+    /// Build the cover style, validating colors
+    pub fn style(&self) -> Result<CoverStyle> {
+        let mut style = CoverStyle {
+            author: self.author.clone(),
+            ..CoverStyle::default()
+        };
+
+        if let Some(color) = &self.background {
+            style.background = parse_hex_color(color).context("Invalid cover background")?;
+        }
+        if let Some(color) = &self.text_color {
+            style.text_color = parse_hex_color(color).context("Invalid cover text_color")?;
+        }
+        if let Some(color) = &self.accent_color {
+            style.accent_color = parse_hex_color(color).context("Invalid cover accent_color")?;
+        }
+        if let Some(template) = &self.template {
+            let config_path = Config::config_path()?;
+            let config_dir = config_path
+                .parent()
+                .context("Failed to get config directory")?;
+            style.background_image = Some(config_dir.join(template));
+        }
+
+        Ok(style)
+    }
+}
+
+/// AI cleaner configuration (`--clean-ai`)
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+pub struct CleanerConfig {
+    /// User-defined replacement rules, applied after the built-in ones
+    #[serde(default)]
+    pub rules: Vec<CleanerRule>,
+}
+
+impl CleanerConfig {
+    /// True if no custom rules are configured
+    pub fn is_empty(&self) -> bool {
+        self.rules.is_empty()
+    }
+}
+
+/// True for the example config's credential values and `INSERT...` stand-ins
+pub(crate) fn is_placeholder_credential(value: &str) -> bool {
+    value.contains("your_dev_to_api_key")
+        || value.contains("your_medium_access_token")
+        || value.contains("INSERT")
 }
 
 /// Dev.to platform configuration
-#[derive(Debug, Serialize, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
 pub struct DevToConfig {
     pub api_key: String,
+
+    /// API base URL (default: https://dev.to/api), e.g. for another Forem instance
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub base_url: Option<String>,
+
+    /// Footnote rendering: keep (default), endnotes or inline
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub footnotes: Option<FootnoteStyle>,
+
+    /// Task list rendering: keep (default) or convert
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub task_lists: Option<TaskListStyle>,
+
+    /// Markers for converted task lists (default: ✓ / ✗)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub task_markers: Option<TaskMarkers>,
+
+    /// Footer appended to every article, e.g. "Originally published at {{canonical_url}}"
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub footer: Option<String>,
+
+    /// Turn lone YouTube, Twitter/X and GitHub URLs into liquid tag embeds (default: true)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub embeds: Option<bool>,
 }
 
 /// Medium platform configuration
-#[derive(Debug, Serialize, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
 pub struct MediumConfig {
     pub access_token: String,
+
+    /// API base URL (default: https://api.medium.com/v1)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub base_url: Option<String>,
+
+    /// Base URL of the RSS feeds used to list posts (default: https://medium.com)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub feed_url: Option<String>,
+
+    /// Footnote rendering: endnotes (default), inline or keep
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub footnotes: Option<FootnoteStyle>,
+
+    /// Table handling: html (default), image or keep
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tables: Option<TableStyle>,
+
+    /// Task list rendering: convert (default) or keep
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub task_lists: Option<TaskListStyle>,
+
+    /// Markers for converted task lists (default: ✓ / ✗)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub task_markers: Option<TaskMarkers>,
+
+    /// License for posts without `medium_license` in their frontmatter
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub license: Option<MediumLicense>,
+
+    /// Notify followers for posts without `notify_followers` in their frontmatter
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub notify_followers: Option<bool>,
+
+    /// Footer appended to every post, e.g. "Originally published at {{canonical_url}}"
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub footer: Option<String>,
+
+    /// Put lone YouTube, Twitter/X and GitHub URLs on a bare line so Medium embeds
+    /// them (default: true)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub embeds: Option<bool>,
 }
 
 impl Config {
@@ -35,6 +467,26 @@ impl Config {
         Ok(config_dir.join("config.toml"))
     }
 
+    /// Get the directory holding user-defined article templates
+    pub fn templates_dir() -> Result<PathBuf> {
+        let config_path = Self::config_path()?;
+        let config_dir = config_path
+            .parent()
+            .context("Failed to get config directory")?;
+
+        Ok(config_dir.join("templates"))
+    }
+
+    /// Get the directory where generated cover images are written
+    pub fn covers_dir() -> Result<PathBuf> {
+        let config_path = Self::config_path()?;
+        let config_dir = config_path
+            .parent()
+            .context("Failed to get config directory")?;
+
+        Ok(config_dir.join("covers"))
+    }
+
     /// Initialize config directory and create example config if it doesn't exist
     pub fn init() -> Result<()> {
         let config_path = Self::config_path()?;
@@ -90,46 +542,115 @@ impl Config {
         Ok(())
     }
 
-    /// Load config from file
+    /// Load config from file, with credentials from the environment taking precedence
+    ///
+    /// `DEVTO_API_KEY` and `MEDIUM_ACCESS_TOKEN` override the file's values. In CI mode
+    /// the file is optional, so credentials can come from the environment alone.
     pub fn load() -> Result<Self> {
         let config_path = Self::config_path()?;
-
-        let content = fs::read_to_string(&config_path).context(format!(
-            "Failed to read config file at {}",
-            config_path.display()
-        ))?;
-
-        let config: Config = toml::from_str(&content).context("Failed to parse config file")?;
+        let mut config = if ci::is_enabled() && !config_path.exists() {
+            Self::default()
+        } else {
+            Self::read(&config_path)?
+        };
+        config.apply_credentials(
+            std::env::var(DEVTO_API_KEY_VAR).ok(),
+            std::env::var(MEDIUM_TOKEN_VAR).ok(),
+        );
 
         // Validate that placeholder values haven't been used
-        if config.dev_to.api_key.contains("your_dev_to_api_key")
-            || config.dev_to.api_key.is_empty()
-            || config.dev_to.api_key.contains("INSERT")
-        {
+        if is_placeholder_credential(&config.dev_to.api_key) || config.dev_to.api_key.is_empty() {
             anyhow::bail!(
-                "dev.to API key is not configured. Please edit {} and add your API key.\n\
+                "dev.to API key is not configured. Please edit {} and add your API key \
+                (or set {}).\n\
                 Get your API key from: https://dev.to/settings/extensions",
-                config_path.display()
+                config_path.display(),
+                DEVTO_API_KEY_VAR
             );
         }
 
-        if config
-            .medium
-            .access_token
-            .contains("your_medium_access_token")
+        if is_placeholder_credential(&config.medium.access_token)
             || config.medium.access_token.is_empty()
-            || config.medium.access_token.contains("INSERT")
         {
             anyhow::bail!(
-                "Medium access token is not configured. Please edit {} and add your access token.\n\
+                "Medium access token is not configured. Please edit {} and add your access token \
+                (or set {}).\n\
                 Get your token from: https://medium.com/me/settings/security",
-                config_path.display()
+                config_path.display(),
+                MEDIUM_TOKEN_VAR
             );
         }
 
         Ok(config)
     }
 
+    /// Replace the configured credentials with non-empty values (from the environment)
+    fn apply_credentials(&mut self, devto_api_key: Option<String>, medium_token: Option<String>) {
+        if let Some(key) = devto_api_key.filter(|key| !key.trim().is_empty()) {
+            self.dev_to.api_key = key.trim().to_string();
+        }
+        if let Some(token) = medium_token.filter(|token| !token.trim().is_empty()) {
+            self.medium.access_token = token.trim().to_string();
+        }
+    }
+
+    /// Load config for offline use (dry runs, validation) without requiring credentials
+    ///
+    /// Returns the default settings if no config file exists yet.
+    pub fn load_settings() -> Result<Self> {
+        let config_path = Self::config_path()?;
+
+        if !config_path.exists() {
+            return Ok(Self::default());
+        }
+
+        Self::read(&config_path)
+    }
+
+    /// Read and parse a config file
+    pub(crate) fn read(config_path: &Path) -> Result<Self> {
+        let content = fs::read_to_string(config_path).context(format!(
+            "Failed to read config file at {}",
+            config_path.display()
+        ))?;
+
+        toml::from_str(&content).context("Failed to parse config file")
+    }
+
+    /// Content transform settings for a platform
+    pub fn sanitize_options(&self, platform: &Platform) -> SanitizeOptions {
+        match platform {
+            // dev.to renders tables natively
+            Platform::DevTo => SanitizeOptions {
+                footnotes: self.dev_to.footnotes,
+                tables: None,
+                task_lists: self.dev_to.task_lists,
+                task_markers: self.dev_to.task_markers.clone(),
+                link_base: self.canonical.base_url.clone(),
+                footer: self.dev_to.footer.clone(),
+                utm: self.utm.options(platform),
+                embeds: self.dev_to.embeds,
+            },
+            Platform::Medium => SanitizeOptions {
+                footnotes: self.medium.footnotes,
+                tables: self.medium.tables,
+                task_lists: self.medium.task_lists,
+                task_markers: self.medium.task_markers.clone(),
+                link_base: self.canonical.base_url.clone(),
+                footer: self.medium.footer.clone(),
+                utm: self.utm.options(platform),
+                embeds: self.medium.embeds,
+            },
+        }
+    }
+
+    /// Fill in the Medium license and follower notification from `[medium]` where
+    /// the article's frontmatter leaves them unset
+    pub fn apply_medium_defaults(&self, article: &mut Article) {
+        article.medium_license = article.medium_license.or(self.medium.license);
+        article.notify_followers = article.notify_followers.or(self.medium.notify_followers);
+    }
+
     /// Display the current config (with sensitive data masked)
     pub fn show() -> Result<()> {
         let _config = Self::load()?;
@@ -155,10 +676,241 @@ impl Config {
         Config {
             dev_to: DevToConfig {
                 api_key: "your_dev_to_api_key_here".to_string(),
+                base_url: None,
+                footnotes: None,
+                task_lists: None,
+                task_markers: None,
+                footer: None,
+                embeds: None,
             },
             medium: MediumConfig {
                 access_token: "your_medium_access_token_here".to_string(),
+                base_url: None,
+                feed_url: None,
+                footnotes: Some(FootnoteStyle::Endnotes),
+                tables: Some(TableStyle::Html),
+                task_lists: Some(TaskListStyle::Convert),
+                task_markers: None,
+                license: None,
+                notify_followers: None,
+                footer: None,
+                embeds: None,
             },
+            cleaner: CleanerConfig::default(),
+            cover: CoverConfig::default(),
+            canonical: CanonicalConfig::default(),
+            frontmatter: FrontmatterConfig::default(),
+            defaults: DefaultsConfig::default(),
+            translate: TranslateConfig::default(),
+            llm: LlmConfig::default(),
+            utm: UtmConfig::default(),
+            publish: PublishConfig::default(),
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_apply_credentials() {
+        let mut config: Config = toml::from_str(
+            "[dev_to]\napi_key = \"file-key\"\n\n[medium]\naccess_token = \"file-token\"\n",
+        )
+        .unwrap();
+
+        config.apply_credentials(Some(" env-key\n".to_string()), Some(String::new()));
+
+        assert_eq!(config.dev_to.api_key, "env-key");
+        assert_eq!(config.medium.access_token, "file-token");
+    }
+
+    #[test]
+    fn test_apply_medium_defaults() {
+        let config: Config = toml::from_str(
+            "[dev_to]\napi_key = \"key\"\n\n[medium]\naccess_token = \"token\"\n\
+             license = \"cc-40-by\"\nnotify_followers = false\n",
+        )
+        .unwrap();
+
+        let mut article = Article::new("Title".to_string(), "Body".to_string())
+            .with_medium_license(MediumLicense::PublicDomain);
+        config.apply_medium_defaults(&mut article);
+
+        assert_eq!(article.medium_license, Some(MediumLicense::PublicDomain));
+        assert_eq!(article.notify_followers, Some(false));
+    }
+
+    #[test]
+    fn test_defaults_config() {
+        let config: Config = toml::from_str(
+            "[dev_to]\napi_key = \"key\"\n\n[medium]\naccess_token = \"token\"\n\n\
+             [defaults]\nplatforms = [\"devto\", \"medium\"]\nclean_ai = true\n\
+             format = \"html\"\ndraft = true\n",
+        )
+        .unwrap();
+        let defaults = &config.defaults;
+
+        assert_eq!(
+            defaults.platforms(Vec::new()).unwrap(),
+            vec![Platform::DevTo, Platform::Medium]
+        );
+        assert_eq!(
+            defaults.platforms(vec![Platform::Medium]).unwrap(),
+            vec![Platform::Medium]
+        );
+        assert!(defaults.clean_ai(false, false));
+        assert!(!defaults.clean_ai(false, true));
+        assert_eq!(defaults.format(None), ContentFormat::Html);
+        assert_eq!(
+            defaults.format(Some(ContentFormat::Markdown)),
+            ContentFormat::Markdown
+        );
+        assert!(defaults.draft);
+
+        let none = DefaultsConfig::default();
+        assert!(none.platforms(Vec::new()).is_err());
+        assert!(!none.clean_ai(false, false));
+        assert_eq!(none.format(None), ContentFormat::Markdown);
+    }
+
+    #[test]
+    fn test_footnotes_setting_is_optional() {
+        let config: Config = toml::from_str(
+            "[dev_to]\napi_key = \"key\"\n\n[medium]\naccess_token = \"token\"\nfootnotes = \"inline\"\n",
+        )
+        .unwrap();
+
+        assert_eq!(config.sanitize_options(&Platform::DevTo).footnotes, None);
+        assert_eq!(
+            config.sanitize_options(&Platform::Medium).footnotes,
+            Some(FootnoteStyle::Inline)
+        );
+        assert_eq!(config.sanitize_options(&Platform::Medium).tables, None);
+    }
+
+    #[test]
+    fn test_task_markers_setting() {
+        let config: Config = toml::from_str(
+            "[dev_to]\napi_key = \"key\"\ntask_lists = \"convert\"\n\
+             task_markers = { checked = \"[x]\", unchecked = \"[ ]\" }\n\n\
+             [medium]\naccess_token = \"token\"\n",
+        )
+        .unwrap();
+
+        let options = config.sanitize_options(&Platform::DevTo);
+        assert_eq!(options.task_lists, Some(TaskListStyle::Convert));
+        assert_eq!(options.task_markers.unwrap().unchecked, "[ ]");
+    }
+
+    #[test]
+    fn test_cleaner_rules_setting() {
+        let config: Config = toml::from_str(
+            "[dev_to]\napi_key = \"key\"\n\n[medium]\naccess_token = \"token\"\n\n\
+             [[cleaner.rules]]\npattern = \"Acme\"\nreplace = \"ACME\"\n\n\
+             [[cleaner.rules]]\npattern = \"(?i)delve\"\nreplace = \"explore\"\n",
+        )
+        .unwrap();
+
+        assert_eq!(config.cleaner.rules.len(), 2);
+        assert_eq!(config.cleaner.rules[1].replace, "explore");
+
+        let without: Config =
+            toml::from_str("[dev_to]\napi_key = \"key\"\n\n[medium]\naccess_token = \"token\"\n")
+                .unwrap();
+        assert!(without.cleaner.is_empty());
+    }
+
+    #[test]
+    fn test_utm_config() {
+        let config: Config = toml::from_str(
+            "[dev_to]\napi_key = \"key\"\n\n[medium]\naccess_token = \"token\"\n\n\
+             [utm]\nenabled = true\ndomains = [\"myblog.dev\"]\nmedium_source = \"medium-copy\"\n",
+        )
+        .unwrap();
+
+        let devto = config.sanitize_options(&Platform::DevTo).utm.unwrap();
+        assert_eq!(devto.domains, vec!["myblog.dev"]);
+        assert_eq!(
+            (devto.source.as_str(), devto.medium.as_str()),
+            ("devto", "referral")
+        );
+        let medium = config.sanitize_options(&Platform::Medium).utm.unwrap();
+        assert_eq!(medium.source, "medium-copy");
+
+        assert!(Config::default()
+            .sanitize_options(&Platform::DevTo)
+            .utm
+            .is_none());
+    }
+
+    #[test]
+    fn test_cover_config() {
+        let config: Config = toml::from_str(
+            "[dev_to]\napi_key = \"key\"\n\n[medium]\naccess_token = \"token\"\n\n\
+             [cover]\ngenerate = true\nauthor = \"Jane\"\nbackground = \"#000000\"\n",
+        )
+        .unwrap();
+
+        assert!(config.cover.generate);
+        let style = config.cover.style().unwrap();
+        assert_eq!(style.author.as_deref(), Some("Jane"));
+        assert_eq!(style.background, parse_hex_color("#000000").unwrap());
+
+        let invalid = CoverConfig {
+            accent_color: Some("blue".to_string()),
+            ..Default::default()
+        };
+        assert!(invalid.style().is_err());
+    }
+
+    #[test]
+    fn test_canonical_config() {
+        let config: Config = toml::from_str(
+            "[dev_to]\napi_key = \"key\"\n\n[medium]\naccess_token = \"token\"\n\n\
+             [canonical]\nbase_url = \"https://myblog.dev/posts/\"\n",
+        )
+        .unwrap();
+        let file = Path::new("drafts/rust-tips.md");
+        let article = Article::new("Ten Rust Tips".to_string(), String::new());
+
+        assert_eq!(
+            config.canonical.derive_url(&article, Some(file)).unwrap(),
+            Some("https://myblog.dev/posts/rust-tips".to_string())
+        );
+        assert_eq!(
+            config.canonical.derive_url(&article, None).unwrap(),
+            Some("https://myblog.dev/posts/ten-rust-tips".to_string())
+        );
+
+        // A frontmatter slug always wins
+        let with_slug = article.clone().with_slug("tips".to_string());
+        assert_eq!(
+            config.canonical.derive_url(&with_slug, Some(file)).unwrap(),
+            Some("https://myblog.dev/posts/tips".to_string())
+        );
+
+        let by_title = CanonicalConfig {
+            slug_from: SlugSource::Title,
+            ..config.canonical.clone()
+        };
+        assert_eq!(
+            by_title.derive_url(&article, Some(file)).unwrap(),
+            Some("https://myblog.dev/posts/ten-rust-tips".to_string())
+        );
+
+        assert_eq!(
+            CanonicalConfig::default()
+                .derive_url(&article, Some(file))
+                .unwrap(),
+            None
+        );
+
+        let invalid = CanonicalConfig {
+            base_url: Some("myblog.dev".to_string()),
+            ..Default::default()
+        };
+        assert!(invalid.derive_url(&article, None).is_err());
+    }
+}
@@ -0,0 +1,136 @@
+use anyhow::{Context, Result};
+use std::collections::BTreeSet;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+/// Markdown files under `path` that changed since `since` (a commit, tag or branch)
+///
+/// `since` is compared with the working tree, so committed and uncommitted changes both
+/// count, as do new untracked files. Deleted files are left out. Paths are absolute.
+pub fn changed_markdown_files(path: &Path, since: &str) -> Result<Vec<PathBuf>> {
+    let path = path
+        .canonicalize()
+        .context(format!("Invalid or inaccessible path: {}", path.display()))?;
+    let dir = if path.is_dir() {
+        path.as_path()
+    } else {
+        path.parent().context("Failed to get parent directory")?
+    };
+
+    if since.starts_with('-') {
+        anyhow::bail!("Invalid git revision: {}", since);
+    }
+
+    let root = PathBuf::from(git(dir, &["rev-parse", "--show-toplevel"])?.trim());
+    git(
+        &root,
+        &[
+            "rev-parse",
+            "--verify",
+            "--quiet",
+            &format!("{}^{{commit}}", since),
+        ],
+    )
+    .context(format!("Unknown git revision: {}", since))?;
+
+    let pathspec = path.display().to_string();
+    let changed = git(
+        &root,
+        &[
+            "diff",
+            "--name-only",
+            "-z",
+            "--diff-filter=d",
+            since,
+            "--",
+            &pathspec,
+        ],
+    )?;
+    let untracked = git(
+        &root,
+        &[
+            "ls-files",
+            "-z",
+            "--others",
+            "--exclude-standard",
+            "--",
+            &pathspec,
+        ],
+    )?;
+
+    let files: BTreeSet<PathBuf> = changed
+        .split('\0')
+        .chain(untracked.split('\0'))
+        .filter(|name| name.ends_with(".md") || name.ends_with(".markdown"))
+        .map(|name| root.join(name))
+        .collect();
+
+    Ok(files.into_iter().collect())
+}
+
+/// Run a git command in `dir` and return its standard output
+fn git(dir: &Path, args: &[&str]) -> Result<String> {
+    let output = Command::new("git")
+        .arg("-C")
+        .arg(dir)
+        .args(args)
+        .output()
+        .context("Failed to run git - is it installed?")?;
+
+    if !output.status.success() {
+        anyhow::bail!(
+            "git {} failed: {}",
+            args.first().unwrap_or(&""),
+            String::from_utf8_lossy(&output.stderr).trim()
+        );
+    }
+
+    String::from_utf8(output.stdout).context("git output is not valid UTF-8")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use tempfile::TempDir;
+
+    fn run(dir: &Path, args: &[&str]) {
+        let status = Command::new("git")
+            .arg("-C")
+            .arg(dir)
+            .args(["-c", "user.name=Test", "-c", "user.email=test@example.com"])
+            .args(args)
+            .output()
+            .unwrap()
+            .status;
+        assert!(status.success(), "git {:?} failed", args);
+    }
+
+    #[test]
+    fn test_changed_markdown_files() {
+        let repo = TempDir::new().unwrap();
+        let root = repo.path().canonicalize().unwrap();
+        let posts = root.join("posts");
+        fs::create_dir(&posts).unwrap();
+        fs::write(posts.join("old.md"), "old").unwrap();
+        fs::write(posts.join("edited.md"), "v1").unwrap();
+        fs::write(posts.join("gone.md"), "bye").unwrap();
+
+        run(&root, &["init", "-q"]);
+        run(&root, &["add", "."]);
+        run(&root, &["commit", "-q", "-m", "initial"]);
+        run(&root, &["tag", "v1"]);
+
+        fs::write(posts.join("edited.md"), "v2").unwrap();
+        fs::write(posts.join("new.md"), "new").unwrap();
+        fs::write(posts.join("notes.txt"), "not markdown").unwrap();
+        fs::remove_file(posts.join("gone.md")).unwrap();
+        fs::write(root.join("README.md"), "outside posts").unwrap();
+
+        let files = changed_markdown_files(&posts, "v1").unwrap();
+
+        assert_eq!(files, vec![posts.join("edited.md"), posts.join("new.md")]);
+        assert!(changed_markdown_files(&posts, "no-such-tag").is_err());
+        assert!(changed_markdown_files(&posts, "--output=x").is_err());
+    }
+}
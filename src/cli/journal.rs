@@ -0,0 +1,179 @@
+use anyhow::{Context, Result};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::PathBuf;
+
+use crate::cli::{Config, Platform};
+
+/// Progress of a batch `post` run (directory input), for `post --resume`
+///
+/// Stored in `journal.json` next to the config file and saved after every file,
+/// so an interrupted run leaves a record of what is already done. The journal is
+/// removed once a run finishes without failures.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct Journal {
+    /// Directory (canonical path) the batch was started for
+    pub input: String,
+
+    /// Files of the batch (canonical paths), in posting order
+    pub files: Vec<String>,
+
+    /// When the batch was first started
+    pub started_at: DateTime<Utc>,
+
+    /// Outcomes keyed by file, then by platform identifier (`devto`, `medium`)
+    #[serde(default)]
+    pub outcomes: BTreeMap<String, BTreeMap<String, JournalEntry>>,
+}
+
+/// Outcome of one file on one platform
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct JournalEntry {
+    /// True once the platform confirmed the article (published, updated or unchanged)
+    pub done: bool,
+
+    /// Error of the last failed attempt
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+
+    /// When the outcome was recorded
+    pub at: DateTime<Utc>,
+}
+
+impl Journal {
+    /// Start a journal for a batch
+    pub fn new(input: String, files: Vec<String>) -> Self {
+        Self {
+            input,
+            files,
+            started_at: Utc::now(),
+            outcomes: BTreeMap::new(),
+        }
+    }
+
+    /// Get the path to the journal file (next to the config file)
+    pub fn journal_path() -> Result<PathBuf> {
+        let config_path = Config::config_path()?;
+        let config_dir = config_path
+            .parent()
+            .context("Failed to get config directory")?;
+
+        Ok(config_dir.join("journal.json"))
+    }
+
+    /// Load the journal of the last unfinished batch, if there is one
+    pub fn load() -> Result<Option<Self>> {
+        let journal_path = Self::journal_path()?;
+
+        if !journal_path.exists() {
+            return Ok(None);
+        }
+
+        let content = fs::read_to_string(&journal_path).context(format!(
+            "Failed to read journal at {}",
+            journal_path.display()
+        ))?;
+
+        serde_json::from_str(&content).map(Some).context(format!(
+            "Failed to parse journal at {}",
+            journal_path.display()
+        ))
+    }
+
+    /// Save the journal (temporary file + rename, like the state file)
+    pub fn save(&self) -> Result<()> {
+        let journal_path = Self::journal_path()?;
+        let journal_dir = journal_path
+            .parent()
+            .context("Failed to get config directory")?;
+
+        if !journal_dir.exists() {
+            fs::create_dir_all(journal_dir).context("Failed to create config directory")?;
+        }
+
+        let json = serde_json::to_string_pretty(self).context("Failed to serialize journal")?;
+        let tmp_path = journal_path.with_extension("json.tmp");
+
+        fs::write(&tmp_path, json).context("Failed to write journal")?;
+        fs::rename(&tmp_path, &journal_path).context("Failed to replace journal")?;
+
+        Ok(())
+    }
+
+    /// Delete the journal file after a successful batch
+    pub fn remove() -> Result<()> {
+        let journal_path = Self::journal_path()?;
+
+        if journal_path.exists() {
+            fs::remove_file(&journal_path).context(format!(
+                "Failed to remove journal at {}",
+                journal_path.display()
+            ))?;
+        }
+
+        Ok(())
+    }
+
+    /// Record the outcome of a file on a platform
+    pub fn record(&mut self, file: &str, platform: &Platform, error: Option<String>) {
+        self.outcomes.entry(file.to_string()).or_default().insert(
+            platform.id().to_string(),
+            JournalEntry {
+                done: error.is_none(),
+                error,
+                at: Utc::now(),
+            },
+        );
+    }
+
+    /// True if the file is confirmed on the platform
+    pub fn is_done(&self, file: &str, platform: &Platform) -> bool {
+        self.outcomes
+            .get(file)
+            .and_then(|platforms| platforms.get(platform.id()))
+            .is_some_and(|entry| entry.done)
+    }
+
+    /// The given platforms a file still has to be posted to
+    pub fn pending(&self, file: &str, platforms: &[Platform]) -> Vec<Platform> {
+        platforms
+            .iter()
+            .filter(|platform| !self.is_done(file, platform))
+            .cloned()
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_journal_pending() {
+        let mut journal = Journal::new(
+            "/posts".to_string(),
+            vec!["/posts/a.md".to_string(), "/posts/b.md".to_string()],
+        );
+        let platforms = [Platform::DevTo, Platform::Medium];
+
+        journal.record("/posts/a.md", &Platform::DevTo, None);
+        journal.record(
+            "/posts/a.md",
+            &Platform::Medium,
+            Some("status 429".to_string()),
+        );
+
+        assert_eq!(
+            journal.pending("/posts/a.md", &platforms),
+            vec![Platform::Medium]
+        );
+        assert_eq!(journal.pending("/posts/b.md", &platforms), platforms);
+
+        let json = serde_json::to_string(&journal).unwrap();
+        let parsed: Journal = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed, journal);
+        assert!(!parsed.is_done("/posts/a.md", &Platform::Medium));
+    }
+}
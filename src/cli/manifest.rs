@@ -0,0 +1,212 @@
+use anyhow::{Context, Result};
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use std::path::Path;
+
+use crate::cli::ci::{RunResult, RunStatus};
+use crate::cli::Platform;
+
+/// Version of the manifest layout; bumped only for changes that break readers
+pub const MANIFEST_SCHEMA_VERSION: u32 = 1;
+
+/// Results of a `post` run for downstream automation (`post --manifest`)
+///
+/// Fields are only ever added within a schema version. Optional values are `null`
+/// rather than left out, so readers can rely on every key being present.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct PublishManifest {
+    /// Always `MANIFEST_SCHEMA_VERSION`
+    pub schema_version: u32,
+
+    /// When the run started
+    pub started_at: DateTime<Utc>,
+
+    /// When the manifest was written
+    pub finished_at: DateTime<Utc>,
+
+    /// True if the command succeeded, was not interrupted and no platform of any
+    /// article failed
+    pub success: bool,
+
+    /// Articles in the order they were processed
+    pub articles: Vec<ManifestArticle>,
+}
+
+/// One article of a run
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ManifestArticle {
+    /// Source file or URL, as given to `post`
+    pub source: String,
+
+    /// Outcome per platform, in publish order
+    pub platforms: Vec<ManifestEntry>,
+}
+
+/// Outcome of one article on one platform
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ManifestEntry {
+    /// Platform identifier (`devto`, `medium`)
+    pub platform: String,
+
+    /// `published`, `updated`, `unchanged`, `skipped` or `failed`
+    pub status: RunStatus,
+
+    /// URL of the copy on the platform
+    pub url: Option<String>,
+
+    /// Article ID on the platform
+    pub id: Option<String>,
+
+    /// When this platform finished
+    pub finished_at: DateTime<Utc>,
+
+    /// Error or skip reason
+    pub message: Option<String>,
+
+    /// Warnings printed while preparing and posting
+    pub warnings: Vec<String>,
+}
+
+impl PublishManifest {
+    /// Group recorded results by article
+    ///
+    /// `command` is the outcome of the whole command: an error that stopped the run
+    /// before every platform was tried (or `interrupted`, for Ctrl-C) leaves no
+    /// failed result behind, but the run still did not succeed.
+    pub fn from_results(
+        results: &[RunResult],
+        started_at: DateTime<Utc>,
+        command: &Result<()>,
+        interrupted: bool,
+    ) -> Self {
+        let mut articles: Vec<ManifestArticle> = Vec::new();
+
+        for result in results {
+            let entry = ManifestEntry {
+                platform: platform_id(&result.platform),
+                status: result.status,
+                url: result.url.clone(),
+                id: result.id.clone(),
+                finished_at: result.finished_at,
+                message: result.message.clone(),
+                warnings: result.warnings.clone(),
+            };
+            match articles.iter_mut().find(|a| a.source == result.article) {
+                Some(article) => article.platforms.push(entry),
+                None => articles.push(ManifestArticle {
+                    source: result.article.clone(),
+                    platforms: vec![entry],
+                }),
+            }
+        }
+
+        let success = command.is_ok()
+            && !interrupted
+            && results.iter().all(|r| r.status != RunStatus::Failed);
+
+        Self {
+            schema_version: MANIFEST_SCHEMA_VERSION,
+            started_at,
+            finished_at: Utc::now(),
+            success,
+            articles,
+        }
+    }
+
+    /// Write the manifest as pretty-printed JSON
+    pub fn write(&self, path: &Path) -> Result<()> {
+        let json = serde_json::to_string_pretty(self).context("Failed to serialize manifest")?;
+        std::fs::write(path, json + "\n")
+            .context(format!("Failed to write manifest {}", path.display()))
+    }
+}
+
+/// Stable identifier for a platform name as recorded in the results
+fn platform_id(name: &str) -> String {
+    name.parse::<Platform>()
+        .map(|platform| platform.id().to_string())
+        .unwrap_or_else(|_| name.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn result(article: &str, platform: &str, status: RunStatus) -> RunResult {
+        RunResult {
+            article: article.to_string(),
+            platform: platform.to_string(),
+            status,
+            url: None,
+            id: None,
+            message: None,
+            finished_at: Utc::now(),
+            warnings: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn test_manifest_groups_by_article() {
+        let published = RunResult {
+            url: Some("https://dev.to/u/a".to_string()),
+            id: Some("42".to_string()),
+            warnings: vec!["Too many tags".to_string()],
+            ..result("posts/a.md", "dev.to", RunStatus::Published)
+        };
+        let results = vec![
+            published,
+            result("posts/b.md", "dev.to", RunStatus::Unchanged),
+            result("posts/a.md", "Medium", RunStatus::Failed),
+        ];
+
+        let manifest = PublishManifest::from_results(&results, Utc::now(), &Ok(()), false);
+
+        assert!(!manifest.success);
+        assert_eq!(manifest.articles.len(), 2);
+        let platforms: Vec<&str> = manifest.articles[0]
+            .platforms
+            .iter()
+            .map(|p| p.platform.as_str())
+            .collect();
+        assert_eq!(platforms, vec!["devto", "medium"]);
+
+        let json = serde_json::to_value(&manifest).unwrap();
+        let devto = &json["articles"][0]["platforms"][0];
+        assert_eq!(json["schema_version"], 1);
+        assert_eq!(devto["status"], "published");
+        assert_eq!(devto["id"], "42");
+        assert_eq!(devto["warnings"][0], "Too many tags");
+        assert!(json["articles"][1]["platforms"][0]["url"].is_null());
+    }
+
+    #[test]
+    fn test_manifest_success() {
+        let results = vec![result("posts/a.md", "dev.to", RunStatus::Published)];
+
+        let manifest = PublishManifest::from_results(&results, Utc::now(), &Ok(()), false);
+
+        assert!(manifest.success);
+    }
+
+    #[test]
+    fn test_manifest_fails_when_command_fails() {
+        // e.g. the second file of a batch didn't parse: nothing of it was recorded
+        let results = vec![result("posts/a.md", "dev.to", RunStatus::Published)];
+        let command = Err(anyhow::anyhow!("Failed to parse posts/b.md"));
+
+        let manifest = PublishManifest::from_results(&results, Utc::now(), &command, false);
+
+        assert!(!manifest.success);
+        assert_eq!(manifest.articles.len(), 1);
+    }
+
+    #[test]
+    fn test_manifest_fails_when_interrupted() {
+        let results = vec![result("posts/a.md", "dev.to", RunStatus::Published)];
+        let command = Err(anyhow::anyhow!("Interrupted"));
+
+        let manifest = PublishManifest::from_results(&results, Utc::now(), &command, true);
+
+        assert!(!manifest.success);
+    }
+}
@@ -1,5 +1,25 @@
 pub mod args;
+pub mod backup;
+pub mod cache;
+pub mod ci;
 pub mod config;
+pub mod doctor;
+pub mod git;
+pub mod journal;
+pub mod manifest;
+pub mod plan;
+pub mod queue;
+pub mod state;
 
-pub use args::{ArticleState, Cli, Commands, ConfigAction, ContentFormat, Platform};
-pub use config::Config;
+pub use args::{
+    ArticleState, Cli, Commands, ConfigAction, ContentFormat, Platform, PostArgs, PostSeriesArgs,
+    QueueAction,
+};
+pub use backup::{ArticleDiscussion, BackupManifest};
+pub use config::{
+    Config, DefaultsConfig, DevToConfig, LlmConfig, MediumConfig, PublishConfig, TranslateConfig,
+    UtmConfig,
+};
+pub use manifest::PublishManifest;
+pub use plan::{PlanStep, PublishPlan};
+pub use state::{content_hash, PlatformRecord, State};
@@ -0,0 +1,155 @@
+use anyhow::Result;
+
+use crate::cli::{Platform, PublishConfig};
+
+/// Order in which a `post` run publishes to its target platforms
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PublishPlan {
+    /// Steps in publish order
+    pub steps: Vec<PlanStep>,
+}
+
+/// One platform of a publish plan
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PlanStep {
+    /// Platform to publish to
+    pub platform: Platform,
+
+    /// Earlier steps that must succeed first; if one fails, this step is skipped
+    pub needs: Vec<Platform>,
+}
+
+impl PublishPlan {
+    /// Order the targets by `[publish] order`, then by `depends_on`
+    ///
+    /// Targets keep their `--to` order unless the config says otherwise. A dependency
+    /// that is not a target of this run must already be published (`is_published`);
+    /// otherwise, or if the dependencies form a cycle, planning fails before anything
+    /// is sent.
+    pub fn build(
+        targets: &[Platform],
+        config: &PublishConfig,
+        is_published: impl Fn(&Platform) -> bool,
+    ) -> Result<Self> {
+        let rank = |platform: &Platform| match config.order.iter().position(|p| p == platform) {
+            Some(position) => position,
+            None => config.order.len() + targets.iter().position(|p| p == platform).unwrap_or(0),
+        };
+
+        let mut pending: Vec<PlanStep> = Vec::new();
+        for platform in targets {
+            if pending.iter().any(|step| step.platform == *platform) {
+                continue;
+            }
+            let mut needs = Vec::new();
+            for dependency in config.depends_on.get(platform).into_iter().flatten() {
+                if dependency == platform {
+                    anyhow::bail!(
+                        "{} cannot depend on itself in [publish] depends_on",
+                        platform
+                    );
+                }
+                if targets.contains(dependency) {
+                    needs.push(dependency.clone());
+                } else if !is_published(dependency) {
+                    anyhow::bail!(
+                        "{} depends on {}, which is not a target of this run and has no \
+                         published copy - add it with --to or change [publish] depends_on",
+                        platform,
+                        dependency
+                    );
+                }
+            }
+            pending.push(PlanStep {
+                platform: platform.clone(),
+                needs,
+            });
+        }
+        pending.sort_by_key(|step| rank(&step.platform));
+
+        // Take the first step whose dependencies are all placed, until none are left
+        let mut steps: Vec<PlanStep> = Vec::with_capacity(pending.len());
+        while !pending.is_empty() {
+            let Some(next) = pending.iter().position(|step| {
+                step.needs
+                    .iter()
+                    .all(|need| steps.iter().any(|placed| placed.platform == *need))
+            }) else {
+                let cycle: Vec<String> = pending.iter().map(|s| s.platform.to_string()).collect();
+                anyhow::bail!(
+                    "[publish] depends_on has a cycle between {}",
+                    cycle.join(" and ")
+                );
+            };
+            steps.push(pending.remove(next));
+        }
+
+        Ok(Self { steps })
+    }
+
+    /// Platforms in publish order
+    pub fn platforms(&self) -> Vec<Platform> {
+        self.steps
+            .iter()
+            .map(|step| step.platform.clone())
+            .collect()
+    }
+
+    /// One-line description, e.g. "dev.to, then Medium (after dev.to)"
+    pub fn describe(&self) -> String {
+        self.steps
+            .iter()
+            .map(|step| {
+                if step.needs.is_empty() {
+                    step.platform.to_string()
+                } else {
+                    let needs: Vec<String> = step.needs.iter().map(|p| p.to_string()).collect();
+                    format!("{} (after {})", step.platform, needs.join(", "))
+                }
+            })
+            .collect::<Vec<_>>()
+            .join(", then ")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn config(toml: &str) -> PublishConfig {
+        toml::from_str(toml).unwrap()
+    }
+
+    #[test]
+    fn test_plan_order_and_dependencies() {
+        let both = [Platform::Medium, Platform::DevTo];
+
+        let plan = PublishPlan::build(&both, &PublishConfig::default(), |_| false).unwrap();
+        assert_eq!(plan.platforms(), both);
+        assert_eq!(plan.describe(), "Medium, then dev.to");
+
+        let ordered = config("order = [\"devto\"]\n");
+        let plan = PublishPlan::build(&both, &ordered, |_| false).unwrap();
+        assert_eq!(plan.platforms(), vec![Platform::DevTo, Platform::Medium]);
+
+        let dependent = config("[depends_on]\nmedium = [\"devto\"]\n");
+        let plan = PublishPlan::build(&both, &dependent, |_| false).unwrap();
+        assert_eq!(plan.describe(), "dev.to, then Medium (after dev.to)");
+    }
+
+    #[test]
+    fn test_plan_rejects_unmet_dependencies() {
+        let dependent = config("[depends_on]\nmedium = [\"devto\"]\n");
+
+        // dev.to is not a target: fine only if the article is already there
+        assert!(PublishPlan::build(&[Platform::Medium], &dependent, |_| false).is_err());
+        let plan =
+            PublishPlan::build(&[Platform::Medium], &dependent, |p| *p == Platform::DevTo).unwrap();
+        assert!(plan.steps[0].needs.is_empty());
+
+        let cycle = config("[depends_on]\nmedium = [\"devto\"]\ndevto = [\"medium\"]\n");
+        let error = PublishPlan::build(&[Platform::DevTo, Platform::Medium], &cycle, |_| false)
+            .unwrap_err();
+        assert!(error.to_string().contains("cycle"));
+    }
+}
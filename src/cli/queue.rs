@@ -0,0 +1,273 @@
+use anyhow::{Context, Result};
+use chrono::{DateTime, Local, NaiveDate, NaiveDateTime, TimeZone, Utc};
+use serde::{Deserialize, Serialize};
+use std::fs::{self, OpenOptions};
+use std::io::Write;
+use std::path::PathBuf;
+use std::time::Duration;
+
+use crate::cli::{Config, ContentFormat, Platform};
+
+/// Number of failed attempts after which a queued post is no longer retried
+pub const MAX_ATTEMPTS: u32 = 3;
+
+/// Posts waiting to be published by `queue run`
+///
+/// Stored in `queue.json` next to the config file.
+#[derive(Debug, Default, Serialize, Deserialize, Clone)]
+pub struct Queue {
+    #[serde(default)]
+    pub items: Vec<QueueItem>,
+}
+
+/// One queued post
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct QueueItem {
+    /// Queue-unique ID (used by `queue remove`)
+    pub id: u32,
+
+    /// Markdown file (canonical path) or dev.to URL
+    pub input: String,
+
+    /// Target platforms
+    pub platforms: Vec<Platform>,
+
+    /// When the post becomes due
+    pub publish_at: DateTime<Utc>,
+
+    /// Apply AI artifact cleaning before posting
+    #[serde(default)]
+    pub clean_ai: bool,
+
+    /// Content format for Medium
+    pub format: ContentFormat,
+
+    /// Failed publishing attempts so far
+    #[serde(default)]
+    pub attempts: u32,
+
+    /// Error from the last failed attempt
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_error: Option<String>,
+}
+
+impl QueueItem {
+    /// True once the post has failed `MAX_ATTEMPTS` times
+    pub fn is_failed(&self) -> bool {
+        self.attempts >= MAX_ATTEMPTS
+    }
+
+    /// True if the post should be published now
+    pub fn is_due(&self, now: DateTime<Utc>) -> bool {
+        self.publish_at <= now && !self.is_failed()
+    }
+}
+
+impl Queue {
+    /// Get the path to the queue file (next to the config file)
+    pub fn queue_path() -> Result<PathBuf> {
+        Ok(Self::config_dir()?.join("queue.json"))
+    }
+
+    /// Get the path to the queue run log (next to the config file)
+    pub fn log_path() -> Result<PathBuf> {
+        Ok(Self::config_dir()?.join("queue.log"))
+    }
+
+    fn config_dir() -> Result<PathBuf> {
+        let config_path = Config::config_path()?;
+        let config_dir = config_path
+            .parent()
+            .context("Failed to get config directory")?;
+
+        Ok(config_dir.to_path_buf())
+    }
+
+    /// Load the queue, or return an empty queue if the file doesn't exist yet
+    pub fn load() -> Result<Self> {
+        let queue_path = Self::queue_path()?;
+
+        if !queue_path.exists() {
+            return Ok(Self::default());
+        }
+
+        let content = fs::read_to_string(&queue_path).context(format!(
+            "Failed to read queue file at {}",
+            queue_path.display()
+        ))?;
+
+        serde_json::from_str(&content).context(format!(
+            "Failed to parse queue file at {}",
+            queue_path.display()
+        ))
+    }
+
+    /// Save the queue (temporary file + rename, like the state file)
+    pub fn save(&self) -> Result<()> {
+        let queue_path = Self::queue_path()?;
+        let queue_dir = queue_path
+            .parent()
+            .context("Failed to get config directory")?;
+
+        if !queue_dir.exists() {
+            fs::create_dir_all(queue_dir).context("Failed to create config directory")?;
+        }
+
+        let json = serde_json::to_string_pretty(self).context("Failed to serialize queue")?;
+        let tmp_path = queue_path.with_extension("json.tmp");
+
+        fs::write(&tmp_path, json).context("Failed to write queue file")?;
+        fs::rename(&tmp_path, &queue_path).context("Failed to replace queue file")?;
+
+        Ok(())
+    }
+
+    /// Add an item, assigning it the next free ID (the given `id` is ignored)
+    pub fn push(&mut self, mut item: QueueItem) -> u32 {
+        item.id = self.items.iter().map(|i| i.id).max().unwrap_or(0) + 1;
+        let id = item.id;
+        self.items.push(item);
+        id
+    }
+
+    /// Remove an item by ID
+    pub fn remove(&mut self, id: u32) -> Option<QueueItem> {
+        let index = self.items.iter().position(|item| item.id == id)?;
+        Some(self.items.remove(index))
+    }
+
+    /// Items due for publishing, oldest first
+    pub fn due(&self, now: DateTime<Utc>) -> Vec<QueueItem> {
+        let mut due: Vec<QueueItem> = self
+            .items
+            .iter()
+            .filter(|item| item.is_due(now))
+            .cloned()
+            .collect();
+        due.sort_by_key(|item| (item.publish_at, item.id));
+        due
+    }
+
+    /// Count a failed attempt for an item
+    pub fn record_failure(&mut self, id: u32, error: String) {
+        if let Some(item) = self.items.iter_mut().find(|item| item.id == id) {
+            item.attempts += 1;
+            item.last_error = Some(error);
+        }
+    }
+}
+
+/// Minimum time between two posts to the same platform during `queue run`
+///
+/// dev.to rejects new articles sent less than about 30 seconds apart.
+pub fn min_publish_interval(platform: &Platform) -> Duration {
+    match platform {
+        Platform::DevTo => Duration::from_secs(30),
+        Platform::Medium => Duration::from_secs(10),
+    }
+}
+
+/// Parse a publish time: RFC 3339, or local `YYYY-MM-DD HH:MM` / `YYYY-MM-DD`
+pub fn parse_publish_time(value: &str) -> Result<DateTime<Utc>> {
+    let value = value.trim();
+
+    if let Ok(time) = DateTime::parse_from_rfc3339(value) {
+        return Ok(time.with_timezone(&Utc));
+    }
+
+    let naive = ["%Y-%m-%d %H:%M", "%Y-%m-%dT%H:%M", "%Y-%m-%d %H:%M:%S"]
+        .iter()
+        .find_map(|format| NaiveDateTime::parse_from_str(value, format).ok())
+        .or_else(|| {
+            NaiveDate::parse_from_str(value, "%Y-%m-%d")
+                .ok()
+                .and_then(|date| date.and_hms_opt(0, 0, 0))
+        })
+        .context(format!(
+            "Invalid time '{}' (expected YYYY-MM-DD, YYYY-MM-DD HH:MM or RFC 3339)",
+            value
+        ))?;
+
+    Local
+        .from_local_datetime(&naive)
+        .earliest()
+        .map(|time| time.with_timezone(&Utc))
+        .context(format!("'{}' does not exist in the local time zone", value))
+}
+
+/// Append a timestamped line to the queue log
+pub fn append_log(message: &str) -> Result<()> {
+    let log_path = Queue::log_path()?;
+    if let Some(dir) = log_path.parent() {
+        fs::create_dir_all(dir).context("Failed to create config directory")?;
+    }
+
+    let mut file = OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(&log_path)
+        .context(format!("Failed to open queue log {}", log_path.display()))?;
+
+    writeln!(file, "{} {}", Utc::now().to_rfc3339(), message)
+        .context(format!("Failed to write queue log {}", log_path.display()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn item(input: &str, publish_at: &str) -> QueueItem {
+        QueueItem {
+            id: 0,
+            input: input.to_string(),
+            platforms: vec![Platform::DevTo, Platform::Medium],
+            publish_at: parse_publish_time(publish_at).unwrap(),
+            clean_ai: false,
+            format: ContentFormat::Markdown,
+            attempts: 0,
+            last_error: None,
+        }
+    }
+
+    #[test]
+    fn test_queue_due_and_failures() {
+        let mut queue = Queue::default();
+        let late = queue.push(item("b.md", "2026-03-01T09:00:00Z"));
+        let early = queue.push(item("a.md", "2026-02-01T09:00:00Z"));
+        queue.push(item("c.md", "2030-01-01T09:00:00Z"));
+
+        let now = parse_publish_time("2026-06-01T00:00:00Z").unwrap();
+        let due: Vec<u32> = queue.due(now).iter().map(|i| i.id).collect();
+        assert_eq!(due, vec![early, late]);
+
+        for _ in 0..MAX_ATTEMPTS {
+            queue.record_failure(early, "status 500".to_string());
+        }
+        let due: Vec<u32> = queue.due(now).iter().map(|i| i.id).collect();
+        assert_eq!(due, vec![late]);
+
+        assert!(queue.remove(late).is_some());
+        assert!(queue.remove(late).is_none());
+        assert_eq!(queue.push(item("d.md", "2026-01-01")), 4);
+    }
+
+    #[test]
+    fn test_queue_serialization() {
+        let mut queue = Queue::default();
+        queue.push(item("a.md", "2026-02-01T09:00:00+02:00"));
+
+        let json = serde_json::to_string(&queue).unwrap();
+        assert!(json.contains("\"platforms\":[\"devto\",\"medium\"]"));
+        assert!(json.contains("\"publish_at\":\"2026-02-01T07:00:00Z\""));
+
+        let parsed: Queue = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed.items, queue.items);
+    }
+
+    #[test]
+    fn test_parse_publish_time() {
+        assert!(parse_publish_time("2026-05-01").is_ok());
+        assert!(parse_publish_time("2026-05-01 14:30").is_ok());
+        assert!(parse_publish_time("tomorrow").is_err());
+    }
+}
@@ -0,0 +1,224 @@
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::PathBuf;
+
+use crate::cli::{Config, Platform};
+
+/// Publication state persisted between runs
+///
+/// Records, per source article and platform, where the article was published and
+/// a hash of the exact payload that was sent, so repeated runs can skip unchanged content.
+#[derive(Debug, Default, Serialize, Deserialize, Clone)]
+pub struct State {
+    /// Records keyed by article slug, or by source (canonical file path or dev.to URL)
+    /// for articles without a slug
+    #[serde(default)]
+    pub articles: BTreeMap<String, ArticleRecord>,
+}
+
+/// Publication records for a single source article
+#[derive(Debug, Default, Serialize, Deserialize, Clone)]
+pub struct ArticleRecord {
+    /// Records keyed by platform identifier (`devto`, `medium`)
+    #[serde(default)]
+    pub platforms: BTreeMap<String, PlatformRecord>,
+}
+
+/// Publication record for one article on one platform
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+pub struct PlatformRecord {
+    /// Published article URL
+    pub url: String,
+
+    /// Platform article ID (missing in records written by older versions)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+
+    /// SHA-256 of the publish payload that produced this record
+    pub content_hash: String,
+
+    /// The copy is a draft on the platform: posted unpublished, or turned back into a
+    /// draft by a failed `post-series` run
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub draft: bool,
+}
+
+impl State {
+    /// Get the path to the state file (next to the config file)
+    pub fn state_path() -> Result<PathBuf> {
+        let config_path = Config::config_path()?;
+        let config_dir = config_path
+            .parent()
+            .context("Failed to get config directory")?;
+
+        Ok(config_dir.join("state.json"))
+    }
+
+    /// Load state from the state file, or return empty state if it doesn't exist yet
+    pub fn load() -> Result<Self> {
+        let state_path = Self::state_path()?;
+
+        if !state_path.exists() {
+            return Ok(Self::default());
+        }
+
+        let content = fs::read_to_string(&state_path).context(format!(
+            "Failed to read state file at {}",
+            state_path.display()
+        ))?;
+
+        serde_json::from_str(&content).context(format!(
+            "Failed to parse state file at {}",
+            state_path.display()
+        ))
+    }
+
+    /// Save state to the state file
+    ///
+    /// Writes to a temporary file first and renames it, so an interrupted run
+    /// never leaves a truncated state file behind.
+    pub fn save(&self) -> Result<()> {
+        let state_path = Self::state_path()?;
+        let state_dir = state_path
+            .parent()
+            .context("Failed to get config directory")?;
+
+        if !state_dir.exists() {
+            fs::create_dir_all(state_dir).context("Failed to create config directory")?;
+        }
+
+        let json = serde_json::to_string_pretty(self).context("Failed to serialize state")?;
+        let tmp_path = state_path.with_extension("json.tmp");
+
+        fs::write(&tmp_path, json).context("Failed to write state file")?;
+        fs::rename(&tmp_path, &state_path).context("Failed to replace state file")?;
+
+        Ok(())
+    }
+
+    /// Get the publication record for an article on a platform
+    pub fn record(&self, article_key: &str, platform: &Platform) -> Option<&PlatformRecord> {
+        self.articles
+            .get(article_key)
+            .and_then(|article| article.platforms.get(platform.id()))
+    }
+
+    /// Store the publication record for an article on a platform
+    pub fn set_record(&mut self, article_key: &str, platform: &Platform, record: PlatformRecord) {
+        self.articles
+            .entry(article_key.to_string())
+            .or_default()
+            .platforms
+            .insert(platform.id().to_string(), record);
+    }
+
+    /// Move an article's records to a new key, unless the new key already has records
+    pub fn rename_article(&mut self, from: &str, to: &str) {
+        if self.articles.contains_key(to) {
+            return;
+        }
+        if let Some(record) = self.articles.remove(from) {
+            self.articles.insert(to.to_string(), record);
+        }
+    }
+
+    /// Check whether the payload is identical to the one last published to the platform
+    pub fn is_unchanged(&self, article_key: &str, platform: &Platform, content_hash: &str) -> bool {
+        self.record(article_key, platform)
+            .is_some_and(|record| record.content_hash == content_hash)
+    }
+}
+
+/// Compute the SHA-256 hex digest of a publish payload
+pub fn content_hash(payload: &str) -> String {
+    format!("{:x}", Sha256::digest(payload.as_bytes()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_content_hash_is_stable() {
+        assert_eq!(content_hash("payload"), content_hash("payload"));
+        assert_ne!(content_hash("payload"), content_hash("payload2"));
+        assert_eq!(content_hash("").len(), 64);
+    }
+
+    #[test]
+    fn test_unchanged_detection() {
+        let mut state = State::default();
+        let hash = content_hash("body");
+
+        assert!(!state.is_unchanged("a.md", &Platform::DevTo, &hash));
+
+        state.set_record(
+            "a.md",
+            &Platform::DevTo,
+            PlatformRecord {
+                url: "https://dev.to/u/a-1".to_string(),
+                id: Some("1".to_string()),
+                content_hash: hash.clone(),
+                draft: false,
+            },
+        );
+
+        assert!(state.is_unchanged("a.md", &Platform::DevTo, &hash));
+        assert!(!state.is_unchanged("a.md", &Platform::Medium, &hash));
+        assert!(!state.is_unchanged("a.md", &Platform::DevTo, &content_hash("edited")));
+    }
+
+    #[test]
+    fn test_rename_article() {
+        let record = PlatformRecord {
+            url: "https://dev.to/u/a-1".to_string(),
+            id: None,
+            content_hash: content_hash("body"),
+            draft: false,
+        };
+        let mut state = State::default();
+        state.set_record("/posts/a.md", &Platform::DevTo, record.clone());
+
+        state.rename_article("/posts/a.md", "my-slug");
+        assert_eq!(state.record("my-slug", &Platform::DevTo), Some(&record));
+        assert!(state.record("/posts/a.md", &Platform::DevTo).is_none());
+
+        // Existing records under the new key are kept
+        state.set_record("/posts/b.md", &Platform::DevTo, record.clone());
+        state.rename_article("/posts/b.md", "my-slug");
+        assert!(state.record("/posts/b.md", &Platform::DevTo).is_some());
+    }
+
+    #[test]
+    fn test_state_roundtrip() {
+        let mut state = State::default();
+        state.set_record(
+            "a.md",
+            &Platform::Medium,
+            PlatformRecord {
+                url: "https://medium.com/p/abc".to_string(),
+                id: Some("abc".to_string()),
+                content_hash: content_hash("body"),
+                draft: false,
+            },
+        );
+
+        let json = serde_json::to_string(&state).unwrap();
+        let restored: State = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(
+            restored.record("a.md", &Platform::Medium),
+            state.record("a.md", &Platform::Medium)
+        );
+
+        // Records from older versions have no ID
+        let legacy: State = serde_json::from_str(
+            r#"{"articles":{"a.md":{"platforms":{"devto":{"url":"u","content_hash":"h"}}}}}"#,
+        )
+        .unwrap();
+        assert_eq!(legacy.record("a.md", &Platform::DevTo).unwrap().id, None);
+    }
+}
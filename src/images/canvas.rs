@@ -0,0 +1,162 @@
+use anyhow::{Context, Result};
+use embedded_graphics::pixelcolor::Rgb888;
+use embedded_graphics::prelude::*;
+use std::convert::Infallible;
+
+/// Largest image side we are willing to render, in output pixels
+const MAX_DIMENSION: u32 = 8000;
+
+/// In-memory RGB drawing surface for embedded-graphics
+///
+/// Drawing happens in logical pixels; each logical pixel is written as a
+/// `scale` x `scale` block so the bitmap fonts stay legible on high-DPI screens.
+pub struct Canvas {
+    width: u32,
+    height: u32,
+    scale: u32,
+    pixels: Vec<u8>,
+}
+
+impl Canvas {
+    /// Create a canvas of the given logical size, filled with a background color
+    pub fn new(width: u32, height: u32, scale: u32, background: Rgb888) -> Result<Self> {
+        let out_width = width * scale;
+        let out_height = height * scale;
+
+        if out_width == 0 || out_height == 0 {
+            anyhow::bail!("Image has no area ({}x{})", out_width, out_height);
+        }
+        if out_width > MAX_DIMENSION || out_height > MAX_DIMENSION {
+            anyhow::bail!(
+                "Image too large: {}x{} pixels (max: {} per side)",
+                out_width,
+                out_height,
+                MAX_DIMENSION
+            );
+        }
+
+        let pixels = [background.r(), background.g(), background.b()]
+            .repeat((out_width * out_height) as usize);
+
+        Ok(Self {
+            width: out_width,
+            height: out_height,
+            scale,
+            pixels,
+        })
+    }
+
+    /// Stretch an RGB image over the whole canvas (nearest-neighbor sampling)
+    pub fn draw_image(&mut self, width: u32, height: u32, rgb: &[u8]) {
+        if width == 0 || height == 0 || rgb.len() < (width * height * 3) as usize {
+            return;
+        }
+
+        for y in 0..self.height {
+            let src_y = (u64::from(y) * u64::from(height) / u64::from(self.height)) as u32;
+            for x in 0..self.width {
+                let src_x = (u64::from(x) * u64::from(width) / u64::from(self.width)) as u32;
+                let src = ((src_y * width + src_x) * 3) as usize;
+                let dst = ((y * self.width + x) * 3) as usize;
+                self.pixels[dst..dst + 3].copy_from_slice(&rgb[src..src + 3]);
+            }
+        }
+    }
+
+    /// Encode the canvas as a PNG image
+    pub fn to_png(&self) -> Result<Vec<u8>> {
+        let mut data = Vec::new();
+
+        let mut encoder = png::Encoder::new(&mut data, self.width, self.height);
+        encoder.set_color(png::ColorType::Rgb);
+        encoder.set_depth(png::BitDepth::Eight);
+
+        let mut writer = encoder
+            .write_header()
+            .context("Failed to write PNG header")?;
+        writer
+            .write_image_data(&self.pixels)
+            .context("Failed to encode PNG image")?;
+        writer.finish().context("Failed to finish PNG image")?;
+
+        Ok(data)
+    }
+}
+
+impl OriginDimensions for Canvas {
+    fn size(&self) -> Size {
+        Size::new(self.width / self.scale, self.height / self.scale)
+    }
+}
+
+impl DrawTarget for Canvas {
+    type Color = Rgb888;
+    type Error = Infallible;
+
+    fn draw_iter<I>(&mut self, pixels: I) -> Result<(), Self::Error>
+    where
+        I: IntoIterator<Item = Pixel<Self::Color>>,
+    {
+        for Pixel(point, color) in pixels {
+            let (Ok(x), Ok(y)) = (u32::try_from(point.x), u32::try_from(point.y)) else {
+                continue;
+            };
+
+            for dy in 0..self.scale {
+                for dx in 0..self.scale {
+                    let (px, py) = (x * self.scale + dx, y * self.scale + dy);
+                    if px < self.width && py < self.height {
+                        let offset = ((py * self.width + px) * 3) as usize;
+                        self.pixels[offset..offset + 3].copy_from_slice(&[
+                            color.r(),
+                            color.g(),
+                            color.b(),
+                        ]);
+                    }
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use embedded_graphics::primitives::{PrimitiveStyle, Rectangle};
+
+    #[test]
+    fn test_canvas_scales_and_encodes() {
+        let mut canvas = Canvas::new(4, 2, 2, Rgb888::WHITE).unwrap();
+        assert_eq!(canvas.size(), Size::new(4, 2));
+
+        Rectangle::new(Point::zero(), Size::new(1, 1))
+            .into_styled(PrimitiveStyle::with_fill(Rgb888::BLACK))
+            .draw(&mut canvas)
+            .unwrap();
+
+        // One logical pixel covers a 2x2 block
+        assert_eq!(&canvas.pixels[..6], &[0, 0, 0, 0, 0, 0]);
+        assert_eq!(&canvas.pixels[6..9], &[255, 255, 255]);
+
+        let png = canvas.to_png().unwrap();
+        assert!(png.starts_with(b"\x89PNG"));
+    }
+
+    #[test]
+    fn test_draw_image_stretches() {
+        let mut canvas = Canvas::new(2, 1, 2, Rgb888::WHITE).unwrap();
+        canvas.draw_image(2, 1, &[255, 0, 0, 0, 0, 255]);
+
+        // Left half red, right half blue
+        assert_eq!(&canvas.pixels[..3], &[255, 0, 0]);
+        assert_eq!(&canvas.pixels[9..12], &[0, 0, 255]);
+    }
+
+    #[test]
+    fn test_canvas_rejects_huge_images() {
+        assert!(Canvas::new(MAX_DIMENSION, 10, 2, Rgb888::WHITE).is_err());
+        assert!(Canvas::new(0, 10, 1, Rgb888::WHITE).is_err());
+    }
+}
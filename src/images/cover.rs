@@ -0,0 +1,254 @@
+use anyhow::{Context, Result};
+use embedded_graphics::mono_font::iso_8859_1::{FONT_10X20, FONT_8X13};
+use embedded_graphics::mono_font::{MonoFont, MonoTextStyle};
+use embedded_graphics::pixelcolor::Rgb888;
+use embedded_graphics::prelude::*;
+use embedded_graphics::primitives::{PrimitiveStyle, Rectangle};
+use embedded_graphics::text::{Baseline, Text};
+use std::fs::File;
+use std::path::{Path, PathBuf};
+
+use crate::images::canvas::Canvas;
+
+/// Logical cover size; with `SCALE` this gives dev.to's recommended 1000x420
+const WIDTH: u32 = 500;
+const HEIGHT: u32 = 210;
+const SCALE: u32 = 2;
+
+/// Outer margin (logical pixels)
+const MARGIN: u32 = 32;
+
+/// Maximum number of title lines before the title is cut off
+const MAX_TITLE_LINES: usize = 4;
+
+/// Title line height (logical pixels)
+const TITLE_LINE_HEIGHT: u32 = 24;
+
+const TITLE_FONT: MonoFont = FONT_10X20;
+const AUTHOR_FONT: MonoFont = FONT_8X13;
+
+/// Appearance of generated cover images
+#[derive(Debug, Clone)]
+pub struct CoverStyle {
+    /// Author line shown at the bottom
+    pub author: Option<String>,
+
+    /// Background color (used when there is no background image)
+    pub background: Rgb888,
+
+    /// Title and author color
+    pub text_color: Rgb888,
+
+    /// Accent bar color
+    pub accent_color: Rgb888,
+
+    /// PNG template stretched over the whole card
+    pub background_image: Option<PathBuf>,
+}
+
+impl Default for CoverStyle {
+    fn default() -> Self {
+        Self {
+            author: None,
+            background: Rgb888::new(0x1e, 0x29, 0x3b),
+            text_color: Rgb888::new(0xf8, 0xfa, 0xfc),
+            accent_color: Rgb888::new(0x38, 0xbd, 0xf8),
+            background_image: None,
+        }
+    }
+}
+
+/// Parse a `#rrggbb` hex color
+pub fn parse_hex_color(value: &str) -> Result<Rgb888> {
+    let hex = value.trim().trim_start_matches('#');
+
+    if hex.len() != 6 || !hex.chars().all(|c| c.is_ascii_hexdigit()) {
+        anyhow::bail!("Invalid color '{}' (expected #rrggbb)", value);
+    }
+
+    let channel = |i: usize| u8::from_str_radix(&hex[i..i + 2], 16);
+    Ok(Rgb888::new(channel(0)?, channel(2)?, channel(4)?))
+}
+
+/// Render an OG-image-style cover card for an article title
+///
+/// Uses built-in Latin-1 bitmap fonts; characters outside Latin-1 are shown as `?`.
+pub fn render_cover_png(title: &str, style: &CoverStyle) -> Result<Vec<u8>> {
+    let mut canvas = Canvas::new(WIDTH, HEIGHT, SCALE, style.background)?;
+
+    if let Some(path) = &style.background_image {
+        let (width, height, pixels) = load_png_rgb(path)?;
+        canvas.draw_image(width, height, &pixels);
+    }
+
+    Rectangle::new(Point::new(MARGIN as i32, MARGIN as i32), Size::new(48, 4))
+        .into_styled(PrimitiveStyle::with_fill(style.accent_color))
+        .draw(&mut canvas)?;
+
+    let title_style = MonoTextStyle::new(&TITLE_FONT, style.text_color);
+    let char_width = TITLE_FONT.character_size.width + TITLE_FONT.character_spacing;
+    let max_chars = ((WIDTH - 2 * MARGIN) / char_width) as usize;
+
+    for (i, line) in wrap_title(title, max_chars).iter().enumerate() {
+        let y = MARGIN + 20 + i as u32 * TITLE_LINE_HEIGHT;
+        Text::with_baseline(
+            line,
+            Point::new(MARGIN as i32, y as i32),
+            title_style,
+            Baseline::Top,
+        )
+        .draw(&mut canvas)?;
+    }
+
+    if let Some(author) = &style.author {
+        let author_style = MonoTextStyle::new(&AUTHOR_FONT, style.text_color);
+        let y = HEIGHT - MARGIN - AUTHOR_FONT.character_size.height;
+        Text::with_baseline(
+            author,
+            Point::new(MARGIN as i32, y as i32),
+            author_style,
+            Baseline::Top,
+        )
+        .draw(&mut canvas)?;
+    }
+
+    canvas.to_png()
+}
+
+/// Word-wrap a title into at most `MAX_TITLE_LINES` lines of `max_chars` characters
+fn wrap_title(title: &str, max_chars: usize) -> Vec<String> {
+    let mut lines: Vec<String> = Vec::new();
+    let mut current = String::new();
+
+    for word in title.split_whitespace() {
+        // Hard-split words that don't fit on a line at all
+        let chars: Vec<char> = word.chars().collect();
+        for chunk in chars.chunks(max_chars) {
+            let chunk: String = chunk.iter().collect();
+            let needed = current.chars().count() + usize::from(!current.is_empty());
+
+            if !current.is_empty() && needed + chunk.chars().count() > max_chars {
+                lines.push(std::mem::take(&mut current));
+            }
+            if !current.is_empty() {
+                current.push(' ');
+            }
+            current.push_str(&chunk);
+        }
+    }
+    if !current.is_empty() {
+        lines.push(current);
+    }
+
+    if lines.len() > MAX_TITLE_LINES {
+        lines.truncate(MAX_TITLE_LINES);
+        let last = &mut lines[MAX_TITLE_LINES - 1];
+        let keep = last.chars().count().min(max_chars - 3);
+        *last = last.chars().take(keep).collect::<String>() + "...";
+    }
+
+    lines
+}
+
+/// Decode a PNG file into 8-bit RGB pixels
+fn load_png_rgb(path: &Path) -> Result<(u32, u32, Vec<u8>)> {
+    let file = File::open(path).context(format!(
+        "Failed to open cover background image: {}",
+        path.display()
+    ))?;
+
+    let mut decoder = png::Decoder::new(file);
+    decoder.set_transformations(png::Transformations::EXPAND | png::Transformations::STRIP_16);
+    let mut reader = decoder.read_info().context(format!(
+        "Failed to read PNG background image: {}",
+        path.display()
+    ))?;
+
+    let mut buffer = vec![0; reader.output_buffer_size()];
+    let info = reader
+        .next_frame(&mut buffer)
+        .context("Failed to decode PNG background image")?;
+    let data = &buffer[..info.buffer_size()];
+
+    let rgb = match info.color_type {
+        png::ColorType::Rgb => data.to_vec(),
+        png::ColorType::Rgba => data
+            .chunks_exact(4)
+            .flat_map(|p| [p[0], p[1], p[2]])
+            .collect(),
+        png::ColorType::Grayscale => data.iter().flat_map(|&g| [g, g, g]).collect(),
+        png::ColorType::GrayscaleAlpha => data
+            .chunks_exact(2)
+            .flat_map(|p| [p[0], p[0], p[0]])
+            .collect(),
+        png::ColorType::Indexed => anyhow::bail!("Unsupported indexed PNG background image"),
+    };
+
+    Ok((info.width, info.height, rgb))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_hex_color() {
+        assert_eq!(
+            parse_hex_color("#1e293b").unwrap(),
+            Rgb888::new(0x1e, 0x29, 0x3b)
+        );
+        assert_eq!(parse_hex_color("FFFFFF").unwrap(), Rgb888::WHITE);
+        assert!(parse_hex_color("#fff").is_err());
+        assert!(parse_hex_color("#gggggg").is_err());
+    }
+
+    #[test]
+    fn test_wrap_title() {
+        assert_eq!(
+            wrap_title("Ten tips for faster Rust builds", 12),
+            vec!["Ten tips for", "faster Rust", "builds"]
+        );
+        assert_eq!(wrap_title("abcdefghij", 4), vec!["abcd", "efgh", "ij"]);
+    }
+
+    #[test]
+    fn test_wrap_title_truncates_long_titles() {
+        let lines = wrap_title("one two three four five six seven eight", 5);
+
+        assert_eq!(lines.len(), MAX_TITLE_LINES);
+        assert_eq!(lines[MAX_TITLE_LINES - 1], "fo...");
+    }
+
+    #[test]
+    fn test_render_cover_png() {
+        let style = CoverStyle {
+            author: Some("Jane Doe".to_string()),
+            ..Default::default()
+        };
+
+        let png = render_cover_png("Hello, covers", &style).unwrap();
+
+        assert!(png.starts_with(b"\x89PNG"));
+    }
+
+    #[test]
+    fn test_render_cover_with_background_image() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let background = dir.path().join("bg.png");
+        let template = Canvas::new(2, 2, 1, Rgb888::RED).unwrap();
+        std::fs::write(&background, template.to_png().unwrap()).unwrap();
+
+        let style = CoverStyle {
+            background_image: Some(background),
+            ..Default::default()
+        };
+
+        assert!(render_cover_png("Title", &style).is_ok());
+
+        let missing = CoverStyle {
+            background_image: Some(dir.path().join("missing.png")),
+            ..Default::default()
+        };
+        assert!(render_cover_png("Title", &missing).is_err());
+    }
+}
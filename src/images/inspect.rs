@@ -0,0 +1,281 @@
+use crate::parsers::validator::Severity;
+
+/// Format and pixel size of an image, read from its header
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ImageInfo {
+    /// Format name (`PNG`, `JPEG`, `GIF`, `WebP`)
+    pub format: &'static str,
+
+    pub width: u32,
+    pub height: u32,
+}
+
+/// What a platform expects from a cover image
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CoverRequirements {
+    /// Platform name for messages
+    pub platform: &'static str,
+
+    /// Narrower covers are upscaled and look blurry
+    pub min_width: u32,
+
+    /// Aspect ratio (width, height) the platform crops covers to, if any
+    pub crop: Option<(u32, u32)>,
+
+    /// Portrait covers lose their top and bottom in landscape previews
+    pub landscape: bool,
+
+    /// Covers larger than this (bytes) slow down the article page
+    pub max_bytes: u64,
+}
+
+/// dev.to shows covers at 1000x420 and crops anything else to 100:42
+pub const DEVTO_COVER: CoverRequirements = CoverRequirements {
+    platform: "dev.to",
+    min_width: 1000,
+    crop: Some((100, 42)),
+    landscape: true,
+    max_bytes: 5 * 1024 * 1024,
+};
+
+/// Medium shows the cover full width in the article and cropped in previews
+pub const MEDIUM_COVER: CoverRequirements = CoverRequirements {
+    platform: "Medium",
+    min_width: 1000,
+    crop: None,
+    landscape: true,
+    max_bytes: 5 * 1024 * 1024,
+};
+
+/// Aspect ratios within this fraction of the crop ratio lose too little to mention
+const CROP_TOLERANCE: f64 = 0.1;
+
+/// Read the format and size of a PNG, JPEG, GIF or WebP image from its first bytes
+///
+/// A few kilobytes are usually enough; JPEGs with large metadata blocks may need more.
+pub fn image_info(data: &[u8]) -> Option<ImageInfo> {
+    let be16 = |at: usize| Some(u16::from_be_bytes([*data.get(at)?, *data.get(at + 1)?]) as u32);
+    let le16 = |at: usize| Some(u16::from_le_bytes([*data.get(at)?, *data.get(at + 1)?]) as u32);
+    let le24 = |at: usize| {
+        Some(u32::from_le_bytes([
+            *data.get(at)?,
+            *data.get(at + 1)?,
+            *data.get(at + 2)?,
+            0,
+        ]))
+    };
+    let be32 = |at: usize| Some(u32::from_be_bytes(data.get(at..at + 4)?.try_into().ok()?));
+    let info = |format, width, height| {
+        Some(ImageInfo {
+            format,
+            width,
+            height,
+        })
+    };
+
+    if data.starts_with(b"\x89PNG\r\n\x1a\n") {
+        return info("PNG", be32(16)?, be32(20)?);
+    }
+
+    if data.starts_with(b"GIF87a") || data.starts_with(b"GIF89a") {
+        return info("GIF", le16(6)?, le16(8)?);
+    }
+
+    if data.starts_with(b"RIFF") && data.get(8..12) == Some(b"WEBP") {
+        return match data.get(12..16)? {
+            b"VP8 " => info("WebP", le16(26)? & 0x3fff, le16(28)? & 0x3fff),
+            b"VP8L" => {
+                let bits = u32::from_le_bytes(data.get(21..25)?.try_into().ok()?);
+                info("WebP", (bits & 0x3fff) + 1, ((bits >> 14) & 0x3fff) + 1)
+            }
+            b"VP8X" => info("WebP", le24(24)? + 1, le24(27)? + 1),
+            _ => None,
+        };
+    }
+
+    if data.starts_with(&[0xff, 0xd8]) {
+        // Walk the JPEG segments up to the start-of-frame header
+        let mut at = 2;
+        while at + 4 <= data.len() {
+            if data[at] != 0xff {
+                return None;
+            }
+            let marker = data[at + 1];
+            match marker {
+                0xff => at += 1,
+                0x01 | 0xd0..=0xd9 => at += 2,
+                0xc0..=0xcf if !matches!(marker, 0xc4 | 0xc8 | 0xcc) => {
+                    return info("JPEG", be16(at + 7)?, be16(at + 5)?);
+                }
+                _ => at += 2 + be16(at + 2)? as usize,
+            }
+        }
+    }
+
+    None
+}
+
+/// Problems with a cover image on one platform, with guidance
+///
+/// `info` is `None` when the dimensions could not be read; `size` is the file size
+/// in bytes, if known.
+pub fn cover_issues(
+    info: Option<&ImageInfo>,
+    size: Option<u64>,
+    requirements: &CoverRequirements,
+) -> Vec<(Severity, String)> {
+    let platform = requirements.platform;
+    let mut issues = Vec::new();
+
+    if let Some(size) = size.filter(|&size| size > requirements.max_bytes) {
+        issues.push((
+            Severity::Warning,
+            format!(
+                "Cover image is {:.1} MB; {} covers over {} MB slow down the article page - \
+                 compress it or export it as JPEG",
+                size as f64 / (1024.0 * 1024.0),
+                platform,
+                requirements.max_bytes / (1024 * 1024)
+            ),
+        ));
+    }
+
+    let Some(info) = info else {
+        issues.push((
+            Severity::Warning,
+            "Could not read the cover image dimensions (PNG, JPEG, GIF and WebP are checked)"
+                .to_string(),
+        ));
+        return issues;
+    };
+    let (width, height) = (info.width, info.height);
+
+    if width == 0 || height == 0 {
+        issues.push((
+            Severity::Error,
+            format!("Cover image has no pixels ({}x{})", width, height),
+        ));
+        return issues;
+    }
+
+    let suggested = match requirements.crop {
+        Some((w, h)) => format!(
+            "{}x{}",
+            requirements.min_width,
+            requirements.min_width * h / w
+        ),
+        None => format!("{} pixels wide", requirements.min_width),
+    };
+
+    if width < requirements.min_width {
+        issues.push((
+            Severity::Warning,
+            format!(
+                "Cover image is only {}x{}; {} displays covers at least {} pixels wide, \
+                 so it will look blurry - use {} or larger",
+                width, height, platform, requirements.min_width, suggested
+            ),
+        ));
+    }
+
+    let ratio = width as f64 / height as f64;
+    if let Some((w, h)) = requirements.crop {
+        let target = w as f64 / h as f64;
+        if (ratio - target).abs() / target > CROP_TOLERANCE {
+            // Share of the image outside the crop area
+            let (lost, side) = if ratio > target {
+                (1.0 - target / ratio, "left and right")
+            } else {
+                (1.0 - ratio / target, "top and bottom")
+            };
+            issues.push((
+                Severity::Warning,
+                format!(
+                    "{} crops covers to {}:{}; this {}x{} image will lose about {:.0}% at the {} - \
+                     use {} (or the same proportions)",
+                    platform,
+                    w,
+                    h,
+                    width,
+                    height,
+                    lost * 100.0,
+                    side,
+                    suggested
+                ),
+            ));
+        }
+    } else if requirements.landscape && height > width {
+        issues.push((
+            Severity::Warning,
+            format!(
+                "Cover image is portrait ({}x{}); {} crops previews to landscape, cutting off \
+                 the top and bottom - use a landscape image",
+                width, height, platform
+            ),
+        ));
+    }
+
+    issues
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_image_info() {
+        let mut png = b"\x89PNG\r\n\x1a\n\0\0\0\rIHDR".to_vec();
+        png.extend_from_slice(&1000u32.to_be_bytes());
+        png.extend_from_slice(&420u32.to_be_bytes());
+        assert_eq!(
+            image_info(&png),
+            Some(ImageInfo {
+                format: "PNG",
+                width: 1000,
+                height: 420
+            })
+        );
+
+        let gif = b"GIF89a\x20\x03\x58\x02";
+        assert_eq!(
+            image_info(gif).map(|i| (i.width, i.height)),
+            Some((800, 600))
+        );
+
+        // SOI, an APP0 segment, then a baseline SOF0 header (precision, height, width)
+        let jpeg = [
+            0xff, 0xd8, 0xff, 0xe0, 0x00, 0x04, 0x00, 0x00, 0xff, 0xc0, 0x00, 0x11, 0x08, 0x02,
+            0x58, 0x04, 0xb0,
+        ];
+        assert_eq!(
+            image_info(&jpeg).map(|i| (i.width, i.height)),
+            Some((1200, 600))
+        );
+
+        assert_eq!(image_info(b"<html>not an image</html>"), None);
+    }
+
+    #[test]
+    fn test_cover_issues() {
+        let info = |width, height| ImageInfo {
+            format: "PNG",
+            width,
+            height,
+        };
+
+        assert!(cover_issues(Some(&info(1000, 420)), Some(200_000), &DEVTO_COVER).is_empty());
+        assert!(cover_issues(Some(&info(1600, 900)), None, &MEDIUM_COVER).is_empty());
+
+        let square = cover_issues(Some(&info(800, 800)), Some(8 * 1024 * 1024), &DEVTO_COVER);
+        assert_eq!(square.len(), 3);
+        assert!(square[0].1.contains("8.0 MB"));
+        assert!(square[1].1.contains("use 1000x420 or larger"));
+        assert!(square[2].1.contains("lose about 58% at the top and bottom"));
+
+        let portrait = cover_issues(Some(&info(1200, 1600)), None, &MEDIUM_COVER);
+        assert!(portrait[0].1.contains("portrait"));
+
+        let unknown = cover_issues(None, None, &MEDIUM_COVER);
+        assert_eq!(unknown[0].0, Severity::Warning);
+    }
+}
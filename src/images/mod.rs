@@ -0,0 +1,10 @@
+pub mod canvas;
+pub mod cover;
+pub mod inspect;
+pub mod table;
+
+pub use cover::{parse_hex_color, render_cover_png, CoverStyle};
+pub use inspect::{
+    cover_issues, image_info, CoverRequirements, ImageInfo, DEVTO_COVER, MEDIUM_COVER,
+};
+pub use table::render_table_png;
@@ -0,0 +1,152 @@
+use anyhow::Result;
+use embedded_graphics::mono_font::iso_8859_1::FONT_9X18;
+use embedded_graphics::mono_font::MonoTextStyle;
+use embedded_graphics::pixelcolor::Rgb888;
+use embedded_graphics::prelude::*;
+use embedded_graphics::primitives::{Line, PrimitiveStyle, Rectangle};
+use embedded_graphics::text::{Baseline, Text};
+
+use crate::images::canvas::Canvas;
+use crate::parsers::tables::{ColumnAlignment, Table};
+
+/// Output pixels per logical pixel
+const SCALE: u32 = 2;
+
+/// Horizontal cell padding (logical pixels)
+const PAD_X: u32 = 8;
+
+/// Vertical cell padding (logical pixels)
+const PAD_Y: u32 = 5;
+
+/// Longest cell text rendered before truncation (characters)
+const MAX_CELL_CHARS: usize = 60;
+
+const BACKGROUND: Rgb888 = Rgb888::WHITE;
+const HEADER_BACKGROUND: Rgb888 = Rgb888::new(240, 240, 240);
+const GRID: Rgb888 = Rgb888::new(200, 200, 200);
+const TEXT: Rgb888 = Rgb888::new(33, 33, 33);
+
+/// Render a table to a PNG image
+///
+/// Uses a built-in Latin-1 bitmap font; characters outside Latin-1 are shown as `?`.
+pub fn render_table_png(table: &Table) -> Result<Vec<u8>> {
+    let columns = std::iter::once(&table.header)
+        .chain(&table.rows)
+        .map(Vec::len)
+        .max()
+        .unwrap_or(0);
+
+    let rows: Vec<Vec<String>> = std::iter::once(&table.header)
+        .chain(&table.rows)
+        .map(|row| {
+            (0..columns)
+                .map(|i| truncate(row.get(i).map(String::as_str).unwrap_or("")))
+                .collect()
+        })
+        .collect();
+
+    let char_width = FONT_9X18.character_size.width + FONT_9X18.character_spacing;
+    let char_height = FONT_9X18.character_size.height;
+
+    let column_widths: Vec<u32> = (0..columns)
+        .map(|i| {
+            let chars = rows.iter().map(|row| row[i].chars().count()).max();
+            chars.unwrap_or(0) as u32 * char_width + 2 * PAD_X
+        })
+        .collect();
+    let row_height = char_height + 2 * PAD_Y;
+
+    let width = column_widths.iter().sum::<u32>() + 1;
+    let height = row_height * rows.len() as u32 + 1;
+    let mut canvas = Canvas::new(width, height, SCALE, BACKGROUND)?;
+
+    // Header background
+    Rectangle::new(Point::zero(), Size::new(width, row_height))
+        .into_styled(PrimitiveStyle::with_fill(HEADER_BACKGROUND))
+        .draw(&mut canvas)?;
+
+    let text_style = MonoTextStyle::new(&FONT_9X18, TEXT);
+    let mut y = 0;
+    for row in &rows {
+        let mut x = 0;
+        for (i, cell) in row.iter().enumerate() {
+            let text_width = cell.chars().count() as u32 * char_width;
+            let free = column_widths[i] - 2 * PAD_X - text_width;
+            let offset = match table.alignments.get(i) {
+                Some(ColumnAlignment::Right) => free,
+                Some(ColumnAlignment::Center) => free / 2,
+                _ => 0,
+            };
+
+            Text::with_baseline(
+                cell,
+                Point::new((x + PAD_X + offset) as i32, (y + PAD_Y) as i32),
+                text_style,
+                Baseline::Top,
+            )
+            .draw(&mut canvas)?;
+
+            x += column_widths[i];
+        }
+        y += row_height;
+    }
+
+    // Grid lines
+    let grid = PrimitiveStyle::with_stroke(GRID, 1);
+    for row in 0..=rows.len() as u32 {
+        let y = (row * row_height) as i32;
+        Line::new(Point::new(0, y), Point::new(width as i32 - 1, y))
+            .into_styled(grid)
+            .draw(&mut canvas)?;
+    }
+    let mut x = 0;
+    for column_width in std::iter::once(0).chain(column_widths.iter().copied()) {
+        x += column_width;
+        Line::new(
+            Point::new(x as i32, 0),
+            Point::new(x as i32, height as i32 - 1),
+        )
+        .into_styled(grid)
+        .draw(&mut canvas)?;
+    }
+
+    canvas.to_png()
+}
+
+/// Shorten overly long cell text so a single cell can't blow up the image width
+fn truncate(text: &str) -> String {
+    if text.chars().count() <= MAX_CELL_CHARS {
+        return text.to_string();
+    }
+
+    let mut truncated: String = text.chars().take(MAX_CELL_CHARS - 3).collect();
+    truncated.push_str("...");
+    truncated
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render_table_png() {
+        let table = Table {
+            alignments: vec![ColumnAlignment::Left, ColumnAlignment::Right],
+            header: vec!["Name".to_string(), "Speed".to_string()],
+            rows: vec![vec!["Rust".to_string()], vec![]],
+        };
+
+        let png = render_table_png(&table).unwrap();
+
+        assert!(png.starts_with(b"\x89PNG"));
+    }
+
+    #[test]
+    fn test_truncate_long_cells() {
+        let long = "x".repeat(100);
+
+        assert_eq!(truncate(&long).chars().count(), MAX_CELL_CHARS);
+        assert!(truncate(&long).ends_with("..."));
+        assert_eq!(truncate("short"), "short");
+    }
+}
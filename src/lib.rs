@@ -1,4 +1,7 @@
 pub mod cli;
+pub mod images;
+pub mod llm;
 pub mod models;
 pub mod parsers;
 pub mod platforms;
+pub mod translate;
@@ -0,0 +1,166 @@
+pub mod suggest;
+pub mod summary;
+
+pub use suggest::{suggest_metadata, MetaSuggestions, SuggestField};
+pub use summary::summarize_with_llm;
+
+use anyhow::{Context, Result};
+use reqwest::Client;
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+
+use crate::cli::LlmConfig;
+use crate::platforms::http_client;
+use crate::platforms::rate_limit::RateLimiter;
+
+/// Shared by every LLM request of a run
+static LLM_RATE_LIMIT: RateLimiter = RateLimiter::new("LLM", Duration::from_secs(10));
+
+/// Client for an OpenAI-compatible chat completions endpoint (OpenAI, Ollama, llama.cpp, ...)
+pub struct LlmClient {
+    client: Client,
+    endpoint: String,
+    api_key: Option<String>,
+    model: String,
+}
+
+/// Request to POST <endpoint> (chat completions)
+#[derive(Debug, Serialize)]
+struct ChatRequest<'a> {
+    model: &'a str,
+    messages: [ChatMessage<'a>; 2],
+    temperature: f32,
+}
+
+#[derive(Debug, Serialize)]
+struct ChatMessage<'a> {
+    role: &'static str,
+    content: &'a str,
+}
+
+/// Response from POST <endpoint> (chat completions)
+#[derive(Debug, Deserialize)]
+struct ChatResponse {
+    choices: Vec<ChatChoice>,
+}
+
+#[derive(Debug, Deserialize)]
+struct ChatChoice {
+    message: ChatReply,
+}
+
+#[derive(Debug, Deserialize)]
+struct ChatReply {
+    #[serde(default)]
+    content: String,
+}
+
+impl LlmClient {
+    /// Create a client for a chat completions URL and model
+    pub fn new(endpoint: String, model: String) -> Self {
+        Self {
+            client: http_client(),
+            endpoint,
+            api_key: None,
+            model,
+        }
+    }
+
+    /// Create a client from the `[llm]` config section
+    ///
+    /// Fails unless an endpoint and model are configured: nothing is sent anywhere by default.
+    pub fn from_config(config: &LlmConfig) -> Result<Self> {
+        let (Some(endpoint), Some(model)) = (&config.endpoint, &config.model) else {
+            anyhow::bail!(
+                "No LLM is configured - set `endpoint` (an OpenAI-compatible chat completions \
+                 URL) and `model` in the [llm] section of the config file"
+            );
+        };
+
+        let client = Self::new(endpoint.clone(), model.clone());
+        Ok(match config.api_key.as_deref().map(str::trim) {
+            Some(key) if !key.is_empty() => client.with_api_key(key.to_string()),
+            _ => client,
+        })
+    }
+
+    /// Send a bearer token with each request (not needed for local servers)
+    pub fn with_api_key(mut self, api_key: String) -> Self {
+        self.api_key = Some(api_key);
+        self
+    }
+
+    /// Model name sent with each request
+    pub fn model(&self) -> &str {
+        &self.model
+    }
+
+    /// Ask the model, returning its reply
+    pub async fn complete(&self, system: &str, prompt: &str) -> Result<String> {
+        let body = ChatRequest {
+            model: &self.model,
+            messages: [
+                ChatMessage {
+                    role: "system",
+                    content: system,
+                },
+                ChatMessage {
+                    role: "user",
+                    content: prompt,
+                },
+            ],
+            temperature: 0.3,
+        };
+
+        let mut request = self.client.post(&self.endpoint).json(&body);
+        if let Some(ref key) = self.api_key {
+            request = request.bearer_auth(key);
+        }
+
+        let response = LLM_RATE_LIMIT
+            .send(request)
+            .await
+            .context(format!("Failed to send request to {}", self.endpoint))?;
+
+        let status = response.status();
+        if !status.is_success() {
+            let error_body = response
+                .text()
+                .await
+                .unwrap_or_else(|_| "Unable to read error response".to_string());
+
+            let message = match status.as_u16() {
+                401 | 403 => "LLM endpoint rejected the API key",
+                404 => "LLM endpoint or model not found",
+                _ => "LLM endpoint error",
+            };
+            anyhow::bail!(
+                "{} (status {}): {}",
+                message,
+                status.as_u16(),
+                error_body.trim()
+            );
+        }
+
+        let response: ChatResponse = response
+            .json()
+            .await
+            .context("Failed to parse LLM response (expected an OpenAI-style chat completion)")?;
+
+        response
+            .choices
+            .into_iter()
+            .next()
+            .map(|choice| choice.message.content)
+            .filter(|content| !content.trim().is_empty())
+            .context("LLM returned an empty reply")
+    }
+}
+
+/// The JSON object in a reply (models often wrap it in a code fence or add a sentence)
+pub(crate) fn extract_json(reply: &str) -> Result<&str> {
+    match (reply.find('{'), reply.rfind('}')) {
+        (Some(start), Some(end)) if start < end => Ok(&reply[start..=end]),
+        _ => anyhow::bail!("LLM reply contains no JSON object: {}", reply.trim()),
+    }
+}
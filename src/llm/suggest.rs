@@ -0,0 +1,155 @@
+use anyhow::{Context, Result};
+use serde::Deserialize;
+
+use crate::llm::{extract_json, LlmClient};
+use crate::models::Article;
+use crate::parsers::DEVTO_LIMITS;
+
+/// Most characters of the article body sent to the model
+const MAX_BODY_CHARS: usize = 12_000;
+
+/// Instructions sent with every suggestion request
+const SYSTEM_PROMPT: &str = "You suggest metadata for technical blog articles. \
+    Reply with a single JSON object and nothing else.";
+
+/// Frontmatter fields `suggest --accept` can write
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SuggestField {
+    Title,
+    Description,
+    Tags,
+}
+
+impl std::str::FromStr for SuggestField {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "title" => Ok(SuggestField::Title),
+            "description" => Ok(SuggestField::Description),
+            "tags" => Ok(SuggestField::Tags),
+            _ => Err(format!(
+                "Unknown field: '{}'. Valid options: title, description, tags",
+                s
+            )),
+        }
+    }
+}
+
+/// Metadata proposed by the model for an article
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct MetaSuggestions {
+    /// SEO-friendly title variant
+    pub title: Option<String>,
+
+    /// One or two sentence description
+    pub description: Option<String>,
+
+    /// Tags, normalized to dev.to's rules (lowercase letters and digits, at most 4)
+    pub tags: Vec<String>,
+}
+
+/// Reply the model is asked for
+#[derive(Debug, Deserialize)]
+struct SuggestionReply {
+    title: Option<String>,
+    description: Option<String>,
+    #[serde(default)]
+    tags: Vec<String>,
+}
+
+/// Ask the model for a title variant, a description and tags for an article
+pub async fn suggest_metadata(client: &LlmClient, article: &Article) -> Result<MetaSuggestions> {
+    let reply = client
+        .complete(SYSTEM_PROMPT, &suggestion_prompt(article))
+        .await
+        .context(format!("Failed to get suggestions for '{}'", article.title))?;
+
+    parse_suggestions(&reply)
+}
+
+/// Prompt describing the article and the fields wanted
+fn suggestion_prompt(article: &Article) -> String {
+    let body = match article.content.char_indices().nth(MAX_BODY_CHARS) {
+        Some((end, _)) => &article.content[..end],
+        None => &article.content,
+    };
+    let tags = if article.tags.is_empty() {
+        "(none)".to_string()
+    } else {
+        article.tags.join(", ")
+    };
+
+    format!(
+        "Suggest metadata for the article below, as JSON with these keys:\n\
+         - \"title\": an SEO-friendly variant of the title, under 70 characters\n\
+         - \"description\": one or two sentences summarizing the article, under 160 characters\n\
+         - \"tags\": up to {} lowercase tags, letters and digits only, most relevant first\n\n\
+         Current title: {}\nCurrent tags: {}\n\nArticle:\n{}",
+        DEVTO_LIMITS.max_tags,
+        article.title,
+        tags,
+        body.trim()
+    )
+}
+
+/// Read the suggestions out of a reply
+fn parse_suggestions(reply: &str) -> Result<MetaSuggestions> {
+    let json = extract_json(reply)?;
+    let parsed: SuggestionReply =
+        serde_json::from_str(json).context(format!("Failed to parse LLM suggestions: {}", json))?;
+
+    let mut tags: Vec<String> = Vec::new();
+    for tag in parsed.tags {
+        let tag: String = tag
+            .chars()
+            .filter(|c| c.is_alphanumeric())
+            .flat_map(char::to_lowercase)
+            .collect();
+        if !tag.is_empty() && !tags.contains(&tag) && tags.len() < DEVTO_LIMITS.max_tags {
+            tags.push(tag);
+        }
+    }
+    let text = |value: Option<String>| {
+        value
+            .map(|value| value.trim().to_string())
+            .filter(|value| !value.is_empty())
+    };
+
+    Ok(MetaSuggestions {
+        title: text(parsed.title),
+        description: text(parsed.description),
+        tags,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_suggestions() {
+        let reply = "Here you go:\n```json\n{\"title\": \" Rust Errors, Explained \", \
+                     \"description\": \"How ? works.\", \
+                     \"tags\": [\"Rust\", \"error-handling\", \"rust\", \"cli\", \"tips\", \"extra\"]}\n```";
+        let suggestions = parse_suggestions(reply).unwrap();
+
+        assert_eq!(suggestions.title.as_deref(), Some("Rust Errors, Explained"));
+        assert_eq!(suggestions.description.as_deref(), Some("How ? works."));
+        assert_eq!(
+            suggestions.tags,
+            vec!["rust", "errorhandling", "cli", "tips"]
+        );
+
+        assert!(parse_suggestions("I can't help with that.").is_err());
+    }
+
+    #[test]
+    fn test_suggestion_prompt_truncates_body() {
+        let article = Article::new("Long".to_string(), "é".repeat(MAX_BODY_CHARS + 10));
+        let prompt = suggestion_prompt(&article);
+
+        assert!(prompt.contains("Current tags: (none)"));
+        assert_eq!(prompt.matches('é').count(), MAX_BODY_CHARS);
+    }
+}
@@ -0,0 +1,100 @@
+use anyhow::{Context, Result};
+use serde::Deserialize;
+
+use crate::llm::{extract_json, LlmClient};
+use crate::models::Article;
+use crate::parsers::summary::{hashtags, SocialSummary};
+
+/// Most characters of the article body sent to the model
+const MAX_BODY_CHARS: usize = 8_000;
+
+/// Instructions sent with every summary request
+const SYSTEM_PROMPT: &str = "You write short announcements of technical blog articles \
+    for social media. Reply with a single JSON object and nothing else.";
+
+/// Reply the model is asked for
+#[derive(Debug, Deserialize)]
+struct SummaryReply {
+    blurb: String,
+    #[serde(default)]
+    hashtags: Vec<String>,
+}
+
+/// Ask the model for a blurb and hashtags announcing an article
+///
+/// Hashtags fall back to the article's tags if the model suggests none.
+pub async fn summarize_with_llm(client: &LlmClient, article: &Article) -> Result<SocialSummary> {
+    let reply = client
+        .complete(SYSTEM_PROMPT, &summary_prompt(article))
+        .await
+        .context(format!("Failed to summarize '{}'", article.title))?;
+
+    parse_summary(&reply, article)
+}
+
+/// Prompt describing the article and the announcement wanted
+fn summary_prompt(article: &Article) -> String {
+    let body = match article.content.char_indices().nth(MAX_BODY_CHARS) {
+        Some((end, _)) => &article.content[..end],
+        None => &article.content,
+    };
+
+    format!(
+        "Announce the article below, as JSON with these keys:\n\
+         - \"blurb\": one or two plain sentences (no hashtags, emoji or links), under 200 characters\n\
+         - \"hashtags\": up to 3 hashtags, most relevant first\n\n\
+         Title: {}\nTags: {}\n\nArticle:\n{}",
+        article.title,
+        article.tags.join(", "),
+        body.trim()
+    )
+}
+
+/// Read the summary out of a reply
+fn parse_summary(reply: &str, article: &Article) -> Result<SocialSummary> {
+    let json = extract_json(reply)?;
+    let parsed: SummaryReply =
+        serde_json::from_str(json).context(format!("Failed to parse LLM summary: {}", json))?;
+
+    let blurb = parsed
+        .blurb
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ");
+    if blurb.is_empty() {
+        anyhow::bail!("LLM returned an empty blurb");
+    }
+    let mut tags = hashtags(&parsed.hashtags);
+    if tags.is_empty() {
+        tags = hashtags(&article.tags);
+    }
+
+    Ok(SocialSummary {
+        blurb,
+        hashtags: tags,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_summary() {
+        let article =
+            Article::new("T".to_string(), String::new()).with_tags(vec!["rust".to_string()]);
+
+        let summary = parse_summary(
+            "```json\n{\"blurb\": \" Errors,\\n made easy. \", \"hashtags\": [\"#RustLang\", \"cli\"]}\n```",
+            &article,
+        )
+        .unwrap();
+        assert_eq!(summary.blurb, "Errors, made easy.");
+        assert_eq!(summary.hashtags, vec!["#RustLang", "#cli"]);
+
+        let summary = parse_summary("{\"blurb\": \"Errors.\"}", &article).unwrap();
+        assert_eq!(summary.hashtags, vec!["#rust"]);
+
+        assert!(parse_summary("{\"blurb\": \"  \"}", &article).is_err());
+    }
+}
@@ -1,41 +1,178 @@
-mod cli;
-mod models;
-mod parsers;
-mod platforms;
-
 use anyhow::{Context, Result};
+use article_cross_poster::cli::cache;
+use article_cross_poster::cli::ci::{self, RunStatus};
+use article_cross_poster::cli::doctor::{self, CheckStatus};
+use article_cross_poster::cli::git::changed_markdown_files;
+use article_cross_poster::cli::journal::Journal;
+use article_cross_poster::cli::queue::{
+    append_log, min_publish_interval, parse_publish_time, Queue, QueueItem,
+};
+use article_cross_poster::cli::{
+    content_hash, ArticleDiscussion, ArticleState, BackupManifest, Cli, Commands, Config,
+    ConfigAction, ContentFormat, PlanStep, Platform, PlatformRecord, PostArgs, PostSeriesArgs,
+    PublishManifest, PublishPlan, QueueAction, State,
+};
+use article_cross_poster::images::{
+    cover_issues, image_info, render_cover_png, CoverRequirements, DEVTO_COVER, MEDIUM_COVER,
+};
+use article_cross_poster::llm::{suggest_metadata, summarize_with_llm, LlmClient, SuggestField};
+use article_cross_poster::models::{is_local_path, Article};
+use article_cross_poster::parsers::{
+    add_series_links, collect_markdown_files, compute_stats, default_file_name,
+    edit_frontmatter_file, fetch_devto_draft, fetch_from_devto_url, find_series_parts,
+    init_drafts_by_default, is_devto_input, load_series, markdown_to_html, parse_devto_draft_ref,
+    parse_devto_url, parse_markdown, preview_file_name, read_medium_export, render_article,
+    render_html_page, render_terminal, scaffold_article, set_frontmatter_field,
+    set_frontmatter_list, summarize, update_frontmatter_file, validate_path, Cleaner,
+    SanitizeOptions, ScaffoldVars, SeriesLink, SeriesPart, Severity, ValidationReport,
+};
+use article_cross_poster::platforms::{
+    self, http_log, DevToClient, ImportedArticle, MediumClient, PreparedRequest, PreviewRequest,
+    RequestPreview,
+};
+use article_cross_poster::translate::{self, translate_article, translation_path, DeepLClient};
 use clap::Parser;
-use cli::{ArticleState, Cli, Commands, Config, ConfigAction, ContentFormat, Platform};
-use models::Article;
-use parsers::{clean_ai_artifacts, fetch_from_devto_url, parse_devto_url, parse_markdown};
-use platforms::{DevToClient, MediumClient};
+use std::collections::HashMap;
 use std::fs;
+use std::io::{Read, Write};
 use std::path::Path;
+use std::time::{Duration, Instant};
 
 #[tokio::main]
 async fn main() -> Result<()> {
     let cli = Cli::parse();
+    ci::init(cli.ci);
+    cache::init(cli.no_cache);
+    if cli.debug_http || cli.record_http.is_some() {
+        let trace = if cli.debug_http {
+            Some(http_log::default_log_path()?)
+        } else {
+            None
+        };
+        http_log::init(trace.as_deref(), cli.record_http.as_deref())?;
+        if let Some(ref path) = trace {
+            eprintln!("Logging HTTP requests to {}", path.display());
+        }
+    }
+    // A broken config file is reported by the commands that need it
+    if let Ok(config) = Config::load_settings() {
+        init_drafts_by_default(config.defaults.draft);
+    }
+
+    // Ctrl-C drops the running command, which cancels any request in flight. The state
+    // file and batch journal are saved as each platform and file finishes, so whatever
+    // was published before the interrupt is already on disk.
+    let batch = matches!(&cli.command, Commands::Post(args)
+        if args.resume || args.changed_since.is_some() || Path::new(&args.input).is_dir());
+    let manifest = match &cli.command {
+        Commands::Post(args) => args.manifest.clone(),
+        _ => None,
+    };
+    let started_at = chrono::Utc::now();
+    let mut interrupted = false;
+    let result = tokio::select! {
+        result = run_command(cli.command) => result,
+        _ = tokio::signal::ctrl_c() => {
+            interrupted = true;
+            let _ = std::io::stdout().flush();
+            eprintln!("\n\n{}", ci::partial_summary(&ci::results()));
+            match Journal::load() {
+                Ok(Some(journal)) if batch => eprintln!(
+                    "Run `post {} --resume` to finish the batch.",
+                    journal.input
+                ),
+                _ => eprintln!(
+                    "Run the command again to finish - platforms already published are skipped."
+                ),
+            }
+            Err(anyhow::anyhow!("Interrupted"))
+        }
+    };
+
+    if let Some(path) = ci::summary_path(cli.summary) {
+        if let Err(e) = ci::write_summary(&path, &ci::results()) {
+            ci::warning(None, &format!("{:#}", e));
+        }
+    }
+    if let Some(path) = manifest {
+        let manifest =
+            PublishManifest::from_results(&ci::results(), started_at, &result, interrupted);
+        if let Err(e) = manifest.write(&path) {
+            ci::warning(None, &format!("{:#}", e));
+        }
+    }
+
+    if let Err(e) = &result {
+        if ci::is_enabled() {
+            println!("{}", ci::annotation("error", None, &format!("{:#}", e)));
+        }
+    }
 
-    match cli.command {
+    if interrupted {
+        // Conventional exit code for a process stopped by SIGINT
+        std::process::exit(130);
+    }
+
+    result
+}
+
+/// Run a command to completion
+async fn run_command(command: Commands) -> Result<()> {
+    match command {
         Commands::Config { action } => handle_config_command(action),
-        Commands::Post {
+        Commands::Doctor => handle_doctor_command().await,
+        Commands::Queue { action } => handle_queue_command(action).await,
+        Commands::Post(args) => handle_post_command(args).await,
+        Commands::PostSeries(args) => handle_post_series_command(args).await,
+        Commands::New {
+            title,
+            tags,
+            template,
+            output,
+        } => handle_new_command(title, tags, template, output),
+        Commands::Preview {
             input,
+            clean_ai,
+            open,
+            render,
             platforms,
+            format,
+            output,
+        } => handle_preview_command(input, clean_ai, open, render, platforms, format, output).await,
+        Commands::Sync {
+            path,
             clean_ai,
-            tags,
-            canonical,
             dry_run,
+        } => handle_sync_command(path, clean_ai, dry_run).await,
+        Commands::Validate {
+            path,
+            platforms,
             format,
-        } => {
-            handle_post_command(input, platforms, clean_ai, tags, canonical, dry_run, format).await
-        }
-        Commands::Preview { input, clean_ai } => handle_preview_command(input, clean_ai).await,
+            json,
+        } => handle_validate_command(path, platforms, format, json),
         Commands::List {
             platform,
             page,
             per_page,
             state,
         } => handle_list_command(platform, page, per_page, state).await,
+        Commands::Import {
+            platform,
+            output,
+            archive,
+            dry_run,
+        } => handle_import_command(platform, output, archive, dry_run).await,
+        Commands::Backup {
+            output,
+            platforms,
+            medium_archive,
+        } => handle_backup_command(output, platforms, medium_archive).await,
+        Commands::Suggest { input, accept } => handle_suggest_command(input, accept).await,
+        Commands::Blurb {
+            input,
+            max_chars,
+            no_llm,
+        } => handle_blurb_command(input, max_chars, no_llm).await,
         Commands::Fetch { id, platform } => handle_fetch_command(id, platform).await,
     }
 }
@@ -49,15 +186,300 @@ fn handle_config_command(action: ConfigAction) -> Result<()> {
     }
 }
 
+/// Handle doctor command - print each setup check with a fix for the failing ones
+async fn handle_doctor_command() -> Result<()> {
+    println!("Checking setup...\n");
+    let checks = doctor::run_checks().await;
+    for check in &checks {
+        println!("{}", check);
+    }
+
+    let count = |status| checks.iter().filter(|c| c.status == status).count();
+    let (warnings, failed) = (count(CheckStatus::Warn), count(CheckStatus::Fail));
+    println!(
+        "\n{} passed, {} warnings, {} failed",
+        count(CheckStatus::Pass),
+        warnings,
+        failed
+    );
+    if failed > 0 {
+        anyhow::bail!("{} setup check(s) failed", failed);
+    }
+    Ok(())
+}
+
+/// Handle new command - scaffold an article with frontmatter
+fn handle_new_command(
+    title: String,
+    tags: Vec<String>,
+    template: Option<String>,
+    output: Option<String>,
+) -> Result<()> {
+    let template_content = match template {
+        Some(name) => {
+            let path = Config::templates_dir()?.join(format!("{}.md", name));
+            let content = fs::read_to_string(&path).context(format!(
+                "Failed to read template '{}' from {}",
+                name,
+                path.display()
+            ))?;
+            Some(content)
+        }
+        None => None,
+    };
+
+    let date = chrono::Local::now().format("%Y-%m-%d").to_string();
+    let vars = ScaffoldVars {
+        title: &title,
+        tags: &tags,
+        date: &date,
+    };
+    let content = scaffold_article(&vars, template_content.as_deref());
+
+    let output = output.unwrap_or_else(|| default_file_name(&title));
+
+    // create_new refuses to overwrite an existing article
+    let mut file = fs::OpenOptions::new()
+        .write(true)
+        .create_new(true)
+        .open(&output)
+        .context(format!(
+            "Failed to create {} (does it already exist?)",
+            output
+        ))?;
+    file.write_all(content.as_bytes())
+        .context(format!("Failed to write {}", output))?;
+
+    println!("Created article: {}", output);
+
+    Ok(())
+}
+
+/// Render a cover image for an article without one, if enabled in the config
+///
+/// The PNG is written to the covers directory and set as a local `cover_image`,
+/// so it is uploaded like any other local cover.
+fn generate_cover(article: &mut Article) -> Result<()> {
+    let config = Config::load_settings().context("Failed to load config")?;
+    if !config.cover.generate || article.cover_image.is_some() {
+        return Ok(());
+    }
+
+    let style = config.cover.style()?;
+    let png = render_cover_png(&article.title, &style).context("Failed to render cover image")?;
+
+    let covers_dir = Config::covers_dir()?;
+    fs::create_dir_all(&covers_dir).context("Failed to create covers directory")?;
+    let path = covers_dir.join(Path::new(&default_file_name(&article.title)).with_extension("png"));
+    fs::write(&path, png).context(format!("Failed to write cover image: {}", path.display()))?;
+
+    println!("Generated cover image: {}", path.display());
+    article.cover_image = Some(path.display().to_string());
+
+    Ok(())
+}
+
+/// Bytes of a cover image read to find its dimensions
+const COVER_PROBE_BYTES: usize = 64 * 1024;
+
+/// Check the cover image's dimensions and size against each target platform
+///
+/// Only the first bytes of a remote cover are downloaded. Guidance is printed as
+/// warnings; a cover that cannot be loaded at all is an error. A network failure
+/// while checking only warns, since the platform may still reach the image.
+async fn check_cover_image(article: &Article, platforms: &[Platform]) -> Result<()> {
+    let Some(ref cover) = article.cover_image else {
+        return Ok(());
+    };
+
+    let (data, size) = if is_local_path(cover) {
+        let size = fs::metadata(cover)
+            .context(format!("Cover image not found: {}", cover))?
+            .len();
+        let mut data = Vec::new();
+        fs::File::open(cover)
+            .and_then(|file| file.take(COVER_PROBE_BYTES as u64).read_to_end(&mut data))
+            .context(format!("Failed to read cover image: {}", cover))?;
+        (data, Some(size))
+    } else if cover.starts_with("http://") || cover.starts_with("https://") {
+        match fetch_cover_prefix(cover).await {
+            Ok(Some(prefix)) => prefix,
+            Ok(None) => anyhow::bail!(
+                "Cover image {} could not be loaded - fix cover_image or remove it before publishing",
+                cover
+            ),
+            Err(e) => {
+                ci::warning(None, &format!("Could not check the cover image: {:#}", e));
+                return Ok(());
+            }
+        }
+    } else {
+        return Ok(());
+    };
+
+    let info = image_info(&data);
+    let mut errors = Vec::new();
+    for platform in platforms {
+        let requirements: &CoverRequirements = match platform {
+            Platform::DevTo => &DEVTO_COVER,
+            Platform::Medium => &MEDIUM_COVER,
+        };
+        for (severity, message) in cover_issues(info.as_ref(), size, requirements) {
+            match severity {
+                Severity::Error => errors.push(message),
+                Severity::Warning => ci::warning(None, &message),
+            }
+        }
+    }
+
+    if !errors.is_empty() {
+        anyhow::bail!("{}", errors.join("\n"));
+    }
+
+    Ok(())
+}
+
+/// Download the start of a remote cover image, with the total size if the server reports it
+///
+/// Returns `None` if the server answers with an error status.
+async fn fetch_cover_prefix(url: &str) -> Result<Option<(Vec<u8>, Option<u64>)>> {
+    let request = platforms::http_client()
+        .get(url)
+        .header("Range", format!("bytes=0-{}", COVER_PROBE_BYTES - 1))
+        .header("User-Agent", "article-cross-poster/0.1.0");
+    let mut response = http_log::send_traced(request)
+        .await
+        .context(format!("Failed to download {}", url))?;
+
+    if !response.status().is_success() {
+        return Ok(None);
+    }
+
+    // A ranged response reports the full size after the slash: `bytes 0-65535/482113`
+    let size = match response.headers().get("content-range") {
+        Some(range) => range
+            .to_str()
+            .ok()
+            .and_then(|range| range.rsplit('/').next())
+            .and_then(|total| total.parse().ok()),
+        None => response.content_length(),
+    };
+
+    let mut data = Vec::new();
+    while data.len() < COVER_PROBE_BYTES {
+        match response
+            .chunk()
+            .await
+            .context(format!("Failed to download {}", url))?
+        {
+            Some(chunk) => data.extend_from_slice(&chunk),
+            None => break,
+        }
+    }
+
+    Ok(Some((data, size)))
+}
+
+/// Give a local article without a `slug` one, if `[frontmatter] write_slug` is enabled
+///
+/// Returns the new slug, which is written back to the file after a successful post.
+fn assign_slug(article: &mut Article, input: &str) -> Result<Option<String>> {
+    if article.slug.is_some() || is_devto_input(input) {
+        return Ok(None);
+    }
+
+    let config = Config::load_settings().context("Failed to load config")?;
+    if !config.frontmatter.write_slug {
+        return Ok(None);
+    }
+
+    let slug = config.canonical.slug_for(article, Some(Path::new(input)));
+    if slug.is_empty() {
+        return Ok(None);
+    }
+
+    article.slug = Some(slug.clone());
+    Ok(Some(slug))
+}
+
+/// Fill in a missing canonical URL from `[canonical] base_url`, if configured
+///
+/// Only applies to local files; articles fetched from dev.to keep their own metadata.
+fn derive_canonical_url(article: &mut Article, input: &str) -> Result<()> {
+    if article.canonical_url.is_some() || is_devto_input(input) {
+        return Ok(());
+    }
+
+    let config = Config::load_settings().context("Failed to load config")?;
+    article.canonical_url = config
+        .canonical
+        .derive_url(article, Some(Path::new(input)))?;
+
+    Ok(())
+}
+
+/// Fill in the Medium license and follower notification from `[medium]`, if configured
+fn apply_medium_defaults(article: &mut Article) -> Result<()> {
+    let config = Config::load_settings().context("Failed to load config")?;
+    config.apply_medium_defaults(article);
+
+    Ok(())
+}
+
+/// Build the AI cleaner with the custom rules from the config file (if any)
+fn load_cleaner() -> Result<Cleaner> {
+    let config = Config::load_settings().context("Failed to load config")?;
+    Cleaner::new(&config.cleaner.rules).context("Failed to load cleaner rules from config")
+}
+
 /// Handle preview command - show processed content without posting
-async fn handle_preview_command(input: String, clean_ai: bool) -> Result<()> {
+async fn handle_preview_command(
+    input: String,
+    clean_ai: bool,
+    open: bool,
+    render: bool,
+    platforms: Vec<Platform>,
+    format: ContentFormat,
+    output: Option<String>,
+) -> Result<()> {
+    if output.is_some() && platforms.len() > 1 {
+        anyhow::bail!("--output writes one platform's content - pass a single platform to --to");
+    }
+
     println!("Loading article from: {}", input);
 
     let mut article = load_article(&input).await?;
+    derive_canonical_url(&mut article, &input)?;
 
     if clean_ai {
         println!("Applying AI artifact cleaning...");
-        article.content = clean_ai_artifacts(&article.content);
+        article.content = load_cleaner()?.clean(&article.content);
+    }
+
+    if !platforms.is_empty() {
+        apply_medium_defaults(&mut article)?;
+        return preview_for_platforms(
+            &article,
+            &input,
+            &platforms,
+            &format,
+            render,
+            output.as_deref(),
+        );
+    }
+
+    if open {
+        return open_html_preview(&article);
+    }
+
+    if let Some(ref output) = output {
+        let content = match format {
+            ContentFormat::Markdown => render_article(&article, None),
+            ContentFormat::Html => {
+                render_html_page(&article).context("Failed to render HTML preview")?
+            }
+        };
+        return write_preview(output, &content);
     }
 
     println!("\n--- PREVIEW ---\n");
@@ -76,149 +498,1551 @@ async fn handle_preview_command(input: String, clean_ai: bool) -> Result<()> {
     }
     println!("Published: {}", article.published);
     println!("\n--- CONTENT ---\n");
-    println!("{}", article.content);
+    if render {
+        print!("{}", render_terminal(&article.content));
+    } else {
+        println!("{}", article.content);
+    }
     println!("\n--- END PREVIEW ---");
 
     Ok(())
 }
 
-/// Handle post command - publish article to platforms
-async fn handle_post_command(
-    input: String,
-    platforms: Vec<Platform>,
-    clean_ai: bool,
-    tags_override: Option<Vec<String>>,
-    canonical_override: Option<String>,
-    dry_run: bool,
-    format: ContentFormat,
+/// Show each platform's article exactly as it would be published, with the warnings raised
+///
+/// Runs the same preparation as a dry run (sanitization, tag handling, series links,
+/// format conversion) and fails if any platform would reject the article.
+fn preview_for_platforms(
+    article: &Article,
+    input: &str,
+    platforms: &[Platform],
+    format: &ContentFormat,
+    render: bool,
+    output: Option<&str>,
 ) -> Result<()> {
-    println!("Loading article from: {}", input);
+    let config = Config::load_settings().context("Failed to load config")?;
+    let state = State::load().context("Failed to load publication state")?;
+    let mut failed = 0;
 
-    let mut article = load_article(&input).await?;
+    for platform in platforms {
+        println!("\n--- {} PREVIEW ---\n", platform);
 
-    // Apply AI cleaning if requested
-    if clean_ai {
-        println!("Applying AI artifact cleaning...");
-        article.content = clean_ai_artifacts(&article.content);
+        let options = config.sanitize_options(platform);
+        let preview =
+            add_series_navigation(article, input, platform, &state).and_then(
+                |linked| match platform {
+                    Platform::DevTo => DevToClient::build_publish_request(&linked, &options)
+                        .map(|prepared| prepared_preview(&prepared)),
+                    Platform::Medium => {
+                        MediumClient::build_publish_request(&linked, format, &options)
+                            .map(|prepared| prepared_preview(&prepared))
+                    }
+                },
+            );
+
+        match preview {
+            Ok(preview) => {
+                for (label, value) in &preview.fields {
+                    println!("{}: {}", label, value);
+                }
+                if let Some(output) = output {
+                    // dev.to always receives markdown; convert it when HTML is asked for
+                    let content = match format {
+                        ContentFormat::Html if preview.format == "markdown" => {
+                            markdown_to_html(&preview.body)?
+                        }
+                        _ => preview.body,
+                    };
+                    write_preview(output, &content)?;
+                    continue;
+                }
+
+                println!("\n--- CONTENT ({}) ---\n", preview.format);
+                if render && preview.format == "markdown" {
+                    print!("{}", render_terminal(&preview.body));
+                } else {
+                    println!("{}", preview.body);
+                }
+            }
+            Err(e) => {
+                failed += 1;
+                println!("✗ {} would reject the article: {:#}", platform, e);
+            }
+        }
     }
+    println!("\n--- END PREVIEW ---");
 
-    // Apply overrides
-    if let Some(tags) = tags_override {
-        article.tags = tags;
+    if failed > 0 {
+        anyhow::bail!(
+            "{} platform(s) would reject the article - fix the errors above before posting",
+            failed
+        );
     }
-    if let Some(canonical) = canonical_override {
-        article.canonical_url = Some(canonical);
+
+    Ok(())
+}
+
+/// Write preview content to a file
+fn write_preview(output: &str, content: &str) -> Result<()> {
+    fs::write(output, content).context(format!("Failed to write {}", output))?;
+    println!("Wrote preview to {}", output);
+
+    Ok(())
+}
+
+/// Print a prepared request's warnings and assets, and return what it will publish
+fn prepared_preview<T: PreviewRequest>(prepared: &PreparedRequest<T>) -> RequestPreview {
+    prepared.print_warnings();
+    prepared.print_assets();
+    prepared.request.preview()
+}
+
+/// Write the article as a styled HTML page to the temp directory and open it in the browser
+fn open_html_preview(article: &Article) -> Result<()> {
+    let page = render_html_page(article).context("Failed to render HTML preview")?;
+    let path = std::env::temp_dir()
+        .join("article-cross-poster")
+        .join(preview_file_name(article));
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir).context(format!("Failed to create directory {}", dir.display()))?;
     }
+    fs::write(&path, page).context(format!("Failed to write {}", path.display()))?;
 
-    if dry_run {
-        println!("\n--- DRY RUN MODE ---");
-        println!(
-            "Would post to platforms: {}",
-            platforms
-                .iter()
-                .map(|p| p.to_string())
-                .collect::<Vec<_>>()
-                .join(", ")
+    println!("Wrote HTML preview to {}", path.display());
+    if let Err(e) = open::that(&path) {
+        eprintln!(
+            "⚠️  Warning: Could not open a browser ({}) - open the file above manually",
+            e
         );
-        println!("\nArticle details:");
-        println!("  Title: {}", article.title);
-        println!("  Tags: {}", article.tags.join(", "));
-        if let Some(ref canonical) = article.canonical_url {
-            println!("  Canonical URL: {}", canonical);
-        }
-        println!("  Published: {}", article.published);
-        println!("  Content length: {} characters", article.content.len());
-        println!("\n--- DRY RUN COMPLETE (no actual posting) ---");
-        return Ok(());
     }
 
-    // Load config for API credentials
-    let config = Config::load().context("Failed to load config. Run 'config init' first.")?;
+    Ok(())
+}
 
-    println!("\nPublishing to {} platform(s)...\n", platforms.len());
+/// Handle post command - publish article to platforms
+async fn handle_post_command(mut args: PostArgs) -> Result<()> {
+    let config = Config::load_settings().context("Failed to load config")?;
+    args.apply_defaults(&config.defaults);
 
-    let mut results = Vec::new();
+    if args.update.is_some() && Path::new(&args.input).is_dir() {
+        anyhow::bail!("--update takes a single article, not a directory");
+    }
+    if !args.translate.is_empty() {
+        if Path::new(&args.input).is_dir() {
+            anyhow::bail!("--translate takes a single article, not a directory");
+        }
+        if is_devto_input(&args.input) {
+            anyhow::bail!("--translate takes a local markdown file, not a dev.to article");
+        }
+    }
+    if args.resume {
+        return resume_batch(args).await;
+    }
+    if let Some(since) = args.changed_since.clone() {
+        return post_changed_articles(args, &since).await;
+    }
+    if Path::new(&args.input).is_dir() {
+        return post_directory(args).await;
+    }
 
-    for platform in platforms {
-        print!("Publishing to {}... ", platform);
+    let languages = args
+        .translate
+        .iter()
+        .map(|code| translate::parse_language(code))
+        .collect::<Result<Vec<_>>>()?;
+    let mut failed = post_article(args.clone(), false).await?;
+    if !languages.is_empty() {
+        failed.extend(post_translations(&args, &languages).await?);
+    }
+    if ci::is_enabled() && !failed.is_empty() {
+        anyhow::bail!("Publishing failed on: {}", failed_platform_names(&failed));
+    }
 
-        let result = match platform {
-            Platform::DevTo => {
-                let client = DevToClient::new(config.dev_to.api_key.clone());
-                publish_to_devto(&client, &article).await
-            }
-            Platform::Medium => {
-                let client = MediumClient::new(config.medium.access_token.clone());
-                publish_to_medium(&client, &article, &format).await
-            }
-        };
+    Ok(())
+}
 
-        match result {
-            Ok(url) => {
-                println!("✓ Success");
-                results.push((platform, Ok(url)));
-            }
-            Err(e) => {
-                println!("✗ Failed");
-                results.push((platform, Err(e)));
-            }
-        }
+/// Publish translations of an article (`post --translate de,es`), after the original
+///
+/// Each translation is a separate article in `<name>.<lang>.md` next to the original,
+/// with the original's canonical URL: its `canonical_url`, or else its URL on the first
+/// target platform. Existing translation files are posted as they are, so they can be
+/// reviewed and corrected before (re-)posting; missing ones are created with DeepL.
+async fn post_translations(
+    args: &PostArgs,
+    languages: &[String],
+) -> Result<Vec<(Platform, String)>> {
+    let mut original = load_article(&args.input).await?;
+    if let Some(ref canonical) = args.canonical {
+        original.canonical_url = Some(canonical.clone());
     }
+    derive_canonical_url(&mut original, &args.input)?;
+    let platforms = if args.platforms.is_empty() {
+        Platform::targets_for(&original, &args.default_platforms)?
+    } else {
+        args.platforms.clone()
+    };
 
-    // Display summary
-    println!("\n--- RESULTS ---");
-    for (platform, result) in results {
-        match result {
-            Ok(url) => {
-                println!("✓ {}: {}", platform, url);
+    let mut failed = Vec::new();
+    for language in languages {
+        let path = translation_path(Path::new(&args.input), language);
+        let input = path.display().to_string();
+        println!("\n=== Translation: {} ===\n", language);
+
+        if !path.exists() {
+            if args.dry_run {
+                println!("Would translate into {} and save it as {}", language, input);
+                continue;
             }
-            Err(e) => {
-                println!("✗ {}: Error", platform);
-                // Show full error chain with details
-                eprintln!("\nError details:");
-                eprintln!("{:#}", e);
+            if let Err(e) =
+                write_translation(&original, &args.input, &platforms, language, &path).await
+            {
+                eprintln!("✗ {}: {:#}", language, e);
+                // The translation would have gone to every target platform
+                let error = format!("Translation into {} failed: {:#}", language, e);
+                for platform in &platforms {
+                    ci::record(
+                        &input,
+                        platform,
+                        RunStatus::Failed,
+                        None,
+                        Some(error.clone()),
+                    );
+                    failed.push((platform.clone(), error.clone()));
+                }
+                continue;
             }
+            println!("Translated into {}: {}", language, input);
         }
+
+        let translation_args = PostArgs {
+            input,
+            platforms: platforms.clone(),
+            canonical: None,
+            translate: Vec::new(),
+            ..args.clone()
+        };
+        failed.extend(post_article(translation_args, false).await?);
     }
 
-    Ok(())
+    Ok(failed)
 }
 
-/// Handle list command - list articles from a platform
-async fn handle_list_command(
-    platform: Platform,
-    page: u32,
-    per_page: u32,
-    state: ArticleState,
+/// Translate an article and save the translation as a new markdown file
+async fn write_translation(
+    original: &Article,
+    original_input: &str,
+    platforms: &[Platform],
+    language: &str,
+    path: &Path,
 ) -> Result<()> {
-    let config = Config::load().context("Failed to load config. Run 'config init' first.")?;
+    let canonical = match original.canonical_url {
+        Some(ref url) => url.clone(),
+        None => {
+            let state = State::load().context("Failed to load publication state")?;
+            let key = state_key(original_input, original);
+            platforms
+                .iter()
+                .find_map(|platform| state.record(&key, platform))
+                .map(|record| record.url.clone())
+                .context(
+                    "The original has no canonical URL and was not published - \
+                     set canonical_url or post the original first",
+                )?
+        }
+    };
 
-    match platform {
-        Platform::DevTo => {
-            let client = DevToClient::new(config.dev_to.api_key.clone());
-            let articles = client
-                .list_articles(page, per_page, &state.to_string())
-                .await
-                .context("Failed to list dev.to articles")?;
+    let config = Config::load_settings().context("Failed to load config")?;
+    let client = DeepLClient::from_config(&config.translate)?;
+    println!("Translating into {} with DeepL...", language);
+    let translation = translate_article(&client, original, language, &canonical).await?;
 
-            println!(
-                "{} articles on dev.to (page {}):\n",
-                state
-                    .to_string()
-                    .chars()
-                    .next()
-                    .unwrap()
-                    .to_uppercase()
-                    .to_string()
-                    + &state.to_string()[1..],
-                page
-            );
+    let mut content = render_article(&translation, None);
+    content = set_frontmatter_field(&content, "lang", language)?;
+    if let Some(ref slug) = translation.slug {
+        content = set_frontmatter_field(&content, "slug", slug)?;
+    }
+    if let Some(license) = translation.medium_license {
+        content = set_frontmatter_field(&content, "medium_license", license.as_str())?;
+    }
+    if let Some(notify) = translation.notify_followers {
+        content = set_frontmatter_field(&content, "notify_followers", &notify.to_string())?;
+    }
+
+    fs::write(path, content).context(format!("Failed to write {}", path.display()))
+}
+
+/// Post every markdown file under a directory (`post <dir>`)
+async fn post_directory(args: PostArgs) -> Result<()> {
+    if args.tags.is_some() || args.canonical.is_some() {
+        anyhow::bail!("--tags and --canonical cannot be used when posting a directory");
+    }
+
+    let dir = Path::new(&args.input)
+        .canonicalize()
+        .context(format!("Invalid or inaccessible path: {}", args.input))?;
+    let files = collect_markdown_files(&dir)?;
+
+    if files.is_empty() {
+        println!("No markdown files found in {}", dir.display());
+        return Ok(());
+    }
+
+    println!(
+        "Posting {} markdown file(s) from {}",
+        files.len(),
+        dir.display()
+    );
+    let files = files.iter().map(|f| f.display().to_string()).collect();
+    post_batch(args, Journal::new(dir.display().to_string(), files)).await
+}
+
+/// Post every markdown file changed since a git revision (`post --changed-since`)
+///
+/// New articles are published. Articles already on dev.to are updated in place, and
+/// ones already on Medium are left alone, since Medium posts cannot be edited.
+async fn post_changed_articles(args: PostArgs, since: &str) -> Result<()> {
+    let input = Path::new(&args.input)
+        .canonicalize()
+        .context(format!("Invalid or inaccessible path: {}", args.input))?;
+    let files = changed_markdown_files(&input, since)?;
+
+    if files.is_empty() {
+        println!("No markdown files changed since {}", since);
+        return Ok(());
+    }
+
+    println!("{} markdown file(s) changed since {}:", files.len(), since);
+    for file in &files {
+        println!("  {}", file.display());
+    }
+
+    let files = files.iter().map(|f| f.display().to_string()).collect();
+    post_batch(args, Journal::new(input.display().to_string(), files)).await
+}
+
+/// Continue the last unfinished batch (`post <dir> --resume`)
+async fn resume_batch(args: PostArgs) -> Result<()> {
+    let input = Path::new(&args.input)
+        .canonicalize()
+        .context(format!("Invalid or inaccessible path: {}", args.input))?
+        .display()
+        .to_string();
+    let journal = Journal::load()?.context("No unfinished batch to resume")?;
+
+    if journal.input != input {
+        anyhow::bail!(
+            "The unfinished batch was started for {}, not {}",
+            journal.input,
+            input
+        );
+    }
+
+    let mut remaining = 0;
+    for file in &journal.files {
+        let pending = match batch_targets(&args, file).await {
+            Ok(targets) => !journal.pending(file, &targets).is_empty(),
+            Err(_) => true,
+        };
+        if pending {
+            remaining += 1;
+        }
+    }
+    println!(
+        "Resuming batch started {}: {} of {} file(s) left",
+        journal
+            .started_at
+            .with_timezone(&chrono::Local)
+            .format("%Y-%m-%d %H:%M"),
+        remaining,
+        journal.files.len()
+    );
+
+    post_batch(args, journal).await
+}
+
+/// Post the files of a batch, recording each outcome in the journal as it goes
+///
+/// Files and platforms the journal already shows as done are skipped. Articles already
+/// on dev.to are updated in place, and ones already on Medium are left alone. The
+/// journal is removed when every file succeeded; dry runs leave it untouched.
+async fn post_batch(args: PostArgs, mut journal: Journal) -> Result<()> {
+    let dry_run = args.dry_run;
+    let mut failed = 0;
+
+    // Saved up front so a batch interrupted during its first file can be resumed
+    if !dry_run {
+        if let Err(e) = journal.save() {
+            eprintln!("⚠️  Warning: Failed to save batch journal: {:#}", e);
+        }
+    }
+
+    for file in journal.files.clone() {
+        let targets = match batch_targets(&args, &file).await {
+            Ok(targets) => targets,
+            Err(e) => {
+                eprintln!("✗ {}: {:#}", file, e);
+                failed += 1;
+                continue;
+            }
+        };
+        if targets.is_empty() {
+            println!("\n= {}: excluded from every platform", file);
+            continue;
+        }
+        let platforms = journal.pending(&file, &targets);
+        if platforms.is_empty() {
+            println!("\n= {}: already done", file);
+            continue;
+        }
+
+        println!("\n=== {} ===\n", file);
+
+        let file_args = PostArgs {
+            input: file.clone(),
+            platforms: platforms.clone(),
+            changed_since: None,
+            resume: false,
+            ..args.clone()
+        };
+        let failures = match post_article(file_args, true).await {
+            Ok(failures) => failures,
+            Err(e) => {
+                eprintln!("✗ {}: {:#}", file, e);
+                let error = format!("{:#}", e);
+                platforms
+                    .iter()
+                    .map(|p| (p.clone(), error.clone()))
+                    .collect()
+            }
+        };
+
+        if !failures.is_empty() {
+            failed += 1;
+        }
+        for platform in &platforms {
+            let error = failures
+                .iter()
+                .find(|(failed, _)| failed == platform)
+                .map(|(_, error)| error.clone());
+            journal.record(&file, platform, error);
+        }
+
+        if !dry_run {
+            if let Err(e) = journal.save() {
+                eprintln!("⚠️  Warning: Failed to save batch journal: {:#}", e);
+            }
+        }
+    }
+
+    if failed > 0 {
+        let to = if args.platforms.is_empty() {
+            String::new()
+        } else {
+            format!(
+                " --to {}",
+                args.platforms
+                    .iter()
+                    .map(|p| p.id())
+                    .collect::<Vec<_>>()
+                    .join(",")
+            )
+        };
+        anyhow::bail!(
+            "{} article(s) could not be posted - run `post {}{} --resume` to retry them",
+            failed,
+            args.input,
+            to
+        );
+    }
+
+    if !dry_run {
+        Journal::remove()?;
+    }
+
+    Ok(())
+}
+
+/// Platforms to post a batch file to: `--to`, else its frontmatter or `[defaults]`
+async fn batch_targets(args: &PostArgs, file: &str) -> Result<Vec<Platform>> {
+    if !args.platforms.is_empty() {
+        return Ok(args.platforms.clone());
+    }
+
+    let article = load_article(file).await?;
+    Platform::targets_for(&article, &args.default_platforms)
+}
+
+/// Comma-separated names of the platforms in a list of failures
+fn failed_platform_names(failures: &[PlatformFailure]) -> String {
+    failures
+        .iter()
+        .map(|(platform, _)| platform.to_string())
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/// A platform an article could not be posted to, with the error
+type PlatformFailure = (Platform, String);
+
+/// Post one article to the target platforms; returns the platforms that failed
+///
+/// With `update_existing`, an article already on dev.to (per the state file or its
+/// frontmatter) is updated in place, and one already on Medium is left alone, instead
+/// of both being refused as duplicates.
+async fn post_article(args: PostArgs, update_existing: bool) -> Result<Vec<PlatformFailure>> {
+    let PostArgs {
+        input,
+        platforms,
+        default_platforms,
+        clean_ai,
+        tags: tags_override,
+        canonical: canonical_override,
+        no_clean_ai: _,
+        dry_run,
+        format,
+        force,
+        changed_since: _,
+        resume: _,
+        update,
+        medium_license,
+        no_notify_followers,
+        translate: _,
+        manifest: _,
+    } = args;
+    let format = format.unwrap_or_default();
+
+    println!("Loading article from: {}", input);
+
+    let mut article = load_article(&input).await?;
+
+    // A dev.to draft is published in place rather than posted to dev.to again
+    let draft_id = parse_devto_draft_ref(&input);
+    let update = update.or_else(|| draft_id.clone());
+    if let Some(ref id) = draft_id {
+        println!("Publishing dev.to draft {}: \"{}\"", id, article.title);
+        article.published = true;
+    }
+
+    // Apply AI cleaning if requested
+    if clean_ai {
+        println!("Applying AI artifact cleaning...");
+        article.content = load_cleaner()?.clean(&article.content);
+    }
+
+    // Apply overrides
+    if let Some(tags) = tags_override {
+        article.tags = tags;
+    }
+    if let Some(canonical) = canonical_override {
+        article.canonical_url = Some(canonical);
+    }
+    if let Some(license) = medium_license {
+        article.medium_license = Some(license);
+    }
+    if no_notify_followers {
+        article.notify_followers = Some(false);
+    }
+
+    let platforms = if platforms.is_empty() {
+        let targets = Platform::targets_for(&article, &default_platforms)?;
+        if targets.is_empty() {
+            anyhow::bail!(
+                "'{}' excludes every target platform in its frontmatter - nothing to post",
+                article.title
+            );
+        }
+        targets
+    } else {
+        platforms
+    };
+    // Drafts are published on dev.to and cross-posted elsewhere; only `--update` is dev.to-only
+    if let Some(ref id) = update.as_ref().filter(|_| draft_id.is_none()) {
+        if platforms.iter().any(|p| *p != Platform::DevTo) {
+            anyhow::bail!(
+                "--update {} only applies to dev.to (Medium posts cannot be updated) - use --to devto",
+                id
+            );
+        }
+    }
+
+    let new_slug = assign_slug(&mut article, &input)?;
+    derive_canonical_url(&mut article, &input)?;
+    apply_medium_defaults(&mut article)?;
+    generate_cover(&mut article)?;
+    check_cover_image(&article, &platforms).await?;
+
+    let settings = Config::load_settings().context("Failed to load config")?;
+    let mut state = State::load().context("Failed to load publication state")?;
+    let source_key = article_key(&input);
+    let key = state_key(&input, &article);
+    if key != source_key {
+        // Records made before the article had a slug are keyed by its source path
+        state.rename_article(&source_key, &key);
+    }
+    let plan = PublishPlan::build(&platforms, &settings.publish, |platform| {
+        existing_record(&state, &key, &article, platform).is_some()
+    })?;
+    let platforms = plan.platforms();
+
+    if dry_run {
+        println!("\n--- DRY RUN MODE ---");
+        println!("Would post to platforms: {}", plan.describe());
+        if let Some(ref id) = update {
+            println!("Would update dev.to article {} instead of creating one", id);
+        }
+        println!("\nArticle details:");
+        println!("  Title: {}", article.title);
+        println!("  Tags: {}", article.tags.join(", "));
+        if let Some(ref canonical) = article.canonical_url {
+            println!("  Canonical URL: {}", canonical);
+        }
+        if let Some(ref slug) = article.slug {
+            if new_slug.is_some() {
+                println!("  Slug: {} (would be written to the file)", slug);
+            } else {
+                println!("  Slug: {}", slug);
+            }
+        }
+        println!("  Published: {}", article.published);
+        println!("  Content length: {} characters", article.content.len());
+        print_content_stats(&article);
+        let failed = print_payloads(&article, &input, &platforms, &format, &settings, &state);
+        println!("\n--- DRY RUN COMPLETE (no actual posting) ---");
+
+        if failed > 0 {
+            anyhow::bail!(
+                "{} platform payload(s) could not be built - fix the errors above before posting",
+                failed
+            );
+        }
+        return Ok(Vec::new());
+    }
+
+    // Load config for API credentials
+    let config = Config::load().context("Failed to load config. Run 'config init' first.")?;
+
+    println!("\nPublishing to {} platform(s)...\n", platforms.len());
+
+    let mut results: Vec<(Platform, Result<PublishOutcome>)> = Vec::new();
+    // Carries the URLs of the platforms done so far, for dependent steps and footers
+    let mut current = article.clone();
+
+    for PlanStep { platform, needs } in plan.steps {
+        let failed_needs: Vec<String> = needs
+            .iter()
+            .filter(|need| {
+                !results
+                    .iter()
+                    .any(|(done, result)| done == *need && result.is_ok())
+            })
+            .map(|need| need.to_string())
+            .collect();
+        if !failed_needs.is_empty() {
+            println!("Skipping {}: {} failed", platform, failed_needs.join(", "));
+            let message = format!(
+                "skipped - depends on {}, which failed",
+                failed_needs.join(", ")
+            );
+            ci::record(
+                &input,
+                &platform,
+                RunStatus::Skipped,
+                None,
+                Some(message.clone()),
+            );
+            results.push((platform, Err(anyhow::anyhow!(message))));
+            continue;
+        }
+
+        print!("Publishing to {}... ", platform);
+
+        let result = match add_series_navigation(&current, &input, &platform, &state) {
+            Err(e) => Err(e),
+            Ok(linked) => match platform {
+                Platform::DevTo => {
+                    let client = DevToClient::from_config(&config.dev_to);
+                    let options = config.sanitize_options(&platform);
+                    let image_backend = MediumClient::from_config(&config.medium);
+                    let existing_id = if update.is_some() {
+                        update.clone()
+                    } else if update_existing {
+                        existing_record(&state, &key, &article, &platform)
+                            .and_then(|record| record.id)
+                            .or_else(|| article.syndication.devto_id.clone())
+                    } else {
+                        None
+                    };
+                    publish_to_devto(
+                        &client,
+                        &image_backend,
+                        &linked,
+                        &options,
+                        &key,
+                        &mut state,
+                        force,
+                        existing_id.as_deref(),
+                    )
+                    .await
+                }
+                Platform::Medium => {
+                    let existing = update_existing
+                        .then(|| existing_record(&state, &key, &article, &platform))
+                        .flatten();
+                    match existing {
+                        Some(record) => Ok(PublishOutcome::Kept(record)),
+                        None => {
+                            let client = MediumClient::from_config(&config.medium);
+                            let options = config.sanitize_options(&platform);
+                            publish_to_medium(
+                                &client, &linked, &format, &options, &key, &mut state, force,
+                            )
+                            .await
+                        }
+                    }
+                }
+            },
+        };
+
+        // Recorded as each platform finishes, so an interrupted run can still report it
+        match result {
+            Ok(outcome @ PublishOutcome::Published(_)) => {
+                println!("✓ Success");
+                if let Err(e) = state.save() {
+                    eprintln!("⚠️  Warning: Failed to save publication state: {:#}", e);
+                }
+                let record = outcome.record();
+                ci::record_copy(&input, &platform, RunStatus::Published, record, None);
+                set_syndication(&mut current, &platform, outcome.record());
+                results.push((platform, Ok(outcome)));
+            }
+            Ok(outcome @ PublishOutcome::Unchanged(_)) => {
+                println!("= Unchanged");
+                let record = outcome.record();
+                ci::record_copy(&input, &platform, RunStatus::Unchanged, record, None);
+                set_syndication(&mut current, &platform, outcome.record());
+                results.push((platform, Ok(outcome)));
+            }
+            Ok(outcome @ PublishOutcome::Kept(_)) => {
+                println!("= Already published");
+                let message = "already published, posts cannot be updated".to_string();
+                let record = outcome.record();
+                ci::record_copy(&input, &platform, RunStatus::Skipped, record, Some(message));
+                set_syndication(&mut current, &platform, outcome.record());
+                results.push((platform, Ok(outcome)));
+            }
+            Err(e) => {
+                println!("✗ Failed");
+                let error = format!("{:#}", e);
+                ci::record(&input, &platform, RunStatus::Failed, None, Some(error));
+                results.push((platform, Err(e)));
+            }
+        }
+    }
+
+    let any_published = results
+        .iter()
+        .any(|(_, result)| matches!(result, Ok(PublishOutcome::Published(_))));
+
+    // Record the slug and (if enabled) where the article now lives in its frontmatter
+    let mut fields: Vec<(&str, String)> = Vec::new();
+    if let (Some(slug), true) = (new_slug, any_published) {
+        fields.push(("slug", slug));
+    }
+    if config.frontmatter.write_urls && !is_devto_input(&input) {
+        for (platform, result) in &results {
+            if let Ok(outcome) = result {
+                fields.extend(syndication_fields(&article, platform, outcome.record()));
+            }
+        }
+    }
+    write_frontmatter_fields(&input, &fields);
+
+    // Display summary
+    println!("\n--- RESULTS ---");
+    let mut failed = Vec::new();
+    for (platform, result) in results {
+        match result {
+            Ok(PublishOutcome::Published(record)) => {
+                println!("✓ {}: {}", platform, record.url);
+            }
+            Ok(PublishOutcome::Unchanged(record)) => {
+                println!("= {}: unchanged since last post ({})", platform, record.url);
+            }
+            Ok(PublishOutcome::Kept(record)) => {
+                println!(
+                    "= {}: already published, posts cannot be updated ({})",
+                    platform, record.url
+                );
+            }
+            Err(e) => {
+                println!("✗ {}: Error", platform);
+                // Show full error chain with details
+                eprintln!("\nError details:");
+                eprintln!("{:#}", e);
+                failed.push((platform, format!("{:#}", e)));
+            }
+        }
+    }
+
+    Ok(failed)
+}
+
+/// Settings and clients shared by all parts of a `post-series` run
+struct SeriesRun<'a> {
+    parts: &'a [SeriesPart],
+    platforms: &'a [Platform],
+    format: &'a ContentFormat,
+    clean_ai: bool,
+    force: bool,
+    config: &'a Config,
+    devto: DevToClient,
+    medium: MediumClient,
+}
+
+/// A copy a `post-series` run made public: state key, platform, record
+type SeriesPublication = (String, Platform, PlatformRecord);
+
+/// Handle post-series command - publish the parts of a series in order
+///
+/// Each part links to the parts already published; once a part is on dev.to, the
+/// previous part's dev.to copy is updated to link forward. If a part fails, the copies
+/// the run made public are turned back into drafts where possible; copies that were
+/// already public stay as they are.
+async fn handle_post_series_command(args: PostSeriesArgs) -> Result<()> {
+    let PostSeriesArgs {
+        path,
+        platforms,
+        clean_ai,
+        no_clean_ai,
+        dry_run,
+        format,
+        force,
+    } = args;
+    let defaults = Config::load_settings()
+        .context("Failed to load config")?
+        .defaults;
+    let platforms = defaults.platforms(platforms)?;
+    let clean_ai = defaults.clean_ai(clean_ai, no_clean_ai);
+    let format = defaults.format(format);
+
+    let (series, parts) = load_series(Path::new(&path)).context("Failed to load series")?;
+
+    println!("Series '{}' ({} part(s)):", series, parts.len());
+    for (index, part) in parts.iter().enumerate() {
+        println!(
+            "  {}. {} ({})",
+            index + 1,
+            part.article.title,
+            part.path.display()
+        );
+    }
+
+    if dry_run {
+        return check_series_payloads(&parts, &platforms, &format, clean_ai).await;
+    }
+
+    let config = Config::load().context("Failed to load config. Run 'config init' first.")?;
+    let mut state = State::load().context("Failed to load publication state")?;
+    let run = SeriesRun {
+        parts: &parts,
+        platforms: &platforms,
+        format: &format,
+        clean_ai,
+        force,
+        config: &config,
+        devto: DevToClient::from_config(&config.dev_to),
+        medium: MediumClient::from_config(&config.medium),
+    };
+    let mut published: Vec<SeriesPublication> = Vec::new();
+
+    for (index, part) in parts.iter().enumerate() {
+        println!(
+            "\nPart {}/{}: {}",
+            index + 1,
+            parts.len(),
+            part.article.title
+        );
+
+        let result = post_series_part(&run, part, &mut state, &mut published).await;
+        if let Err(e) = result {
+            eprintln!("\nError details:");
+            eprintln!("{:#}", e);
+            rollback_series(&run.devto, &published, &mut state).await;
+            if let Err(e) = state.save() {
+                eprintln!("⚠️  Warning: Failed to save publication state: {:#}", e);
+            }
+            anyhow::bail!(
+                "Series publishing stopped at part {} of {}",
+                index + 1,
+                parts.len()
+            );
+        }
+
+        if let Err(e) = state.save() {
+            eprintln!("⚠️  Warning: Failed to save publication state: {:#}", e);
+        }
+
+        if index > 0 && platforms.contains(&Platform::DevTo) {
+            if let Err(e) = link_previous_part(&run, &parts[index - 1], &mut state).await {
+                eprintln!(
+                    "⚠️  Warning: Failed to link part {} to part {} on dev.to (run 'sync' later): {:#}",
+                    index,
+                    index + 1,
+                    e
+                );
+            }
+        }
+    }
+
+    println!("\n✓ Published all {} part(s) of '{}'", parts.len(), series);
+
+    Ok(())
+}
+
+/// Load a series part and apply the same preparation as `post`
+///
+/// Returns the article and a newly assigned slug (to be written back), if any.
+async fn prepare_series_article(
+    part: &SeriesPart,
+    clean_ai: bool,
+) -> Result<(Article, Option<String>)> {
+    let input = part.path.display().to_string();
+    let mut article = load_article(&input).await?;
+
+    // The series name and order may come from a manifest rather than the frontmatter
+    article.series = part.article.series.clone();
+    article.series_part = part.article.series_part;
+
+    if clean_ai {
+        article.content = load_cleaner()?.clean(&article.content);
+    }
+
+    let new_slug = assign_slug(&mut article, &input)?;
+    derive_canonical_url(&mut article, &input)?;
+    apply_medium_defaults(&mut article)?;
+    generate_cover(&mut article)?;
+
+    Ok((article, new_slug))
+}
+
+/// Publish one part of a series to every target platform
+///
+/// Copies this run makes public are added to `published` as they happen, so a failure
+/// on a later platform can roll back the earlier ones too. Copies that were already
+/// public before the run are left out: a failed re-run must not unpublish them. Parts
+/// already on Medium are kept as they are, since Medium posts cannot be updated.
+async fn post_series_part(
+    run: &SeriesRun<'_>,
+    part: &SeriesPart,
+    state: &mut State,
+    published: &mut Vec<SeriesPublication>,
+) -> Result<()> {
+    let input = part.path.display().to_string();
+    let (article, new_slug) = prepare_series_article(part, run.clean_ai).await?;
+
+    let source_key = article_key(&input);
+    let key = state_key(&input, &article);
+    if key != source_key {
+        state.rename_article(&source_key, &key);
+    }
+
+    let mut fields: Vec<(&str, String)> = Vec::new();
+    if let Some(slug) = new_slug {
+        fields.push(("slug", slug));
+    }
+
+    for platform in run.platforms {
+        print!("  {}... ", platform);
+
+        let linked = link_series_parts(&article, &part.path, run.parts, platform, state);
+        let options = run.config.sanitize_options(platform);
+        let live_before = state
+            .record(&key, platform)
+            .is_some_and(|record| !record.draft);
+        let result = match platform {
+            Platform::DevTo => {
+                // Re-running after a rollback turns the existing draft back into the article
+                let existing_id = state.record(&key, platform).and_then(|r| r.id.clone());
+                publish_to_devto(
+                    &run.devto,
+                    &run.medium,
+                    &linked,
+                    &options,
+                    &key,
+                    state,
+                    run.force,
+                    existing_id.as_deref(),
+                )
+                .await
+            }
+            Platform::Medium => match existing_record(state, &key, &article, platform) {
+                Some(record) => Ok(PublishOutcome::Kept(record)),
+                None => {
+                    publish_to_medium(
+                        &run.medium,
+                        &linked,
+                        run.format,
+                        &options,
+                        &key,
+                        state,
+                        run.force,
+                    )
+                    .await
+                }
+            },
+        };
+
+        let outcome = match result {
+            Ok(outcome) => outcome,
+            Err(e) => {
+                println!("✗ Failed");
+                let e = e.context(format!("Failed to publish to {}", platform));
+                ci::record(
+                    &input,
+                    platform,
+                    RunStatus::Failed,
+                    None,
+                    Some(format!("{:#}", e)),
+                );
+                return Err(e);
+            }
+        };
+
+        match &outcome {
+            PublishOutcome::Published(record) => {
+                println!("✓ {}", record.url);
+                ci::record_copy(&input, platform, RunStatus::Published, record, None);
+                if !live_before {
+                    published.push((key.clone(), platform.clone(), record.clone()));
+                }
+            }
+            PublishOutcome::Unchanged(record) => {
+                println!("= unchanged ({})", record.url);
+                ci::record_copy(&input, platform, RunStatus::Unchanged, record, None);
+            }
+            PublishOutcome::Kept(record) => {
+                println!("= already published ({})", record.url);
+                let message = "already published, posts cannot be updated".to_string();
+                ci::record_copy(&input, platform, RunStatus::Skipped, record, Some(message));
+            }
+        }
+
+        if run.config.frontmatter.write_urls {
+            fields.extend(syndication_fields(&article, platform, outcome.record()));
+        }
+    }
+
+    write_frontmatter_fields(&input, &fields);
+
+    Ok(())
+}
+
+/// Update the previous part's dev.to copy so it links to the part just published
+async fn link_previous_part(
+    run: &SeriesRun<'_>,
+    previous: &SeriesPart,
+    state: &mut State,
+) -> Result<()> {
+    let input = previous.path.display().to_string();
+    let (article, _) = prepare_series_article(previous, run.clean_ai).await?;
+    let key = state_key(&input, &article);
+
+    let Some(id) = state
+        .record(&key, &Platform::DevTo)
+        .and_then(|record| record.id.clone())
+    else {
+        return Ok(());
+    };
+
+    let linked = link_series_parts(&article, &previous.path, run.parts, &Platform::DevTo, state);
+    let options = run.config.sanitize_options(&Platform::DevTo);
+    let outcome = update_devto_copy(
+        &run.devto,
+        &run.medium,
+        &linked,
+        &options,
+        &key,
+        &id,
+        state,
+        false,
+    )
+    .await?;
+
+    if let SyncOutcome::Updated(url) = outcome {
+        println!("  Linked previous part on dev.to: {}", url);
+    }
+
+    Ok(())
+}
+
+/// Turn articles published during a failed `post-series` run back into drafts
+///
+/// dev.to copies are unpublished and their state records invalidated, so the next run
+/// re-sends them (updating the drafts). Medium has no API for this; those posts are
+/// reported so they can be removed by hand.
+async fn rollback_series(client: &DevToClient, published: &[SeriesPublication], state: &mut State) {
+    if published.is_empty() {
+        return;
+    }
+
+    println!("\nRolling back {} publication(s)...", published.len());
+
+    for (key, platform, record) in published.iter().rev() {
+        match platform {
+            Platform::DevTo => {
+                let Some(id) = &record.id else {
+                    continue;
+                };
+                match client.set_published(id, false).await {
+                    Ok(()) => {
+                        println!("↩ dev.to: reverted to draft {}", record.url);
+                        state.set_record(
+                            key,
+                            platform,
+                            PlatformRecord {
+                                content_hash: String::new(),
+                                draft: true,
+                                ..record.clone()
+                            },
+                        );
+                    }
+                    Err(e) => eprintln!(
+                        "⚠️  Warning: Failed to revert {} to a draft: {:#}",
+                        record.url, e
+                    ),
+                }
+            }
+            Platform::Medium => eprintln!(
+                "⚠️  Warning: Medium posts cannot be unpublished through the API - remove it manually: {}",
+                record.url
+            ),
+        }
+    }
+}
+
+/// Build every part's payload for every platform without posting (`post-series --dry-run`)
+async fn check_series_payloads(
+    parts: &[SeriesPart],
+    platforms: &[Platform],
+    format: &ContentFormat,
+    clean_ai: bool,
+) -> Result<()> {
+    let config = Config::load_settings().context("Failed to load config")?;
+    let state = State::load().context("Failed to load publication state")?;
+
+    println!("\n--- DRY RUN MODE ---");
+
+    let mut failed = 0;
+    for (index, part) in parts.iter().enumerate() {
+        let (article, _) = prepare_series_article(part, clean_ai).await?;
+
+        for platform in platforms {
+            let linked = link_series_parts(&article, &part.path, parts, platform, &state);
+            let options = config.sanitize_options(platform);
+            let payload = match platform {
+                Platform::DevTo => DevToClient::build_publish_request(&linked, &options)
+                    .and_then(|prepared| payload_json(&prepared.request)),
+                Platform::Medium => MediumClient::build_publish_request(&linked, format, &options)
+                    .and_then(|prepared| payload_json(&prepared.request)),
+            };
+
+            match payload {
+                Ok((size, _)) => println!("✓ Part {} {}: {} bytes", index + 1, platform, size),
+                Err(e) => {
+                    failed += 1;
+                    println!("✗ Part {} {}: {:#}", index + 1, platform, e);
+                }
+            }
+        }
+    }
+
+    println!("\n--- DRY RUN COMPLETE (no actual posting) ---");
+
+    if failed > 0 {
+        anyhow::bail!("{} payload(s) could not be built", failed);
+    }
+
+    Ok(())
+}
+
+/// Result of syncing one article to dev.to
+enum SyncOutcome {
+    /// The dev.to copy was updated; carries its URL
+    Updated(String),
+
+    /// The dev.to copy would be updated (dry run); carries its ID
+    WouldUpdate(String),
+
+    /// The payload is identical to the last one sent
+    Unchanged,
+
+    /// The article has no recorded dev.to copy
+    NotPosted,
+}
+
+/// Handle sync command - push local changes to articles already posted to dev.to
+async fn handle_sync_command(path: String, clean_ai: bool, dry_run: bool) -> Result<()> {
+    let root = Path::new(&path);
+    let files = if root.is_dir() {
+        collect_markdown_files(root)?
+    } else {
+        vec![root.to_path_buf()]
+    };
+
+    let config = if dry_run {
+        Config::load_settings().context("Failed to load config")?
+    } else {
+        Config::load().context("Failed to load config. Run 'config init' first.")?
+    };
+    let mut state = State::load().context("Failed to load publication state")?;
+    let client = DevToClient::from_config(&config.dev_to);
+    let image_backend = MediumClient::from_config(&config.medium);
+    let options = config.sanitize_options(&Platform::DevTo);
+
+    let mut failed = 0;
+    for file in files {
+        let input = file.display().to_string();
+        let outcome = sync_to_devto(
+            &client,
+            &image_backend,
+            &input,
+            clean_ai,
+            &options,
+            &mut state,
+            dry_run,
+        )
+        .await;
+
+        let platform = Platform::DevTo;
+        match outcome {
+            Ok(SyncOutcome::Updated(url)) => {
+                println!("✓ {}: updated {}", input, url);
+                ci::record(&input, &platform, RunStatus::Updated, Some(&url), None);
+                if let Err(e) = state.save() {
+                    eprintln!("⚠️  Warning: Failed to save publication state: {:#}", e);
+                }
+            }
+            Ok(SyncOutcome::WouldUpdate(id)) => {
+                println!("~ {}: would update dev.to article {}", input, id);
+                let message = format!("dry run: would update article {}", id);
+                ci::record(&input, &platform, RunStatus::Skipped, None, Some(message));
+            }
+            Ok(SyncOutcome::Unchanged) => {
+                println!("= {}: unchanged", input);
+                ci::record(&input, &platform, RunStatus::Unchanged, None, None);
+            }
+            Ok(SyncOutcome::NotPosted) => {
+                println!("- {}: not posted to dev.to", input);
+                let message = "not posted to dev.to".to_string();
+                ci::record(&input, &platform, RunStatus::Skipped, None, Some(message));
+            }
+            Err(e) => {
+                failed += 1;
+                println!("✗ {}: Error", input);
+                eprintln!("{:#}", e);
+                ci::record(
+                    &input,
+                    &platform,
+                    RunStatus::Failed,
+                    None,
+                    Some(format!("{:#}", e)),
+                );
+            }
+        }
+    }
+
+    if failed > 0 {
+        anyhow::bail!("{} article(s) could not be synced", failed);
+    }
+
+    Ok(())
+}
+
+/// Rebuild an article's dev.to payload and update its dev.to copy if the payload changed
+async fn sync_to_devto(
+    client: &DevToClient,
+    image_backend: &MediumClient,
+    input: &str,
+    clean_ai: bool,
+    options: &SanitizeOptions,
+    state: &mut State,
+    dry_run: bool,
+) -> Result<SyncOutcome> {
+    let mut article = load_article(input).await?;
+    if clean_ai {
+        article.content = load_cleaner()?.clean(&article.content);
+    }
+    derive_canonical_url(&mut article, input)?;
+
+    let key = state_key(input, &article);
+    let Some(id) = state
+        .record(&key, &Platform::DevTo)
+        .and_then(|record| record.id.clone())
+        .or_else(|| article.syndication.devto_id.clone())
+    else {
+        return Ok(SyncOutcome::NotPosted);
+    };
+
+    generate_cover(&mut article)?;
+
+    let linked = add_series_navigation(&article, input, &Platform::DevTo, state)?;
+    update_devto_copy(
+        client,
+        image_backend,
+        &linked,
+        options,
+        &key,
+        &id,
+        state,
+        dry_run,
+    )
+    .await
+}
+
+/// Update an existing dev.to article if its payload differs from the last one sent
+#[allow(clippy::too_many_arguments)]
+async fn update_devto_copy(
+    client: &DevToClient,
+    image_backend: &MediumClient,
+    article: &Article,
+    options: &SanitizeOptions,
+    key: &str,
+    id: &str,
+    state: &mut State,
+    dry_run: bool,
+) -> Result<SyncOutcome> {
+    let prepared = DevToClient::build_publish_request(article, options)?;
+    prepared.print_warnings();
+    let mut request = prepared.request;
+    let hash = content_hash(&serde_json::to_string(&request)?);
+
+    if state.is_unchanged(key, &Platform::DevTo, &hash) {
+        return Ok(SyncOutcome::Unchanged);
+    }
+    if dry_run {
+        return Ok(SyncOutcome::WouldUpdate(id.to_string()));
+    }
+
+    image_backend
+        .upload_assets(&mut request, &prepared.assets)
+        .await
+        .context("Failed to upload images for dev.to")?;
+
+    let updated = client.update_article(id, &request).await?;
+
+    state.set_record(
+        key,
+        &Platform::DevTo,
+        PlatformRecord {
+            url: updated.url.clone(),
+            id: Some(updated.id),
+            content_hash: hash,
+            draft: !article.published,
+        },
+    );
+
+    Ok(SyncOutcome::Updated(updated.url))
+}
+
+/// Print content statistics for a dry run
+fn print_content_stats(article: &Article) {
+    let stats = compute_stats(&article.content);
+
+    println!("\nContent statistics:");
+    println!("  Words: {}", stats.word_count);
+    println!("  Images: {}", stats.image_count);
+    println!("  Code blocks: {}", stats.code_block_count);
+    println!("  Links: {}", stats.link_count);
+
+    if stats.headings.is_empty() {
+        println!("  Headings: (none)");
+    } else {
+        println!("  Headings:");
+        for heading in &stats.headings {
+            println!(
+                "    {}{} {}",
+                "  ".repeat(usize::from(heading.level.saturating_sub(1))),
+                "#".repeat(usize::from(heading.level)),
+                heading.text
+            );
+        }
+    }
+}
+
+/// Build and print the exact API payload for each platform
+///
+/// Returns the number of platforms whose payload could not be built.
+fn print_payloads(
+    article: &Article,
+    input: &str,
+    platforms: &[Platform],
+    format: &ContentFormat,
+    config: &Config,
+    state: &State,
+) -> usize {
+    let mut failed = 0;
+
+    for platform in platforms {
+        println!("\n--- {} PAYLOAD ---", platform);
+
+        let options = config.sanitize_options(platform);
+        let payload =
+            add_series_navigation(article, input, platform, state).and_then(
+                |linked| match platform {
+                    Platform::DevTo => DevToClient::build_publish_request(&linked, &options)
+                        .and_then(|prepared| {
+                            prepared.print_warnings();
+                            prepared.print_assets();
+                            payload_json(&prepared.request)
+                        }),
+                    Platform::Medium => MediumClient::build_publish_request(
+                        &linked, format, &options,
+                    )
+                    .and_then(|prepared| {
+                        prepared.print_warnings();
+                        prepared.print_assets();
+                        payload_json(&prepared.request)
+                    }),
+                },
+            );
+
+        match payload {
+            Ok((size, json)) => {
+                println!("Size: {} bytes\n", size);
+                println!("{}", json);
+            }
+            Err(e) => {
+                failed += 1;
+                println!("✗ Failed to build payload: {:#}", e);
+            }
+        }
+    }
+
+    failed
+}
+
+/// Serialize a publish request, returning its wire size (compact JSON) and a pretty-printed form
+fn payload_json<T: serde::Serialize>(request: &T) -> Result<(usize, String)> {
+    let compact = serde_json::to_string(request)?;
+    let pretty = serde_json::to_string_pretty(request)?;
+    Ok((compact.len(), pretty))
+}
+
+/// Handle validate command - lint articles without publishing
+fn handle_validate_command(
+    path: String,
+    platforms: Vec<Platform>,
+    format: ContentFormat,
+    json: bool,
+) -> Result<()> {
+    let config = Config::load_settings().context("Failed to load config")?;
+    let report = validate_path(Path::new(&path), &platforms, &format, &config)?;
+
+    if json {
+        println!(
+            "{}",
+            serde_json::to_string_pretty(&report).context("Failed to serialize report")?
+        );
+    } else {
+        print_validation_report(&report);
+    }
+
+    if ci::is_enabled() {
+        print_validation_annotations(&report);
+    }
+
+    if !report.is_ok() {
+        anyhow::bail!("Validation failed with {} error(s)", report.error_count);
+    }
+
+    Ok(())
+}
+
+/// Print a human-readable validation report
+fn print_validation_report(report: &ValidationReport) {
+    for file in &report.files {
+        if file.count(Severity::Error) > 0 {
+            println!("✗ {}", file.path);
+        } else {
+            println!("✓ {}", file.path);
+        }
+
+        for issue in &file.issues {
+            let severity = match issue.severity {
+                Severity::Error => "error",
+                Severity::Warning => "warning",
+            };
+            match issue.platform {
+                Some(ref platform) => {
+                    println!("    {} [{}]: {}", severity, platform, issue.message)
+                }
+                None => println!("    {}: {}", severity, issue.message),
+            }
+        }
+    }
+
+    println!(
+        "\nChecked {} file(s): {} error(s), {} warning(s)",
+        report.files.len(),
+        report.error_count,
+        report.warning_count
+    );
+}
+
+/// Print validation issues as GitHub Actions annotations
+fn print_validation_annotations(report: &ValidationReport) {
+    for file in &report.files {
+        for issue in &file.issues {
+            let level = match issue.severity {
+                Severity::Error => "error",
+                Severity::Warning => "warning",
+            };
+            let message = match issue.platform {
+                Some(ref platform) => format!("[{}] {}", platform, issue.message),
+                None => issue.message.clone(),
+            };
+            println!("{}", ci::annotation(level, Some(&file.path), &message));
+        }
+    }
+}
+
+/// Handle list command - list articles from a platform
+async fn handle_list_command(
+    platform: Platform,
+    page: u32,
+    per_page: u32,
+    state: ArticleState,
+) -> Result<()> {
+    let config = Config::load().context("Failed to load config. Run 'config init' first.")?;
+
+    match platform {
+        Platform::DevTo => {
+            let client = DevToClient::from_config(&config.dev_to);
+            let articles = client
+                .list_articles(page, per_page, &state.to_string())
+                .await
+                .context("Failed to list dev.to articles")?;
+
+            println!(
+                "{} articles on dev.to (page {}):\n",
+                state
+                    .to_string()
+                    .chars()
+                    .next()
+                    .unwrap()
+                    .to_uppercase()
+                    .to_string()
+                    + &state.to_string()[1..],
+                page
+            );
             println!("  {:<10} {:<12} Title", "ID", "Published");
             println!("  {:<10} {:<12} -----", "------", "----------");
 
             for article in &articles {
                 let date = if article.published_at.len() >= 10 {
                     &article.published_at[..10]
+                } else if article.published_at.is_empty() {
+                    "draft"
                 } else {
                     &article.published_at
                 };
@@ -233,7 +2057,7 @@ async fn handle_list_command(
             );
         }
         Platform::Medium => {
-            let client = MediumClient::new(config.medium.access_token.clone());
+            let client = MediumClient::from_config(&config.medium);
             let articles = client
                 .list_articles()
                 .await
@@ -243,17 +2067,141 @@ async fn handle_list_command(
             println!("  {:<12} Title", "Published");
             println!("  {:<12} -----", "----------");
 
-            for article in &articles {
-                println!("  {:<12} {}", article.published_at, article.title);
-            }
+            for article in &articles {
+                println!("  {:<12} {}", article.published_at, article.title);
+            }
+
+            println!(
+                "\nNote: Medium RSS feed returns at most 10 recent articles.\n      \
+                 Content is HTML only (no original markdown). No pagination."
+            );
+        }
+    }
+
+    Ok(())
+}
+
+/// Handle suggest command - propose a title, description and tags with the configured LLM
+async fn handle_suggest_command(input: String, accept: Vec<SuggestField>) -> Result<()> {
+    if is_devto_input(&input) {
+        anyhow::bail!("suggest takes a local markdown file, not a dev.to article");
+    }
+
+    let config = Config::load_settings().context("Failed to load config")?;
+    let client = LlmClient::from_config(&config.llm)?;
+    let article = load_article(&input).await?;
+
+    println!(
+        "Asking {} for suggestions for '{}'...",
+        client.model(),
+        article.title
+    );
+    let suggestions = suggest_metadata(&client, &article).await?;
+
+    let none = || "(none)".to_string();
+    println!("\nSuggested:");
+    println!(
+        "  title:       {}",
+        suggestions
+            .title
+            .as_ref()
+            .map_or_else(none, |t| format!("{:?}", t))
+    );
+    println!(
+        "  description: {}",
+        suggestions
+            .description
+            .as_ref()
+            .map_or_else(none, |d| format!("{:?}", d))
+    );
+    println!("  tags:        [{}]", suggestions.tags.join(", "));
+    println!("\nCurrent:");
+    println!("  title:       {:?}", article.title);
+    println!(
+        "  description: {}",
+        article
+            .description
+            .as_ref()
+            .map_or_else(none, |d| format!("{:?}", d))
+    );
+    println!("  tags:        [{}]", article.tags.join(", "));
+
+    if accept.is_empty() {
+        println!(
+            "\nTo use them: article-cross-poster suggest {} --accept title,description,tags",
+            input
+        );
+        return Ok(());
+    }
 
-            println!(
-                "\nNote: Medium RSS feed returns at most 10 recent articles.\n      \
-                 Content is HTML only (no original markdown). No pagination."
-            );
+    let mut written = Vec::new();
+    edit_frontmatter_file(Path::new(&input), |mut content| {
+        for field in &accept {
+            match field {
+                SuggestField::Title => {
+                    if let Some(ref title) = suggestions.title {
+                        content = set_frontmatter_field(&content, "title", title)?;
+                        written.push("title");
+                    }
+                }
+                SuggestField::Description => {
+                    if let Some(ref description) = suggestions.description {
+                        content = set_frontmatter_field(&content, "description", description)?;
+                        written.push("description");
+                    }
+                }
+                SuggestField::Tags => {
+                    if !suggestions.tags.is_empty() {
+                        content = set_frontmatter_list(&content, "tags", &suggestions.tags)?;
+                        written.push("tags");
+                    }
+                }
+            }
         }
+        Ok(content)
+    })?;
+
+    if written.is_empty() {
+        println!("\nNothing to write - the LLM suggested none of the accepted fields");
+    } else {
+        println!("\nUpdated {} in {}", written.join(", "), input);
+    }
+
+    Ok(())
+}
+
+/// Handle blurb command - print a short announcement for an article
+async fn handle_blurb_command(input: String, max_chars: usize, no_llm: bool) -> Result<()> {
+    if is_devto_input(&input) {
+        anyhow::bail!("blurb takes a local markdown file, not a dev.to article");
     }
 
+    let config = Config::load_settings().context("Failed to load config")?;
+    let article = load_article(&input).await?;
+
+    let summary = if no_llm || config.llm.endpoint.is_none() {
+        summarize(&article)
+    } else {
+        let client = LlmClient::from_config(&config.llm)?;
+        match summarize_with_llm(&client, &article).await {
+            Ok(summary) => summary,
+            Err(e) => {
+                ci::warning(
+                    Some(&input),
+                    &format!("{:#} - using the rule-based summary", e),
+                );
+                summarize(&article)
+            }
+        }
+    };
+
+    let url = article
+        .canonical_url
+        .as_deref()
+        .or(article.syndication.devto_url.as_deref())
+        .or(article.syndication.medium_url.as_deref());
+    println!("{}", summary.post_text(url, max_chars));
+
     Ok(())
 }
 
@@ -263,7 +2211,7 @@ async fn handle_fetch_command(id: String, platform: Platform) -> Result<()> {
         Platform::DevTo => {
             let config =
                 Config::load().context("Failed to load config. Run 'config init' first.")?;
-            let client = DevToClient::new(config.dev_to.api_key.clone());
+            let client = DevToClient::from_config(&config.dev_to);
             let article = client
                 .fetch_article(&id)
                 .await
@@ -299,14 +2247,499 @@ async fn handle_fetch_command(id: String, platform: Platform) -> Result<()> {
     Ok(())
 }
 
+/// Handle queue commands
+async fn handle_queue_command(action: QueueAction) -> Result<()> {
+    match action {
+        QueueAction::Add {
+            input,
+            platforms,
+            at,
+            clean_ai,
+            no_clean_ai,
+            format,
+        } => {
+            let defaults = Config::load_settings()
+                .context("Failed to load config")?
+                .defaults;
+            let clean_ai = defaults.clean_ai(clean_ai, no_clean_ai);
+            let format = defaults.format(format);
+            let platforms = if platforms.is_empty() {
+                let article = load_article(&input).await?;
+                let targets = Platform::targets_for(&article, &defaults.platforms)?;
+                if targets.is_empty() {
+                    anyhow::bail!(
+                        "'{}' excludes every target platform in its frontmatter - nothing to queue",
+                        article.title
+                    );
+                }
+                targets
+            } else {
+                platforms
+            };
+
+            // Store files by absolute path, so `queue run` works from any directory
+            let input = if is_devto_input(&input) {
+                input
+            } else {
+                Path::new(&input)
+                    .canonicalize()
+                    .context(format!("Invalid or inaccessible path: {}", input))?
+                    .display()
+                    .to_string()
+            };
+            let publish_at = match at {
+                Some(at) => parse_publish_time(&at)?,
+                None => chrono::Utc::now(),
+            };
+
+            let mut queue = Queue::load().context("Failed to load queue")?;
+            let id = queue.push(QueueItem {
+                id: 0,
+                input: input.clone(),
+                platforms,
+                publish_at,
+                clean_ai,
+                format,
+                attempts: 0,
+                last_error: None,
+            });
+            queue.save().context("Failed to save queue")?;
+
+            println!(
+                "Queued #{}: {} (due {})",
+                id,
+                input,
+                publish_at
+                    .with_timezone(&chrono::Local)
+                    .format("%Y-%m-%d %H:%M")
+            );
+        }
+        QueueAction::List => {
+            let queue = Queue::load().context("Failed to load queue")?;
+            if queue.items.is_empty() {
+                println!("The queue is empty");
+                return Ok(());
+            }
+
+            let now = chrono::Utc::now();
+            for item in &queue.items {
+                let status = if item.is_failed() {
+                    "failed"
+                } else if item.is_due(now) {
+                    "due"
+                } else {
+                    "scheduled"
+                };
+                let platforms = item
+                    .platforms
+                    .iter()
+                    .map(|p| p.to_string())
+                    .collect::<Vec<_>>()
+                    .join(", ");
+
+                println!(
+                    "#{:<4} {}  {:<9}  {}  -> {}",
+                    item.id,
+                    item.publish_at
+                        .with_timezone(&chrono::Local)
+                        .format("%Y-%m-%d %H:%M"),
+                    status,
+                    item.input,
+                    platforms
+                );
+                if let Some(ref error) = item.last_error {
+                    println!("      last error ({} attempt(s)): {}", item.attempts, error);
+                }
+            }
+        }
+        QueueAction::Remove { id } => {
+            let mut queue = Queue::load().context("Failed to load queue")?;
+            let item = queue
+                .remove(id)
+                .context(format!("No queued post with ID {}", id))?;
+            queue.save().context("Failed to save queue")?;
+            println!("Removed #{}: {}", id, item.input);
+        }
+        QueueAction::Run { daemon, interval } => {
+            let mut last_publish = HashMap::new();
+            loop {
+                run_due_queue_items(&mut last_publish, daemon).await?;
+                if !daemon {
+                    break;
+                }
+                tokio::time::sleep(Duration::from_secs(interval.max(1))).await;
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Publish every queued post that is due
+///
+/// Posts to the same platform are spaced by `min_publish_interval()`. Published
+/// posts leave the queue; failed ones stay with their error and are retried on the
+/// next run (post state makes retries skip the platforms that already succeeded).
+async fn run_due_queue_items(
+    last_publish: &mut HashMap<&'static str, Instant>,
+    daemon: bool,
+) -> Result<()> {
+    let due = Queue::load()
+        .context("Failed to load queue")?
+        .due(chrono::Utc::now());
+
+    if due.is_empty() {
+        if !daemon {
+            println!("No queued posts are due");
+        }
+        return Ok(());
+    }
+
+    for item in due {
+        // Wait until every target platform is past its rate-limit interval
+        let wait = item
+            .platforms
+            .iter()
+            .filter_map(|platform| {
+                let last = last_publish.get(platform.id())?;
+                min_publish_interval(platform).checked_sub(last.elapsed())
+            })
+            .max();
+        if let Some(wait) = wait {
+            println!("Waiting {}s for rate limits...", wait.as_secs());
+            tokio::time::sleep(wait).await;
+        }
+
+        log_queue(&format!("Publishing #{}: {}", item.id, item.input));
+        let args = PostArgs {
+            input: item.input.clone(),
+            platforms: item.platforms.clone(),
+            default_platforms: Vec::new(),
+            clean_ai: item.clean_ai,
+            no_clean_ai: false,
+            tags: None,
+            canonical: None,
+            dry_run: false,
+            format: Some(item.format.clone()),
+            force: false,
+            changed_since: None,
+            resume: false,
+            update: None,
+            medium_license: None,
+            no_notify_followers: false,
+            translate: Vec::new(),
+            manifest: None,
+        };
+        let result = post_article(args, false).await;
+
+        for platform in &item.platforms {
+            last_publish.insert(platform.id(), Instant::now());
+        }
+
+        // Reload, so items added while publishing are kept
+        let mut queue = Queue::load().context("Failed to load queue")?;
+        match result {
+            Ok(failed) if failed.is_empty() => {
+                queue.remove(item.id);
+                log_queue(&format!("✓ #{} published", item.id));
+            }
+            Ok(failed) => {
+                let error = failed
+                    .iter()
+                    .map(|(platform, error)| format!("{}: {}", platform, error))
+                    .collect::<Vec<_>>()
+                    .join("; ");
+                log_queue(&format!("✗ #{}: {}", item.id, error));
+                queue.record_failure(item.id, error);
+            }
+            Err(e) => {
+                let error = format!("{:#}", e);
+                log_queue(&format!("✗ #{}: {}", item.id, error));
+                queue.record_failure(item.id, error);
+            }
+        }
+        queue.save().context("Failed to save queue")?;
+    }
+
+    Ok(())
+}
+
+/// Print a queue run message and append it to the queue log
+fn log_queue(message: &str) {
+    println!("{}", message);
+    if let Err(e) = append_log(message) {
+        eprintln!("⚠️  Warning: Failed to write queue log: {:#}", e);
+    }
+}
+
+/// Handle import command - download articles from a platform into markdown files
+///
+/// Medium posts come from the RSS feed (recent posts only) or from an export archive,
+/// which needs no credentials.
+async fn handle_import_command(
+    platform: Platform,
+    output: String,
+    archive: Option<String>,
+    dry_run: bool,
+) -> Result<()> {
+    let articles = match (&platform, archive) {
+        (Platform::Medium, Some(archive)) => {
+            println!("Reading Medium export: {}", archive);
+            read_medium_export(Path::new(&archive))?
+        }
+        (Platform::DevTo, Some(_)) => {
+            anyhow::bail!("--archive is only supported for Medium exports")
+        }
+        (_, None) => {
+            let config =
+                Config::load().context("Failed to load config. Run 'config init' first.")?;
+
+            println!("Downloading articles from {}...", platform);
+            match platform {
+                Platform::DevTo => DevToClient::from_config(&config.dev_to)
+                    .import_articles()
+                    .await
+                    .context("Failed to download articles from dev.to")?,
+                Platform::Medium => MediumClient::from_config(&config.medium)
+                    .import_articles()
+                    .await
+                    .context("Failed to download posts from Medium")?,
+            }
+        }
+    };
+    println!("Found {} article(s)\n", articles.len());
+
+    let output_dir = Path::new(&output);
+    if !dry_run {
+        fs::create_dir_all(output_dir).context(format!(
+            "Failed to create directory {}",
+            output_dir.display()
+        ))?;
+    }
+    let mut state = State::load().context("Failed to load publication state")?;
+
+    let mut written = 0;
+    for imported in articles {
+        let article = &imported.article;
+        let path = output_dir.join(default_file_name(&article.title));
+
+        if path.exists() {
+            println!("= {}: already exists, skipped", path.display());
+            continue;
+        }
+        if dry_run {
+            println!("~ {}: would write \"{}\"", path.display(), article.title);
+            continue;
+        }
+
+        // Keep the date part of an ISO 8601 timestamp
+        let date = imported.published_at.as_deref().and_then(|d| d.get(..10));
+        let content = render_article(article, date);
+        let mut file = fs::OpenOptions::new()
+            .write(true)
+            .create_new(true)
+            .open(&path)
+            .context(format!("Failed to create {}", path.display()))?;
+        file.write_all(content.as_bytes())
+            .context(format!("Failed to write {}", path.display()))?;
+
+        let input = path.display().to_string();
+        let syndication = &article.syndication;
+        let (url, id) = match platform {
+            Platform::DevTo => (&syndication.devto_url, &syndication.devto_id),
+            Platform::Medium => (&syndication.medium_url, &syndication.medium_id),
+        };
+        if let Some(url) = url {
+            let record = PlatformRecord {
+                url: url.clone(),
+                id: id.clone(),
+                content_hash: String::new(),
+                draft: !article.published,
+            };
+            state.set_record(&state_key(&input, article), &platform, record);
+        }
+
+        written += 1;
+        println!("✓ {}", path.display());
+    }
+
+    if dry_run {
+        return Ok(());
+    }
+
+    state.save().context("Failed to save publication state")?;
+    println!(
+        "\nImported {} article(s) into {}",
+        written,
+        output_dir.display()
+    );
+
+    Ok(())
+}
+
+/// Download the comments and reaction counts of the published dev.to articles
+///
+/// Comments are only requested for articles that have some; drafts have no discussion.
+/// Articles whose comments could not be downloaded are returned with the error, by ID.
+async fn fetch_devto_discussions(
+    client: &DevToClient,
+    articles: &[ImportedArticle],
+) -> (Vec<ArticleDiscussion>, Vec<(String, String)>) {
+    let mut discussions = Vec::new();
+    let mut failures = Vec::new();
+    for imported in articles
+        .iter()
+        .filter(|imported| imported.article.published)
+    {
+        let Some(ref id) = imported.article.syndication.devto_id else {
+            continue;
+        };
+        let engagement = imported.engagement.unwrap_or_default();
+        let comments = if engagement.comments > 0 {
+            match client.fetch_comments(id).await {
+                Ok(comments) => comments,
+                Err(e) => {
+                    println!(
+                        "✗ Failed to download comments of \"{}\": {:#}",
+                        imported.article.title, e
+                    );
+                    failures.push((id.clone(), format!("{:#}", e)));
+                    continue;
+                }
+            }
+        } else {
+            Vec::new()
+        };
+
+        discussions.push(ArticleDiscussion {
+            article_id: id.clone(),
+            fetched_at: chrono::Utc::now(),
+            reactions: engagement.reactions,
+            comments_count: engagement.comments,
+            comments,
+        });
+    }
+
+    (discussions, failures)
+}
+
+/// Handle backup command - archive every article from the platforms locally
+async fn handle_backup_command(
+    output: String,
+    platforms: Vec<Platform>,
+    medium_archive: Option<String>,
+) -> Result<()> {
+    let platforms = if platforms.is_empty() {
+        vec![Platform::DevTo, Platform::Medium]
+    } else {
+        platforms
+    };
+    let config = Config::load().context("Failed to load config. Run 'config init' first.")?;
+
+    let mut manifest = BackupManifest::new();
+    let backup_dir = Path::new(&output).join(manifest.dir_name());
+    fs::create_dir_all(&backup_dir).context(format!(
+        "Failed to create directory {}",
+        backup_dir.display()
+    ))?;
+    println!("Backing up to {}\n", backup_dir.display());
+
+    let devto = DevToClient::from_config(&config.dev_to);
+    for platform in &platforms {
+        let (source, articles) = match (platform, &medium_archive) {
+            (Platform::DevTo, _) => (
+                "api",
+                devto
+                    .import_articles()
+                    .await
+                    .context("Failed to download articles from dev.to"),
+            ),
+            (Platform::Medium, Some(archive)) => {
+                ("archive", read_medium_export(Path::new(archive)))
+            }
+            (Platform::Medium, None) => (
+                "rss",
+                MediumClient::from_config(&config.medium)
+                    .import_articles()
+                    .await
+                    .context("Failed to download posts from Medium"),
+            ),
+        };
+
+        let written = articles.and_then(|articles| {
+            manifest
+                .add_platform(&backup_dir, platform, source, &articles)
+                .map(|count| (count, articles))
+        });
+        match written {
+            Ok((count, articles)) => {
+                println!("✓ {}: {} article(s)", platform, count);
+                if source == "rss" {
+                    println!(
+                        "  Medium's feed only has the 10 most recent posts - pass \
+                         --medium-archive with your Medium export to back up everything"
+                    );
+                }
+                if *platform == Platform::DevTo {
+                    let (discussions, failures) = fetch_devto_discussions(&devto, &articles).await;
+                    let written =
+                        manifest.add_discussions(&backup_dir, platform, &discussions, &failures);
+                    match written {
+                        Ok(count) => {
+                            println!(
+                                "✓ {}: comments and reactions of {} article(s)",
+                                platform, count
+                            )
+                        }
+                        Err(e) => {
+                            println!("✗ {} comments: {:#}", platform, e);
+                            manifest.add_failure(platform, "comments", format!("{:#}", e));
+                        }
+                    }
+                }
+            }
+            Err(e) => {
+                println!("✗ {}: {:#}", platform, e);
+                manifest.add_failure(platform, source, format!("{:#}", e));
+            }
+        }
+    }
+
+    let manifest_path = manifest.save(&backup_dir)?;
+    println!(
+        "\nBacked up {} article(s); manifest: {}",
+        manifest.articles.len(),
+        manifest_path.display()
+    );
+
+    let failed: Vec<&str> = manifest
+        .sources
+        .iter()
+        .filter(|source| source.error.is_some())
+        .map(|source| source.platform.as_str())
+        .collect();
+    if !failed.is_empty() {
+        anyhow::bail!("Backup incomplete - failed on: {}", failed.join(", "));
+    }
+
+    Ok(())
+}
+
 /// Load article from file or dev.to URL
 async fn load_article(input: &str) -> Result<Article> {
+    // A dev.to draft, by ID
+    if let Some(draft_id) = parse_devto_draft_ref(input) {
+        let config = Config::load().context("Failed to load config. Run 'config init' first.")?;
+
+        return fetch_devto_draft(&draft_id, &DevToClient::from_config(&config.dev_to)).await;
+    }
+
     // Check if input is a dev.to URL
     if parse_devto_url(input).is_ok() {
         // Fetch from dev.to - need API key from config
         let config = Config::load().context("Failed to load config. Run 'config init' first.")?;
 
-        fetch_from_devto_url(input, &config.dev_to.api_key)
+        fetch_from_devto_url(input, &DevToClient::from_config(&config.dev_to))
             .await
             .context("Failed to fetch article from dev.to URL")
     } else {
@@ -328,26 +2761,393 @@ async fn load_article(input: &str) -> Result<Article> {
             canonical_path.display()
         ))?;
 
-        parse_markdown(&content).context("Failed to parse markdown file")
+        let mut article = parse_markdown(&content).context("Failed to parse markdown file")?;
+
+        // Local cover images are relative to the article file
+        if let Some(dir) = canonical_path.parent() {
+            article.resolve_cover_path(dir);
+        }
+
+        Ok(article)
     }
 }
 
-/// Publish article to dev.to
-async fn publish_to_devto(client: &DevToClient, article: &Article) -> Result<String> {
-    client
-        .publish_article(article)
+/// Refuse to publish if the frontmatter records a dev.to copy, or dev.to already has an
+/// article with the same title or canonical URL
+async fn ensure_not_duplicate_on_devto(
+    client: &DevToClient,
+    article: &Article,
+    force: bool,
+) -> Result<()> {
+    if force {
+        return Ok(());
+    }
+
+    if let Some(url) = &article.syndication.devto_url {
+        anyhow::bail!(
+            "The article's frontmatter records it as already published on dev.to: {}\n\
+             Use --force to publish anyway.",
+            url
+        );
+    }
+
+    let existing = client
+        .find_existing_article(article)
+        .await
+        .context("Failed to check dev.to for duplicates (use --force to skip this check)")?;
+
+    if let Some(existing) = existing {
+        anyhow::bail!(
+            "An article with the same title or canonical URL already exists on dev.to: {} (id {})\n\
+             Use --force to publish anyway.",
+            existing.url,
+            existing.id
+        );
+    }
+
+    Ok(())
+}
+
+/// Refuse to publish if the frontmatter records a Medium copy, or Medium already has a
+/// recent article with the same title
+async fn ensure_not_duplicate_on_medium(
+    client: &MediumClient,
+    article: &Article,
+    force: bool,
+) -> Result<()> {
+    if force {
+        return Ok(());
+    }
+
+    if let Some(url) = &article.syndication.medium_url {
+        anyhow::bail!(
+            "The article's frontmatter records it as already published on Medium: {}\n\
+             Use --force to publish anyway.",
+            url
+        );
+    }
+
+    let existing = client
+        .find_existing_article(article)
+        .await
+        .context("Failed to check Medium for duplicates (use --force to skip this check)")?;
+
+    if let Some(existing) = existing {
+        anyhow::bail!(
+            "An article with the same title already exists on Medium: {}\n\
+             Use --force to publish anyway.",
+            existing.url
+        );
+    }
+
+    Ok(())
+}
+
+/// Outcome of publishing an article to a single platform
+enum PublishOutcome {
+    /// The article was published; carries the new record
+    Published(PlatformRecord),
+
+    /// The payload is identical to the last post; carries the stored record
+    Unchanged(PlatformRecord),
+
+    /// The article is already on a platform that cannot update posts; carries its record
+    Kept(PlatformRecord),
+}
+
+impl PublishOutcome {
+    /// Publication record (URL and ID) of the article on the platform
+    fn record(&self) -> &PlatformRecord {
+        match self {
+            Self::Published(record) | Self::Unchanged(record) | Self::Kept(record) => record,
+        }
+    }
+}
+
+/// An article's copy on a platform, from the state file or its frontmatter URL fields
+fn existing_record(
+    state: &State,
+    key: &str,
+    article: &Article,
+    platform: &Platform,
+) -> Option<PlatformRecord> {
+    if let Some(record) = state.record(key, platform) {
+        return Some(record.clone());
+    }
+
+    let syndication = &article.syndication;
+    let (url, id) = match platform {
+        Platform::DevTo => (&syndication.devto_url, &syndication.devto_id),
+        Platform::Medium => (&syndication.medium_url, &syndication.medium_id),
+    };
+
+    Some(PlatformRecord {
+        url: url.clone()?,
+        id: id.clone(),
+        content_hash: String::new(),
+        draft: false,
+    })
+}
+
+/// Set an article's URL and ID on a platform, so later steps of a run can use them
+fn set_syndication(article: &mut Article, platform: &Platform, record: &PlatformRecord) {
+    let syndication = &mut article.syndication;
+    let (url, id) = match platform {
+        Platform::DevTo => (&mut syndication.devto_url, &mut syndication.devto_id),
+        Platform::Medium => (&mut syndication.medium_url, &mut syndication.medium_id),
+    };
+
+    *url = Some(record.url.clone());
+    if record.id.is_some() {
+        *id = record.id.clone();
+    }
+}
+
+/// Frontmatter fields (`<platform>_url`, `<platform>_id`) that differ from the recorded copy
+fn syndication_fields(
+    article: &Article,
+    platform: &Platform,
+    record: &PlatformRecord,
+) -> Vec<(&'static str, String)> {
+    let syndication = &article.syndication;
+    let (url_key, id_key, current_url, current_id) = match platform {
+        Platform::DevTo => (
+            "devto_url",
+            "devto_id",
+            &syndication.devto_url,
+            &syndication.devto_id,
+        ),
+        Platform::Medium => (
+            "medium_url",
+            "medium_id",
+            &syndication.medium_url,
+            &syndication.medium_id,
+        ),
+    };
+
+    let mut fields = Vec::new();
+    if current_url.as_deref() != Some(record.url.as_str()) {
+        fields.push((url_key, record.url.clone()));
+    }
+    if let Some(id) = &record.id {
+        if current_id.as_ref() != Some(id) {
+            fields.push((id_key, id.clone()));
+        }
+    }
+
+    fields
+}
+
+/// Write fields into the source file's frontmatter, warning (not failing) on errors
+fn write_frontmatter_fields(input: &str, fields: &[(&str, String)]) {
+    if fields.is_empty() {
+        return;
+    }
+
+    let fields: Vec<(&str, &str)> = fields
+        .iter()
+        .map(|(key, value)| (*key, value.as_str()))
+        .collect();
+
+    match update_frontmatter_file(Path::new(input), &fields) {
+        Ok(()) => {
+            let keys: Vec<&str> = fields.iter().map(|(key, _)| *key).collect();
+            println!("\nUpdated {} in {}", keys.join(", "), input);
+        }
+        Err(e) => eprintln!(
+            "\n⚠️  Warning: Failed to update frontmatter of {}: {:#}",
+            input, e
+        ),
+    }
+}
+
+/// Key of an article's records in the state file: its slug, or else its source
+fn state_key(input: &str, article: &Article) -> String {
+    article.slug.clone().unwrap_or_else(|| article_key(input))
+}
+
+/// Add previous/next links to the neighbouring parts of the article's series
+///
+/// Parts are looked up next to the source file; links point to each part's copy on
+/// the target platform (from its frontmatter or the state file). Parts that are not
+/// published there yet are left out, and get linked on a later `sync`.
+fn add_series_navigation(
+    article: &Article,
+    input: &str,
+    platform: &Platform,
+    state: &State,
+) -> Result<Article> {
+    let Some(series) = &article.series else {
+        return Ok(article.clone());
+    };
+    if is_devto_input(input) {
+        return Ok(article.clone());
+    }
+
+    let path = Path::new(input)
+        .canonicalize()
+        .context(format!("Invalid or inaccessible file path: {}", input))?;
+    let dir = path.parent().context("Failed to get article directory")?;
+    let parts = find_series_parts(dir, series).context("Failed to find series parts")?;
+
+    Ok(link_series_parts(article, &path, &parts, platform, state))
+}
+
+/// Add previous/next links using an ordered list of series parts
+///
+/// `path` must be the canonical path of the article, matching one of the parts.
+fn link_series_parts(
+    article: &Article,
+    path: &Path,
+    parts: &[SeriesPart],
+    platform: &Platform,
+    state: &State,
+) -> Article {
+    let Some(index) = parts.iter().position(|part| part.path == path) else {
+        return article.clone();
+    };
+
+    let link = |part: &SeriesPart| {
+        let input = part.path.display().to_string();
+        let recorded = match platform {
+            Platform::DevTo => &part.article.syndication.devto_url,
+            Platform::Medium => &part.article.syndication.medium_url,
+        };
+        let url = recorded.clone().or_else(|| {
+            state
+                .record(&state_key(&input, &part.article), platform)
+                .map(|record| record.url.clone())
+        })?;
+
+        Some(SeriesLink {
+            title: part.article.title.clone(),
+            url,
+        })
+    };
+
+    let previous = index
+        .checked_sub(1)
+        .and_then(|i| parts.get(i))
+        .and_then(link);
+    let next = parts.get(index + 1).and_then(link);
+
+    let mut linked = article.clone();
+    linked.content = add_series_links(&article.content, previous.as_ref(), next.as_ref());
+
+    linked
+}
+
+/// Key identifying an article source in the state file
+fn article_key(input: &str) -> String {
+    if is_devto_input(input) {
+        return input.to_string();
+    }
+
+    Path::new(input)
+        .canonicalize()
+        .map(|path| path.display().to_string())
+        .unwrap_or_else(|_| input.to_string())
+}
+
+/// Publish article to dev.to, skipping it if the payload is unchanged since the last post
+///
+/// Local images (cover) are uploaded through `image_backend` first, since dev.to has
+/// no image upload API. With `existing_id`, that article is updated in place instead
+/// of creating a new one (and the duplicate check is skipped).
+#[allow(clippy::too_many_arguments)]
+async fn publish_to_devto(
+    client: &DevToClient,
+    image_backend: &MediumClient,
+    article: &Article,
+    options: &SanitizeOptions,
+    key: &str,
+    state: &mut State,
+    force: bool,
+    existing_id: Option<&str>,
+) -> Result<PublishOutcome> {
+    let prepared = DevToClient::build_publish_request(article, options)?;
+    prepared.print_warnings();
+    let mut request = prepared.request;
+    let hash = content_hash(&serde_json::to_string(&request)?);
+
+    if !force && state.is_unchanged(key, &Platform::DevTo, &hash) {
+        // Only unchanged if the recorded article is the one being updated
+        if let Some(record) = state
+            .record(key, &Platform::DevTo)
+            .filter(|record| existing_id.is_none() || record.id.as_deref() == existing_id)
+        {
+            return Ok(PublishOutcome::Unchanged(record.clone()));
+        }
+    }
+
+    if existing_id.is_none() {
+        ensure_not_duplicate_on_devto(client, article, force).await?;
+    }
+
+    image_backend
+        .upload_assets(&mut request, &prepared.assets)
         .await
-        .context("Failed to publish to dev.to")
+        .context("Failed to upload images for dev.to")?;
+
+    let published = match existing_id {
+        Some(id) => client.update_article(id, &request).await?,
+        None => client
+            .publish_request(&request)
+            .await
+            .context("Failed to publish to dev.to")?,
+    };
+
+    let record = PlatformRecord {
+        url: published.url,
+        id: Some(published.id),
+        content_hash: hash,
+        draft: !article.published,
+    };
+    state.set_record(key, &Platform::DevTo, record.clone());
+
+    Ok(PublishOutcome::Published(record))
 }
 
-/// Publish article to Medium
+/// Publish article to Medium, skipping it if the payload is unchanged since the last post
 async fn publish_to_medium(
     client: &MediumClient,
     article: &Article,
     format: &ContentFormat,
-) -> Result<String> {
+    options: &SanitizeOptions,
+    key: &str,
+    state: &mut State,
+    force: bool,
+) -> Result<PublishOutcome> {
+    let prepared = MediumClient::build_publish_request(article, format, options)?;
+    prepared.print_warnings();
+    let mut request = prepared.request;
+    let hash = content_hash(&serde_json::to_string(&request)?);
+
+    if !force && state.is_unchanged(key, &Platform::Medium, &hash) {
+        if let Some(record) = state.record(key, &Platform::Medium) {
+            return Ok(PublishOutcome::Unchanged(record.clone()));
+        }
+    }
+
+    ensure_not_duplicate_on_medium(client, article, force).await?;
+
     client
-        .publish_article(article, format)
+        .upload_assets(&mut request, &prepared.assets)
         .await
-        .context("Failed to publish to Medium")
+        .context("Failed to upload images to Medium")?;
+
+    let published = client
+        .publish_request(&request)
+        .await
+        .context("Failed to publish to Medium")?;
+
+    let record = PlatformRecord {
+        url: published.url,
+        id: Some(published.id),
+        content_hash: hash,
+        draft: !article.published,
+    };
+    state.set_record(key, &Platform::Medium, record.clone());
+
+    Ok(PublishOutcome::Published(record))
 }
@@ -1,4 +1,8 @@
+use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
+use std::path::Path;
+
+use crate::models::{is_local_path, MediumLicense};
 
 /// Lightweight article summary for list output
 #[derive(Debug, Clone)]
@@ -10,6 +14,22 @@ pub struct ArticleSummary {
     pub tags: Vec<String>,
 }
 
+/// Where an article has been cross-posted, as recorded in its frontmatter
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Syndication {
+    /// dev.to article URL
+    pub devto_url: Option<String>,
+
+    /// dev.to article ID
+    pub devto_id: Option<String>,
+
+    /// Medium post URL
+    pub medium_url: Option<String>,
+
+    /// Medium post ID
+    pub medium_id: Option<String>,
+}
+
 /// Internal representation of an article
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Article {
@@ -28,11 +48,47 @@ pub struct Article {
     /// Optional publication status (published, draft, etc.)
     pub published: bool,
 
-    /// Optional cover image URL
+    /// Optional cover image URL or local file path
     pub cover_image: Option<String>,
 
     /// Optional article description/summary
     pub description: Option<String>,
+
+    /// Optional URL slug (used for canonical URLs and state tracking)
+    pub slug: Option<String>,
+
+    /// Remote copies recorded in the frontmatter
+    pub syndication: Syndication,
+
+    /// Optional series name (articles sharing it are parts of one series)
+    pub series: Option<String>,
+
+    /// Optional position within the series
+    pub series_part: Option<u32>,
+
+    /// Optional license of the Medium post (Medium's default when unset)
+    #[serde(default)]
+    pub medium_license: Option<MediumLicense>,
+
+    /// Optional Medium follower notification on publish (Medium's default when unset)
+    #[serde(default)]
+    pub notify_followers: Option<bool>,
+
+    /// Platform IDs to post to when `--to` is not given (empty for the configured defaults)
+    #[serde(default)]
+    pub platforms: Vec<String>,
+
+    /// Platform IDs never to post to unless named with `--to`
+    #[serde(default)]
+    pub exclude_platforms: Vec<String>,
+
+    /// Optional original publication date, for backdating migrated articles
+    #[serde(default)]
+    pub published_at: Option<DateTime<Utc>>,
+
+    /// Language code of a translation (`de`, `pt-br`), set by `post --translate`
+    #[serde(default)]
+    pub lang: Option<String>,
 }
 
 impl Article {
@@ -46,6 +102,16 @@ impl Article {
             published: true,
             cover_image: None,
             description: None,
+            slug: None,
+            syndication: Syndication::default(),
+            series: None,
+            series_part: None,
+            medium_license: None,
+            notify_followers: None,
+            platforms: Vec::new(),
+            exclude_platforms: Vec::new(),
+            published_at: None,
+            lang: None,
         }
     }
 
@@ -78,4 +144,97 @@ impl Article {
         self.description = Some(description);
         self
     }
+
+    /// Builder pattern: set slug
+    pub fn with_slug(mut self, slug: String) -> Self {
+        self.slug = Some(slug);
+        self
+    }
+
+    /// Builder pattern: set recorded remote copies
+    pub fn with_syndication(mut self, syndication: Syndication) -> Self {
+        self.syndication = syndication;
+        self
+    }
+
+    /// Builder pattern: set series name and optional part number
+    pub fn with_series(mut self, series: String, part: Option<u32>) -> Self {
+        self.series = Some(series);
+        self.series_part = part;
+        self
+    }
+
+    /// Builder pattern: set Medium license
+    pub fn with_medium_license(mut self, license: MediumLicense) -> Self {
+        self.medium_license = Some(license);
+        self
+    }
+
+    /// Builder pattern: set whether Medium notifies followers
+    pub fn with_notify_followers(mut self, notify: bool) -> Self {
+        self.notify_followers = Some(notify);
+        self
+    }
+
+    /// Builder pattern: set the target platform IDs
+    pub fn with_platforms(mut self, platforms: Vec<String>) -> Self {
+        self.platforms = platforms;
+        self
+    }
+
+    /// Builder pattern: set the excluded platform IDs
+    pub fn with_excluded_platforms(mut self, platforms: Vec<String>) -> Self {
+        self.exclude_platforms = platforms;
+        self
+    }
+
+    /// Builder pattern: set the original publication date
+    pub fn with_published_at(mut self, published_at: DateTime<Utc>) -> Self {
+        self.published_at = Some(published_at);
+        self
+    }
+
+    /// Builder pattern: mark the article as a translation into a language
+    pub fn with_lang(mut self, lang: String) -> Self {
+        self.lang = Some(lang);
+        self
+    }
+
+    /// Original publication date, if it lies before the current day (UTC)
+    ///
+    /// A date of today means "now", and a future date is not a backdate, so neither
+    /// needs to be sent to a platform.
+    pub fn backdate(&self) -> Option<DateTime<Utc>> {
+        self.published_at
+            .filter(|date| date.date_naive() < Utc::now().date_naive())
+    }
+
+    /// Resolve a relative local `cover_image` path against the article's directory
+    pub fn resolve_cover_path(&mut self, base_dir: &Path) {
+        if let Some(cover) = &self.cover_image {
+            if is_local_path(cover) && Path::new(cover).is_relative() {
+                self.cover_image = Some(base_dir.join(cover).display().to_string());
+            }
+        }
+    }
+
+    /// Check whether a remote article with the given title and canonical URL is a copy of this one
+    ///
+    /// Titles are compared case-insensitively; canonical URLs ignore a trailing slash.
+    /// Translations share the original's canonical URL, so only their titles are compared.
+    pub fn is_duplicate_of(&self, title: &str, canonical_url: Option<&str>) -> bool {
+        if self.title.trim().eq_ignore_ascii_case(title.trim()) {
+            return true;
+        }
+        if self.lang.is_some() {
+            return false;
+        }
+
+        match (self.canonical_url.as_deref(), canonical_url) {
+            (Some(ours), Some(theirs)) => {
+                ours.trim_end_matches('/') == theirs.trim_end_matches('/')
+            }
+            _ => false,
+        }
+    }
 }
@@ -0,0 +1,116 @@
+use anyhow::{Context, Result};
+use sha2::{Digest, Sha256};
+use std::fs;
+use std::path::Path;
+
+/// URL scheme used for assets that still have to be uploaded
+pub const ASSET_SCHEME: &str = "asset://";
+
+/// A binary file (e.g. a rendered image) that must be uploaded before publishing
+///
+/// Content references the asset through its placeholder URL, which is replaced with
+/// the hosted URL once the upload succeeds. The file name embeds a short content hash,
+/// so the publish payload changes whenever the asset does.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Asset {
+    /// File name sent with the upload (`<stem>-<hash>.<ext>`)
+    pub file_name: String,
+
+    /// MIME type of the data
+    pub content_type: String,
+
+    /// Raw file contents
+    pub data: Vec<u8>,
+}
+
+impl Asset {
+    /// Create an asset, deriving its file name from a stem, the content hash and an extension
+    pub fn new(stem: &str, extension: &str, content_type: &str, data: Vec<u8>) -> Self {
+        let hash = format!("{:x}", Sha256::digest(&data));
+
+        Self {
+            file_name: format!("{}-{}.{}", stem, &hash[..8], extension),
+            content_type: content_type.to_string(),
+            data,
+        }
+    }
+
+    /// Load a local image file as an asset
+    ///
+    /// Only formats accepted by the image backend (PNG, JPEG, GIF, TIFF) are allowed.
+    pub fn from_image_file(path: &Path, stem: &str) -> Result<Self> {
+        let extension = path
+            .extension()
+            .and_then(|ext| ext.to_str())
+            .map(|ext| ext.to_ascii_lowercase())
+            .unwrap_or_default();
+
+        let content_type = match extension.as_str() {
+            "png" => "image/png",
+            "jpg" | "jpeg" => "image/jpeg",
+            "gif" => "image/gif",
+            "tif" | "tiff" => "image/tiff",
+            _ => anyhow::bail!(
+                "Unsupported image type '{}' for {} (supported: png, jpg, jpeg, gif, tiff)",
+                extension,
+                path.display()
+            ),
+        };
+
+        let data =
+            fs::read(path).context(format!("Failed to read image file: {}", path.display()))?;
+
+        Ok(Self::new(stem, &extension, content_type, data))
+    }
+
+    /// Placeholder URL used in content until the asset is uploaded
+    pub fn placeholder(&self) -> String {
+        format!("{}{}", ASSET_SCHEME, self.file_name)
+    }
+}
+
+/// True if an image reference is a local file path rather than a hosted URL or placeholder
+pub fn is_local_path(reference: &str) -> bool {
+    !(reference.starts_with("http://")
+        || reference.starts_with("https://")
+        || reference.starts_with(ASSET_SCHEME))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_asset_name_tracks_content() {
+        let a = Asset::new("table-1", "png", "image/png", vec![1, 2, 3]);
+        let b = Asset::new("table-1", "png", "image/png", vec![1, 2, 4]);
+
+        assert!(a.file_name.starts_with("table-1-"));
+        assert!(a.file_name.ends_with(".png"));
+        assert_ne!(a.file_name, b.file_name);
+        assert_eq!(a.placeholder(), format!("asset://{}", a.file_name));
+    }
+
+    #[test]
+    fn test_asset_from_image_file() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let jpeg = dir.path().join("Cover.JPG");
+        fs::write(&jpeg, b"fake jpeg").unwrap();
+        fs::write(dir.path().join("cover.bmp"), b"fake bmp").unwrap();
+
+        let asset = Asset::from_image_file(&jpeg, "cover").unwrap();
+        assert_eq!(asset.content_type, "image/jpeg");
+        assert!(asset.file_name.starts_with("cover-") && asset.file_name.ends_with(".jpg"));
+
+        assert!(Asset::from_image_file(&dir.path().join("cover.bmp"), "cover").is_err());
+        assert!(Asset::from_image_file(&dir.path().join("missing.png"), "cover").is_err());
+    }
+
+    #[test]
+    fn test_is_local_path() {
+        assert!(is_local_path("images/cover.png"));
+        assert!(is_local_path("/abs/cover.png"));
+        assert!(!is_local_path("https://example.com/cover.png"));
+        assert!(!is_local_path("asset://cover-1234abcd.png"));
+    }
+}
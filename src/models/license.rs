@@ -0,0 +1,108 @@
+use serde::{Deserialize, Serialize};
+
+/// License of a Medium post, as named by the Medium API's `license` field
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum MediumLicense {
+    /// Medium's default
+    #[default]
+    #[serde(rename = "all-rights-reserved")]
+    AllRightsReserved,
+
+    #[serde(rename = "cc-40-by")]
+    Cc40By,
+
+    #[serde(rename = "cc-40-by-sa")]
+    Cc40BySa,
+
+    #[serde(rename = "cc-40-by-nd")]
+    Cc40ByNd,
+
+    #[serde(rename = "cc-40-by-nc")]
+    Cc40ByNc,
+
+    #[serde(rename = "cc-40-by-nc-nd")]
+    Cc40ByNcNd,
+
+    #[serde(rename = "cc-40-by-nc-sa")]
+    Cc40ByNcSa,
+
+    #[serde(rename = "cc-40-zero")]
+    Cc40Zero,
+
+    #[serde(rename = "public-domain")]
+    PublicDomain,
+}
+
+impl MediumLicense {
+    /// Every license, in the order Medium lists them
+    pub const ALL: [MediumLicense; 9] = [
+        MediumLicense::AllRightsReserved,
+        MediumLicense::Cc40By,
+        MediumLicense::Cc40BySa,
+        MediumLicense::Cc40ByNd,
+        MediumLicense::Cc40ByNc,
+        MediumLicense::Cc40ByNcNd,
+        MediumLicense::Cc40ByNcSa,
+        MediumLicense::Cc40Zero,
+        MediumLicense::PublicDomain,
+    ];
+
+    /// Medium API value
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            MediumLicense::AllRightsReserved => "all-rights-reserved",
+            MediumLicense::Cc40By => "cc-40-by",
+            MediumLicense::Cc40BySa => "cc-40-by-sa",
+            MediumLicense::Cc40ByNd => "cc-40-by-nd",
+            MediumLicense::Cc40ByNc => "cc-40-by-nc",
+            MediumLicense::Cc40ByNcNd => "cc-40-by-nc-nd",
+            MediumLicense::Cc40ByNcSa => "cc-40-by-nc-sa",
+            MediumLicense::Cc40Zero => "cc-40-zero",
+            MediumLicense::PublicDomain => "public-domain",
+        }
+    }
+}
+
+impl std::str::FromStr for MediumLicense {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.to_lowercase();
+        Self::ALL
+            .into_iter()
+            .find(|license| license.as_str() == s)
+            .ok_or_else(|| {
+                let valid: Vec<&str> = Self::ALL.iter().map(|l| l.as_str()).collect();
+                format!(
+                    "Unknown license: '{}'. Valid options: {}",
+                    s,
+                    valid.join(", ")
+                )
+            })
+    }
+}
+
+impl std::fmt::Display for MediumLicense {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_medium_license_round_trip() {
+        for license in MediumLicense::ALL {
+            assert_eq!(license.as_str().parse::<MediumLicense>(), Ok(license));
+            assert_eq!(
+                serde_json::to_string(&license).unwrap(),
+                format!("\"{}\"", license)
+            );
+        }
+
+        assert_eq!("CC-40-BY".parse(), Ok(MediumLicense::Cc40By));
+        assert!("cc-by".parse::<MediumLicense>().is_err());
+    }
+}
@@ -1,3 +1,7 @@
 pub mod article;
+pub mod asset;
+pub mod license;
 
-pub use article::{Article, ArticleSummary};
+pub use article::{Article, ArticleSummary, Syndication};
+pub use asset::{is_local_path, Asset};
+pub use license::MediumLicense;
@@ -1,122 +1,200 @@
-/// Clean AI artifacts from text
+use anyhow::{Context, Result};
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use std::borrow::Cow;
+
+use crate::parsers::segments::write_prose;
+
+/// User-defined text replacement rule (`[[cleaner.rules]]` in the config file)
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CleanerRule {
+    /// Regular expression to search for
+    pub pattern: String,
+
+    /// Replacement text; may reference capture groups as `$1` or `${name}`
+    pub replace: String,
+}
+
+/// AI artifact cleaner with optional user-defined rules
 ///
-/// Removes Unicode emojis, smart quotes, dashes, and other AI-generated formatting
-pub fn clean_ai_artifacts(text: &str) -> String {
-    let mut result = text.to_string();
+/// Built-in rules run first, then user rules in the order they are defined.
+#[derive(Debug, Default)]
+pub struct Cleaner {
+    rules: Vec<(Regex, String)>,
+}
+
+impl Cleaner {
+    /// Compile user-defined rules
+    pub fn new(rules: &[CleanerRule]) -> Result<Self> {
+        let rules = rules
+            .iter()
+            .map(|rule| {
+                Regex::new(&rule.pattern)
+                    .map(|regex| (regex, rule.replace.clone()))
+                    .context(format!("Invalid cleaner rule pattern '{}'", rule.pattern))
+            })
+            .collect::<Result<_>>()?;
+
+        Ok(Self { rules })
+    }
+
+    /// Clean markdown text, leaving fenced/indented code blocks and inline code untouched
+    ///
+    /// The built-in rules write into a single output buffer in one pass over the text;
+    /// user rules, if any, then run on each cleaned prose segment.
+    pub fn clean(&self, text: &str) -> String {
+        let mut output = String::with_capacity(text.len());
 
-    // Remove Unicode emojis
-    result = remove_emojis(&result);
+        if self.rules.is_empty() {
+            write_prose(text, &mut output, clean_prose_into);
+            return output;
+        }
 
-    // Replace typographic characters
-    result = replace_typography(&result);
+        let mut segment = String::new();
+        write_prose(text, &mut output, |prose, output| {
+            segment.clear();
+            clean_prose_into(prose, &mut segment);
 
-    // Remove special whitespace and zero-width characters
-    result = clean_whitespace(&result);
+            let mut result = Cow::Borrowed(segment.as_str());
+            for (regex, replace) in &self.rules {
+                if let Cow::Owned(replaced) = regex.replace_all(&result, replace.as_str()) {
+                    result = Cow::Owned(replaced);
+                }
+            }
+            output.push_str(&result);
+        });
 
-    result
+        output
+    }
 }
 
-/// Remove Unicode emoji characters
-fn remove_emojis(text: &str) -> String {
-    text.chars()
-        .filter(|&c| {
-            let code = c as u32;
-            // Emoji ranges
-            let is_emoji = matches!(code,
-                0x1F600..=0x1F64F | // Emoticons
-                0x1F300..=0x1F5FF | // Misc Symbols and Pictographs
-                0x1F680..=0x1F6FF | // Transport and Map
-                0x1F1E0..=0x1F1FF | // Regional Indicators
-                0x2600..=0x26FF   | // Misc symbols
-                0x2700..=0x27BF   | // Dingbats
-                0xFE00..=0xFE0F   | // Variation Selectors
-                0x1F900..=0x1F9FF | // Supplemental Symbols and Pictographs
-                0x1F018..=0x1F270 | // Various asian characters
-                0x238C..=0x2454   | // Misc items
-                0x20D0..=0x20FF     // Combining Diacritical Marks for Symbols
-            );
-            !is_emoji
-        })
-        .collect()
+/// Clean AI artifacts from markdown text
+///
+/// Removes Unicode emojis, smart quotes, dashes, and other AI-generated formatting.
+/// Fenced/indented code blocks and inline code are left untouched.
+pub fn clean_ai_artifacts(text: &str) -> String {
+    Cleaner::default().clean(text)
 }
 
-/// Replace typographic characters with ASCII equivalents
-fn replace_typography(text: &str) -> String {
-    text
-        // Em dash → double hyphen
-        .replace('\u{2014}', "--")
-        .replace("—", "--")
-        // En dash → single hyphen
-        .replace('\u{2013}', "-")
-        .replace("–", "-")
-        // Smart double quotes → straight quotes
-        .replace(['\u{201C}', '\u{201D}'], "\"")
-        // Smart single quotes → straight apostrophes
-        .replace(['\u{2018}', '\u{2019}'], "'")
-        // Ellipsis → three dots
-        .replace('\u{2026}', "...")
+/// Clean AI artifacts from a prose segment (no code), appending the result to `output`
+///
+/// Text up to the first character that needs cleaning is copied as one slice, so
+/// clean prose costs a single copy.
+fn clean_prose_into(text: &str, output: &mut String) {
+    let Some(first) = text.find(needs_cleaning) else {
+        output.push_str(text);
+        return;
+    };
+    output.push_str(&text[..first]);
+
+    for c in text[first..].chars() {
+        match c {
+            // Em dash → double hyphen
+            '\u{2014}' => output.push_str("--"),
+            // En dash → single hyphen
+            '\u{2013}' => output.push('-'),
+            // Smart double quotes → straight quotes
+            '\u{201C}' | '\u{201D}' => output.push('"'),
+            // Smart single quotes → straight apostrophes
+            '\u{2018}' | '\u{2019}' => output.push('\''),
+            // Ellipsis → three dots
+            '\u{2026}' => output.push_str("..."),
+            c if is_emoji(c) || is_invisible(c) => {}
+            c => output.push(c),
+        }
+    }
 }
 
-/// Clean special whitespace and zero-width characters
-fn clean_whitespace(text: &str) -> String {
-    text.chars()
-        .filter(|&c| {
-            // Filter out problematic whitespace
-            !matches!(
-                c,
-                '\u{00A0}' | // Non-breaking space
-                '\u{200B}' | // Zero-width space
-                '\u{200C}' | // Zero-width non-joiner
-                '\u{200D}' | // Zero-width joiner
-                '\u{FEFF}' // Zero-width no-break space
-            )
-        })
-        .collect::<String>()
+/// True for characters `clean_prose_into` replaces or removes
+fn needs_cleaning(c: char) -> bool {
+    matches!(
+        c,
+        '\u{2014}' | '\u{2013}' | '\u{201C}' | '\u{201D}' | '\u{2018}' | '\u{2019}' | '\u{2026}'
+    ) || is_emoji(c)
+        || is_invisible(c)
+}
+
+/// Unicode emoji characters
+fn is_emoji(c: char) -> bool {
+    let code = c as u32;
+    // ASCII is never an emoji; checked first because it is nearly all of the text
+    code >= 0x20D0
+        && matches!(code,
+            0x1F600..=0x1F64F | // Emoticons
+            0x1F300..=0x1F5FF | // Misc Symbols and Pictographs
+            0x1F680..=0x1F6FF | // Transport and Map
+            0x1F1E0..=0x1F1FF | // Regional Indicators
+            0x2600..=0x26FF   | // Misc symbols
+            0x2700..=0x27BF   | // Dingbats
+            0xFE00..=0xFE0F   | // Variation Selectors
+            0x1F900..=0x1F9FF | // Supplemental Symbols and Pictographs
+            0x1F018..=0x1F270 | // Various asian characters
+            0x238C..=0x2454   | // Misc items
+            0x20D0..=0x20FF     // Combining Diacritical Marks for Symbols
+        )
+}
+
+/// Special whitespace and zero-width characters
+fn is_invisible(c: char) -> bool {
+    matches!(
+        c,
+        '\u{00A0}' | // Non-breaking space
+        '\u{200B}' | // Zero-width space
+        '\u{200C}' | // Zero-width non-joiner
+        '\u{200D}' | // Zero-width joiner
+        '\u{FEFF}' // Zero-width no-break space
+    )
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    fn clean_prose(text: &str) -> String {
+        let mut output = String::new();
+        clean_prose_into(text, &mut output);
+        output
+    }
+
     #[test]
     fn test_remove_emojis() {
         let text = "Hello 👋 World 🌍!";
-        let cleaned = remove_emojis(text);
+        let cleaned = clean_prose(text);
         assert_eq!(cleaned, "Hello  World !");
     }
 
     #[test]
     fn test_replace_em_dash() {
         let text = "This is an em dash — right here.";
-        let cleaned = replace_typography(text);
+        let cleaned = clean_prose(text);
         assert_eq!(cleaned, "This is an em dash -- right here.");
     }
 
     #[test]
     fn test_replace_en_dash() {
         let text = "Range: 1–10";
-        let cleaned = replace_typography(text);
+        let cleaned = clean_prose(text);
         assert_eq!(cleaned, "Range: 1-10");
     }
 
     #[test]
     fn test_replace_smart_quotes() {
         let text = "\u{201C}Hello\u{201D} and \u{2018}world\u{2019}";
-        let cleaned = replace_typography(text);
+        let cleaned = clean_prose(text);
         assert_eq!(cleaned, "\"Hello\" and 'world'");
     }
 
     #[test]
     fn test_replace_ellipsis() {
         let text = "Wait…";
-        let cleaned = replace_typography(text);
+        let cleaned = clean_prose(text);
         assert_eq!(cleaned, "Wait...");
     }
 
     #[test]
     fn test_clean_zero_width_characters() {
         let text = "Hello\u{200B}World\u{FEFF}!";
-        let cleaned = clean_whitespace(text);
+        let cleaned = clean_prose(text);
         assert_eq!(cleaned, "HelloWorld!");
     }
 
@@ -131,6 +209,62 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_clean_ai_artifacts_skips_code() {
+        let text = "Use \u{201C}quotes\u{201D} \u{2014} not `\"\u{2014}\"` \u{2026}\n\n\
+                    ```python\nprint(\"\u{201C}caf\u{00E9}\u{201D} \u{2014} \u{2705}\")\n```\n\n\
+                    Done \u{2705}\n";
+        let cleaned = clean_ai_artifacts(text);
+
+        assert_eq!(
+            cleaned,
+            "Use \"quotes\" -- not `\"\u{2014}\"` ...\n\n\
+             ```python\nprint(\"\u{201C}caf\u{00E9}\u{201D} \u{2014} \u{2705}\")\n```\n\n\
+             Done \n"
+        );
+    }
+
+    #[test]
+    fn test_cleaner_user_rules() {
+        let cleaner = Cleaner::new(&[
+            CleanerRule {
+                pattern: r"\bAcme Corp\b".to_string(),
+                replace: "ACME".to_string(),
+            },
+            CleanerRule {
+                pattern: r"https://old\.example\.com/(\S+)".to_string(),
+                replace: "https://example.com/$1".to_string(),
+            },
+            // Runs after the built-in rules, so it sees straight quotes
+            CleanerRule {
+                pattern: "\"delve\"".to_string(),
+                replace: "\"explore\"".to_string(),
+            },
+        ])
+        .unwrap();
+
+        let cleaned = cleaner.clean(
+            "Acme Corp \u{2014} see https://old.example.com/docs and \u{201C}delve\u{201D}.\n\n\
+             `Acme Corp`\n",
+        );
+
+        assert_eq!(
+            cleaned,
+            "ACME -- see https://example.com/docs and \"explore\".\n\n`Acme Corp`\n"
+        );
+    }
+
+    #[test]
+    fn test_cleaner_rejects_invalid_pattern() {
+        let err = Cleaner::new(&[CleanerRule {
+            pattern: "(unclosed".to_string(),
+            replace: String::new(),
+        }])
+        .unwrap_err();
+
+        assert!(err.to_string().contains("(unclosed"));
+    }
+
     #[test]
     fn test_clean_ai_artifacts_preserves_normal_text() {
         let text = "Normal text without any special characters.";
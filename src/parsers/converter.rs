@@ -1,22 +1,12 @@
 use anyhow::Result;
 use pulldown_cmark::{html, Options, Parser};
 
-/// Medium's approximate content size limit (1MB)
-const MEDIUM_MAX_CONTENT_SIZE: usize = 1024 * 1024;
-
 /// Convert markdown to HTML safely
 ///
 /// This function converts markdown to HTML without allowing raw HTML passthrough,
-/// preventing XSS attacks. It also validates content size limits.
+/// preventing XSS attacks. Size limits are checked by the caller for the target
+/// platform (see `body_size_issue`).
 pub fn markdown_to_html(markdown: &str) -> Result<String> {
-    if markdown.len() > MEDIUM_MAX_CONTENT_SIZE {
-        anyhow::bail!(
-            "Content too large for conversion: {} bytes (max: {})",
-            markdown.len(),
-            MEDIUM_MAX_CONTENT_SIZE
-        );
-    }
-
     // Configure options - IMPORTANT: Do NOT enable ENABLE_HTML to prevent XSS
     let mut options = Options::empty();
     options.insert(Options::ENABLE_STRIKETHROUGH);
@@ -30,14 +20,6 @@ pub fn markdown_to_html(markdown: &str) -> Result<String> {
     let mut html_output = String::new();
     html::push_html(&mut html_output, parser);
 
-    if html_output.len() > MEDIUM_MAX_CONTENT_SIZE {
-        anyhow::bail!(
-            "Converted HTML too large: {} bytes (max: {})",
-            html_output.len(),
-            MEDIUM_MAX_CONTENT_SIZE
-        );
-    }
-
     Ok(html_output)
 }
 
@@ -59,6 +41,38 @@ pub fn ensure_title_in_content(title: &str, content: &str) -> String {
     }
 }
 
+/// Remove a leading H1 heading that repeats the title
+///
+/// The mirror of `ensure_title_in_content` for platforms that display the title
+/// themselves (dev.to). Only an H1 matching the title (ignoring case and closing `#`s)
+/// is removed; any other opening heading is kept.
+pub fn strip_title_from_content(title: &str, content: &str) -> String {
+    let trimmed = content.trim_start();
+    let (first, rest) = trimmed.split_once('\n').unwrap_or((trimmed, ""));
+
+    let Some(heading) = first.strip_prefix("# ") else {
+        return content.to_string();
+    };
+    let heading = strip_closing_sequence(heading.trim());
+
+    if heading.to_lowercase() == title.trim().to_lowercase() {
+        rest.trim_start_matches(['\r', '\n']).to_string()
+    } else {
+        content.to_string()
+    }
+}
+
+/// Remove an ATX heading's closing `#` sequence, which CommonMark only recognizes after
+/// whitespace (`# Title ##`), so `# Learning C#` keeps its `#`
+fn strip_closing_sequence(heading: &str) -> &str {
+    let without = heading.trim_end_matches('#');
+    if without.is_empty() || without.ends_with([' ', '\t']) {
+        without.trim_end()
+    } else {
+        heading
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -106,15 +120,6 @@ mod tests {
         // explicitly parse and pass through HTML tags.
     }
 
-    #[test]
-    fn test_markdown_to_html_size_limit() {
-        let huge_markdown = "a".repeat(MEDIUM_MAX_CONTENT_SIZE + 1);
-        let result = markdown_to_html(&huge_markdown);
-
-        assert!(result.is_err());
-        assert!(result.unwrap_err().to_string().contains("too large"));
-    }
-
     #[test]
     fn test_ensure_title_when_missing() {
         let title = "My Article";
@@ -146,6 +151,37 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_strip_title_from_content() {
+        assert_eq!(
+            strip_title_from_content("My Article", "# My article ##\n\nThe content."),
+            "The content."
+        );
+        assert_eq!(strip_title_from_content("My Article", "\n# My Article"), "");
+        assert_eq!(
+            strip_title_from_content("Learning C#", "# Learning C#\n\nThe content."),
+            "The content."
+        );
+        assert_eq!(
+            strip_title_from_content("Learning C#", "# Learning C# #\n\nThe content."),
+            "The content."
+        );
+        let unrelated = "# Learning C\n\nThe content.";
+        assert_eq!(
+            strip_title_from_content("Learning C#", unrelated),
+            unrelated
+        );
+
+        // Other headings are kept
+        let different = "# Introduction\n\nThe content.";
+        assert_eq!(strip_title_from_content("My Article", different), different);
+        let subheading = "## My Article\n\nThe content.";
+        assert_eq!(
+            strip_title_from_content("My Article", subheading),
+            subheading
+        );
+    }
+
     #[test]
     fn test_ensure_title_with_any_h1() {
         let title = "My Article";
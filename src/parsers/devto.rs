@@ -2,6 +2,7 @@ use anyhow::{Context, Result};
 use once_cell::sync::Lazy;
 use regex::Regex;
 
+use crate::cli::cache;
 use crate::models::Article;
 use crate::platforms::devto::DevToClient;
 
@@ -12,6 +13,9 @@ use crate::platforms::devto::DevToClient;
 static DEVTO_URL_PATTERN: Lazy<Regex> =
     Lazy::new(|| Regex::new(r"https?://dev\.to/[^/]+/[^/]+-([a-z0-9]+)/?$").unwrap());
 
+/// Prefix of inputs naming a dev.to draft by ID (`devto-draft:123456`)
+const DEVTO_DRAFT_PREFIX: &str = "devto-draft:";
+
 /// Parse dev.to URL and extract article ID
 pub fn parse_devto_url(url: &str) -> Result<String> {
     let captures = DEVTO_URL_PATTERN
@@ -27,17 +31,51 @@ pub fn parse_devto_url(url: &str) -> Result<String> {
     Ok(article_id)
 }
 
+/// Parse a dev.to draft reference (`devto-draft:<id>`) and extract the article ID
+///
+/// Drafts have no public URL, so they are named by the ID shown in
+/// `list --from devto --state unpublished`.
+pub fn parse_devto_draft_ref(input: &str) -> Option<String> {
+    let id = input.strip_prefix(DEVTO_DRAFT_PREFIX)?.trim();
+    (!id.is_empty() && id.chars().all(|c| c.is_ascii_digit())).then(|| id.to_string())
+}
+
+/// True for inputs fetched from dev.to (article URLs and draft references) rather than files
+pub fn is_devto_input(input: &str) -> bool {
+    parse_devto_url(input).is_ok() || parse_devto_draft_ref(input).is_some()
+}
+
+/// Fetch one of the user's dev.to drafts by ID
+pub async fn fetch_devto_draft(article_id: &str, client: &DevToClient) -> Result<Article> {
+    client
+        .fetch_draft(article_id)
+        .await
+        .context(format!("Failed to fetch dev.to draft {}", article_id))
+}
+
 /// Fetch article from dev.to URL
-pub async fn fetch_from_devto_url(url: &str, api_key: &str) -> Result<Article> {
+///
+/// A cached copy (see `cli::cache`) is revalidated with its ETag / Last-Modified
+/// instead of being downloaded again.
+pub async fn fetch_from_devto_url(url: &str, client: &DevToClient) -> Result<Article> {
     let article_id = parse_devto_url(url)?;
 
-    let client = DevToClient::new(api_key.to_string());
-    let article = client
-        .fetch_article(&article_id)
+    let cached = cache::load_devto_article(&article_id);
+    let response = client
+        .fetch_article_response(&article_id, cached.as_ref())
         .await
         .context("Failed to fetch article from dev.to")?;
 
-    Ok(article)
+    if cached.as_ref() == Some(&response) {
+        println!(
+            "Using cached copy of dev.to article {} (not modified)",
+            article_id
+        );
+    } else if let Err(e) = cache::save_devto_article(&article_id, &response) {
+        eprintln!("⚠️  Warning: Failed to cache dev.to article: {:#}", e);
+    }
+
+    DevToClient::parse_article_response(&response.body)
 }
 
 #[cfg(test)]
@@ -73,4 +111,19 @@ mod tests {
         let result = parse_devto_url(url);
         assert!(result.is_err());
     }
+
+    #[test]
+    fn test_parse_devto_draft_ref() {
+        assert_eq!(
+            parse_devto_draft_ref("devto-draft:123456"),
+            Some("123456".to_string())
+        );
+        assert_eq!(parse_devto_draft_ref("devto-draft:"), None);
+        assert_eq!(parse_devto_draft_ref("devto-draft:../x"), None);
+        assert_eq!(parse_devto_draft_ref("posts/devto-draft:1.md"), None);
+
+        assert!(is_devto_input("devto-draft:42"));
+        assert!(is_devto_input("https://dev.to/username/my-article-1a2b3c"));
+        assert!(!is_devto_input("posts/article.md"));
+    }
 }
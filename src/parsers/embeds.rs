@@ -0,0 +1,194 @@
+use pulldown_cmark::{Event, Options, Parser, Tag, TagEnd};
+use reqwest::Url;
+
+/// How a platform shows media a paragraph links to on its own
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EmbedStyle {
+    /// dev.to liquid tags (`{% youtube dQw4w9WgXcQ %}`)
+    LiquidTag,
+
+    /// The bare URL on its own line, which the platform unfurls (Medium)
+    BareUrl,
+}
+
+/// Media that platforms can embed
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Media {
+    /// YouTube video ID
+    YouTube(String),
+
+    /// Tweet ID
+    Tweet(String),
+
+    /// GitHub repository (`owner/repo`), issue or pull request URL
+    GitHub(String),
+
+    /// Gist URL
+    Gist(String),
+}
+
+/// Turn paragraphs that hold nothing but a YouTube, Twitter/X or GitHub URL into embeds
+///
+/// Only top-level paragraphs are considered; URLs inside text, lists, quotes and code
+/// stay links. The URL may be bare, in angle brackets, or a link whose text is the URL.
+/// Returns the new content and the number of embeds.
+pub fn embed_media_urls(content: &str, style: EmbedStyle) -> (String, usize) {
+    let mut options = Options::empty();
+    options.insert(Options::ENABLE_TABLES);
+    options.insert(Options::ENABLE_FOOTNOTES);
+
+    let mut output = String::with_capacity(content.len());
+    let mut cursor = 0;
+    let mut count = 0;
+    let mut nesting = 0;
+
+    for (event, range) in Parser::new_ext(content, options).into_offset_iter() {
+        match event {
+            Event::Start(Tag::BlockQuote(_) | Tag::List(_) | Tag::Table(_))
+            | Event::Start(Tag::FootnoteDefinition(_)) => nesting += 1,
+            Event::End(TagEnd::BlockQuote(_) | TagEnd::List(_) | TagEnd::Table)
+            | Event::End(TagEnd::FootnoteDefinition) => nesting -= 1,
+            Event::Start(Tag::Paragraph) if nesting == 0 => {
+                let paragraph = content[range.clone()].trim_end();
+                let Some(url) = lone_url(paragraph) else {
+                    continue;
+                };
+                let Some(media) = media(url) else {
+                    continue;
+                };
+                let embed = match style {
+                    EmbedStyle::LiquidTag => liquid_tag(&media),
+                    EmbedStyle::BareUrl => url.to_string(),
+                };
+                output.push_str(&content[cursor..range.start]);
+                output.push_str(&embed);
+                cursor = range.start + paragraph.len();
+                count += 1;
+            }
+            _ => {}
+        }
+    }
+    output.push_str(&content[cursor..]);
+
+    (output, count)
+}
+
+/// The URL of a paragraph made of a single URL: bare, `<url>` or `[url](url)`
+fn lone_url(paragraph: &str) -> Option<&str> {
+    let text = paragraph.trim();
+    let url = if let Some(inner) = text.strip_prefix('<').and_then(|t| t.strip_suffix('>')) {
+        inner
+    } else if let Some(inner) = text.strip_prefix('[').and_then(|t| t.strip_suffix(')')) {
+        let (label, target) = inner.split_once("](")?;
+        if label != target {
+            return None;
+        }
+        target
+    } else {
+        text
+    };
+
+    let is_url = (url.starts_with("https://") || url.starts_with("http://"))
+        && !url.contains(char::is_whitespace);
+    is_url.then_some(url)
+}
+
+/// Recognize an embeddable media URL
+fn media(url: &str) -> Option<Media> {
+    let parsed = Url::parse(url).ok()?;
+    let host = parsed.host_str()?.trim_start_matches("www.");
+    let host = host.strip_prefix("m.").unwrap_or(host);
+    let segments: Vec<&str> = parsed
+        .path_segments()
+        .map(|segments| segments.filter(|s| !s.is_empty()).collect())
+        .unwrap_or_default();
+    let is_id = |id: &str| {
+        !id.is_empty()
+            && id
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+    };
+
+    match (host, segments.as_slice()) {
+        ("youtube.com", ["watch"]) => parsed
+            .query_pairs()
+            .find(|(key, _)| key == "v")
+            .map(|(_, id)| id.to_string())
+            .filter(|id| is_id(id))
+            .map(Media::YouTube),
+        ("youtube.com", ["shorts" | "embed" | "live", id]) | ("youtu.be", [id]) if is_id(id) => {
+            Some(Media::YouTube(id.to_string()))
+        }
+        ("twitter.com" | "x.com", [_, "status", id]) if id.chars().all(|c| c.is_ascii_digit()) => {
+            Some(Media::Tweet(id.to_string()))
+        }
+        ("github.com", [owner, repo]) => Some(Media::GitHub(format!("{}/{}", owner, repo))),
+        ("github.com", [_, _, "issues" | "pull", number])
+            if number.chars().all(|c| c.is_ascii_digit()) =>
+        {
+            Some(Media::GitHub(url.to_string()))
+        }
+        ("gist.github.com", [_, _]) => Some(Media::Gist(url.to_string())),
+        _ => None,
+    }
+}
+
+/// dev.to liquid tag for media
+fn liquid_tag(media: &Media) -> String {
+    match media {
+        Media::YouTube(id) => format!("{{% youtube {} %}}", id),
+        Media::Tweet(id) => format!("{{% twitter {} %}}", id),
+        Media::GitHub(target) => format!("{{% github {} %}}", target),
+        Media::Gist(url) => format!("{{% gist {} %}}", url),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_embed_media_urls_as_liquid_tags() {
+        let content = "Intro.\n\n\
+                       https://www.youtube.com/watch?v=dQw4w9WgXcQ&t=42\n\n\
+                       <https://youtu.be/dQw4w9WgXcQ>\n\n\
+                       [https://x.com/rustlang/status/1234567890](https://x.com/rustlang/status/1234567890)\n\n\
+                       https://github.com/rust-lang/rust\n\n\
+                       https://github.com/rust-lang/rust/issues/42\n\n\
+                       https://gist.github.com/someone/abc123\n\n\
+                       See https://youtu.be/dQw4w9WgXcQ for more.\n\n\
+                       - https://youtu.be/dQw4w9WgXcQ\n\n\
+                       https://github.com/rust-lang/rust/blob/master/README.md\n\n\
+                       ```\nhttps://youtu.be/dQw4w9WgXcQ\n```\n";
+
+        let (embedded, count) = embed_media_urls(content, EmbedStyle::LiquidTag);
+
+        assert_eq!(count, 6);
+        assert_eq!(
+            embedded,
+            "Intro.\n\n\
+             {% youtube dQw4w9WgXcQ %}\n\n\
+             {% youtube dQw4w9WgXcQ %}\n\n\
+             {% twitter 1234567890 %}\n\n\
+             {% github rust-lang/rust %}\n\n\
+             {% github https://github.com/rust-lang/rust/issues/42 %}\n\n\
+             {% gist https://gist.github.com/someone/abc123 %}\n\n\
+             See https://youtu.be/dQw4w9WgXcQ for more.\n\n\
+             - https://youtu.be/dQw4w9WgXcQ\n\n\
+             https://github.com/rust-lang/rust/blob/master/README.md\n\n\
+             ```\nhttps://youtu.be/dQw4w9WgXcQ\n```\n"
+        );
+    }
+
+    #[test]
+    fn test_embed_media_urls_as_bare_urls() {
+        let content = "<https://youtu.be/dQw4w9WgXcQ>\n\n[Watch](https://youtu.be/dQw4w9WgXcQ)\n";
+        let (embedded, count) = embed_media_urls(content, EmbedStyle::BareUrl);
+
+        assert_eq!(count, 1);
+        assert_eq!(
+            embedded,
+            "https://youtu.be/dQw4w9WgXcQ\n\n[Watch](https://youtu.be/dQw4w9WgXcQ)\n"
+        );
+    }
+}
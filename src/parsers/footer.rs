@@ -0,0 +1,121 @@
+use anyhow::{bail, Result};
+use once_cell::sync::Lazy;
+use regex::Regex;
+
+use crate::models::Article;
+
+/// Variables available to footer templates
+pub const FOOTER_VARIABLES: &[&str] = &[
+    "title",
+    "description",
+    "slug",
+    "tags",
+    "canonical_url",
+    "devto_url",
+    "medium_url",
+];
+
+/// `{{name}}` placeholders (spaces inside the braces allowed)
+static PLACEHOLDER: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"\{\{\s*([A-Za-z_]+)\s*\}\}").expect("Invalid placeholder pattern"));
+
+/// A footer template filled in for one article
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RenderedFooter {
+    /// Footer markdown
+    pub text: String,
+
+    /// Variables the template uses that the article has no value for
+    pub missing: Vec<String>,
+}
+
+/// Fill in a footer template with an article's values
+///
+/// Fails on variables that don't exist (see `FOOTER_VARIABLES`), so a typo in the config
+/// is not published. Variables without a value are left empty and listed in `missing`.
+pub fn render_footer(template: &str, article: &Article) -> Result<RenderedFooter> {
+    let mut text = String::with_capacity(template.len());
+    let mut missing = Vec::new();
+    let mut cursor = 0;
+
+    for captures in PLACEHOLDER.captures_iter(template) {
+        let whole = captures.get(0).expect("match has a whole group");
+        let name = &captures[1];
+        let value = match name {
+            "title" => Some(article.title.clone()),
+            "description" => article.description.clone(),
+            "slug" => article.slug.clone(),
+            "tags" => Some(article.tags.join(", ")).filter(|tags| !tags.is_empty()),
+            "canonical_url" => article.canonical_url.clone(),
+            "devto_url" => article.syndication.devto_url.clone(),
+            "medium_url" => article.syndication.medium_url.clone(),
+            _ => bail!(
+                "Unknown footer variable {{{{{}}}}} - available: {}",
+                name,
+                FOOTER_VARIABLES.join(", ")
+            ),
+        };
+
+        text.push_str(&template[cursor..whole.start()]);
+        match value {
+            Some(value) => text.push_str(&value),
+            None if !missing.iter().any(|m| m == name) => missing.push(name.to_string()),
+            None => {}
+        }
+        cursor = whole.end();
+    }
+    text.push_str(&template[cursor..]);
+
+    Ok(RenderedFooter {
+        text: text.trim().to_string(),
+        missing,
+    })
+}
+
+/// Append a footer after the article body, separated by a blank line
+///
+/// Content that already ends with the footer is returned unchanged.
+pub fn append_footer(content: &str, footer: &str) -> String {
+    let body = content.trim_end();
+    if footer.is_empty() || body.ends_with(footer) {
+        return content.to_string();
+    }
+
+    format!("{}\n\n{}\n", body, footer)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render_footer() {
+        let article = Article::new("Errors".to_string(), "Body".to_string())
+            .with_canonical_url("https://blog.example.com/errors/".to_string());
+
+        let footer = render_footer(
+            "---\n\n*Originally published at [my blog]({{ canonical_url }}).* {{title}}\n",
+            &article,
+        )
+        .unwrap();
+        assert_eq!(
+            footer.text,
+            "---\n\n*Originally published at [my blog](https://blog.example.com/errors/).* Errors"
+        );
+        assert!(footer.missing.is_empty());
+
+        let footer = render_footer("Also on [dev.to]({{devto_url}})", &article).unwrap();
+        assert_eq!(footer.missing, vec!["devto_url"]);
+
+        assert!(render_footer("{{canonical}}", &article).is_err());
+    }
+
+    #[test]
+    fn test_append_footer() {
+        let footer = "*Thanks for reading!*";
+
+        let content = append_footer("# Title\n\nBody.\n\n", footer);
+        assert_eq!(content, "# Title\n\nBody.\n\n*Thanks for reading!*\n");
+        assert_eq!(append_footer(&content, footer), content);
+    }
+}
@@ -0,0 +1,225 @@
+use pulldown_cmark::{Event, Options, Parser, Tag};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::ops::Range;
+
+/// How markdown footnotes (`[^1]`) are rendered for a platform
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum FootnoteStyle {
+    /// Leave footnotes untouched (platform renders them natively)
+    #[default]
+    Keep,
+
+    /// Replace references with `[n]` and collect definitions in a numbered "Notes" section
+    Endnotes,
+
+    /// Replace references with the footnote text in parentheses
+    Inline,
+}
+
+impl std::str::FromStr for FootnoteStyle {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "keep" => Ok(FootnoteStyle::Keep),
+            "endnotes" => Ok(FootnoteStyle::Endnotes),
+            "inline" => Ok(FootnoteStyle::Inline),
+            _ => Err(format!(
+                "Unknown footnote style: '{}'. Valid options: keep, endnotes, inline",
+                s
+            )),
+        }
+    }
+}
+
+/// Convert markdown footnotes for platforms that don't render them
+///
+/// Footnotes are located with the markdown parser, so `[^1]` inside code is left alone.
+pub fn convert_footnotes(content: &str, style: FootnoteStyle) -> String {
+    if style == FootnoteStyle::Keep {
+        return content.to_string();
+    }
+
+    let mut options = Options::empty();
+    options.insert(Options::ENABLE_FOOTNOTES);
+    options.insert(Options::ENABLE_TABLES);
+    options.insert(Options::ENABLE_STRIKETHROUGH);
+    options.insert(Options::ENABLE_TASKLISTS);
+
+    let mut references: Vec<(Range<usize>, String)> = Vec::new();
+    let mut definitions: Vec<(Range<usize>, String)> = Vec::new();
+
+    for (event, range) in Parser::new_ext(content, options).into_offset_iter() {
+        match event {
+            Event::FootnoteReference(label) => references.push((range, label.to_string())),
+            Event::Start(Tag::FootnoteDefinition(label)) => {
+                definitions.push((range, label.to_string()))
+            }
+            _ => {}
+        }
+    }
+
+    if references.is_empty() && definitions.is_empty() {
+        return content.to_string();
+    }
+
+    let texts: HashMap<&str, String> = definitions
+        .iter()
+        .map(|(range, label)| (label.as_str(), definition_text(&content[range.clone()])))
+        .collect();
+
+    // Number footnotes in order of first reference, then any unreferenced definitions
+    let mut order: Vec<&str> = Vec::new();
+    for (_, label) in &references {
+        if texts.contains_key(label.as_str()) && !order.contains(&label.as_str()) {
+            order.push(label);
+        }
+    }
+    let referenced = order.len();
+    for (_, label) in &definitions {
+        if !order.contains(&label.as_str()) {
+            order.push(label);
+        }
+    }
+
+    let mut edits: Vec<(Range<usize>, String)> = Vec::new();
+    for (range, label) in &references {
+        let Some(text) = texts.get(label.as_str()) else {
+            continue;
+        };
+        let replacement = match style {
+            FootnoteStyle::Inline => format!(" ({})", text),
+            _ => {
+                let number = order.iter().position(|l| l == label).unwrap_or(0) + 1;
+                format!("[{}]", number)
+            }
+        };
+        edits.push((range.clone(), replacement));
+    }
+    for (range, _) in &definitions {
+        edits.push((range.clone(), String::new()));
+    }
+    edits.sort_by_key(|(range, _)| range.start);
+
+    let mut output = String::with_capacity(content.len());
+    let mut cursor = 0;
+    for (range, replacement) in edits {
+        // Skip references nested inside a definition that was already removed
+        if range.start < cursor {
+            continue;
+        }
+        output.push_str(&content[cursor..range.start]);
+        output.push_str(&replacement);
+        cursor = range.end;
+    }
+    output.push_str(&content[cursor..]);
+
+    // Inline style only needs a notes section for definitions nobody referenced
+    let notes: Vec<(usize, &str)> = match style {
+        FootnoteStyle::Inline => order
+            .iter()
+            .enumerate()
+            .skip(referenced)
+            .map(|(i, label)| (i + 1 - referenced, *label))
+            .collect(),
+        _ => order
+            .iter()
+            .enumerate()
+            .map(|(i, label)| (i + 1, *label))
+            .collect(),
+    };
+
+    if notes.is_empty() {
+        return output;
+    }
+
+    let mut result = output.trim_end().to_string();
+    result.push_str("\n\n---\n\n**Notes**\n\n");
+    for (number, label) in notes {
+        result.push_str(&format!("{}. {}\n", number, texts[label]));
+    }
+    result
+}
+
+/// Extract the text of a footnote definition (`[^label]: text`) as a single line
+fn definition_text(raw: &str) -> String {
+    let body = raw.split_once("]:").map(|(_, body)| body).unwrap_or(raw);
+
+    body.lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty())
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ARTICLE: &str = "Rust is fast[^speed] and safe[^safety].\n\n\
+        More text[^speed].\n\n\
+        [^speed]: No garbage collector.\n\
+        [^safety]: Ownership and borrowing.\n";
+
+    #[test]
+    fn test_keep_leaves_content_untouched() {
+        assert_eq!(convert_footnotes(ARTICLE, FootnoteStyle::Keep), ARTICLE);
+    }
+
+    #[test]
+    fn test_endnotes() {
+        let result = convert_footnotes(ARTICLE, FootnoteStyle::Endnotes);
+
+        assert!(result.starts_with("Rust is fast[1] and safe[2].\n\nMore text[1]."));
+        assert!(result.ends_with(
+            "---\n\n**Notes**\n\n1. No garbage collector.\n2. Ownership and borrowing.\n"
+        ));
+        assert!(!result.contains("[^"));
+    }
+
+    #[test]
+    fn test_inline() {
+        let result = convert_footnotes(ARTICLE, FootnoteStyle::Inline);
+
+        assert!(result.starts_with(
+            "Rust is fast (No garbage collector.) and safe (Ownership and borrowing.)."
+        ));
+        assert!(!result.contains("**Notes**"));
+        assert!(!result.contains("[^"));
+    }
+
+    #[test]
+    fn test_footnote_syntax_in_code_is_ignored() {
+        let content = "Use `[^1]` syntax.\n\n```markdown\nText[^1]\n\n[^1]: Note\n```\n";
+        assert_eq!(convert_footnotes(content, FootnoteStyle::Endnotes), content);
+    }
+
+    #[test]
+    fn test_undefined_reference_is_left_alone() {
+        let content = "Dangling[^missing] reference.";
+        assert_eq!(convert_footnotes(content, FootnoteStyle::Endnotes), content);
+    }
+
+    #[test]
+    fn test_multiline_definition() {
+        let content = "Text[^long].\n\n[^long]: First line\n    continues here.\n";
+        let result = convert_footnotes(content, FootnoteStyle::Endnotes);
+
+        assert!(result.contains("1. First line continues here.\n"));
+    }
+
+    #[test]
+    fn test_footnote_style_from_str() {
+        assert_eq!(
+            "endnotes".parse::<FootnoteStyle>().unwrap(),
+            FootnoteStyle::Endnotes
+        );
+        assert_eq!(
+            "INLINE".parse::<FootnoteStyle>().unwrap(),
+            FootnoteStyle::Inline
+        );
+        assert!("bogus".parse::<FootnoteStyle>().is_err());
+    }
+}
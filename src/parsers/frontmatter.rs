@@ -0,0 +1,189 @@
+use anyhow::{Context, Result};
+use std::fs;
+use std::path::Path;
+
+use crate::parsers::scaffold::yaml_scalar;
+
+/// Frontmatter delimiter line
+const DELIMITER: &str = "---";
+
+/// Set a top-level frontmatter field, replacing an existing value or appending a new line
+///
+/// The rest of the file (other fields, comments, formatting, body) is left untouched.
+/// Values are written as YAML scalars, quoted when needed.
+pub fn set_frontmatter_field(content: &str, key: &str, value: &str) -> Result<String> {
+    set_frontmatter_yaml(content, key, &yaml_scalar(value))
+}
+
+/// Set a top-level frontmatter field to a flow list (`tags: [rust, cli]`)
+pub fn set_frontmatter_list(content: &str, key: &str, values: &[String]) -> Result<String> {
+    let items: Vec<String> = values.iter().map(|value| yaml_scalar(value)).collect();
+    set_frontmatter_yaml(content, key, &format!("[{}]", items.join(", ")))
+}
+
+/// Set a top-level frontmatter field to a YAML value written as given
+///
+/// A replaced field's continuation lines (block list items, folded or literal scalar
+/// lines) are removed along with it.
+fn set_frontmatter_yaml(content: &str, key: &str, yaml: &str) -> Result<String> {
+    let lines: Vec<&str> = content.split_inclusive('\n').collect();
+
+    if lines.first().map(|line| line.trim_end()) != Some(DELIMITER) {
+        anyhow::bail!("File has no frontmatter block");
+    }
+
+    let end = lines
+        .iter()
+        .skip(1)
+        .position(|line| line.trim_end() == DELIMITER)
+        .map(|i| i + 1)
+        .context("Frontmatter block is not closed with '---'")?;
+
+    let newline = if lines[0].ends_with("\r\n") {
+        "\r\n"
+    } else {
+        "\n"
+    };
+    let field = format!("{}: {}{}", key, yaml, newline);
+    let prefix = format!("{}:", key);
+
+    let mut output = String::with_capacity(content.len() + field.len());
+    let mut replaced = false;
+    let mut in_field = false;
+
+    for (i, line) in lines.iter().enumerate() {
+        if i > 0 && i < end && line.starts_with(&prefix) {
+            if !replaced {
+                output.push_str(&field);
+                replaced = true;
+            }
+            in_field = true;
+            continue;
+        }
+        if in_field && i < end && is_continuation(line) {
+            continue;
+        }
+        in_field = false;
+        if i == end && !replaced {
+            output.push_str(&field);
+        }
+        output.push_str(line);
+    }
+
+    Ok(output)
+}
+
+/// True for a line that continues the value of the field above it: indented, or a
+/// block list item at the start of the line
+fn is_continuation(line: &str) -> bool {
+    line.starts_with([' ', '\t']) && !line.trim().is_empty()
+        || line.starts_with("- ")
+        || line.trim_end() == "-"
+}
+
+/// Set several frontmatter fields in a markdown file
+pub fn update_frontmatter_file(path: &Path, fields: &[(&str, &str)]) -> Result<()> {
+    edit_frontmatter_file(path, |mut content| {
+        for (key, value) in fields {
+            content = set_frontmatter_field(&content, key, value)?;
+        }
+        Ok(content)
+    })
+}
+
+/// Rewrite a markdown file with an edit of its content (e.g. `set_frontmatter_list`)
+///
+/// The file is replaced atomically (temporary file + rename).
+pub fn edit_frontmatter_file(
+    path: &Path,
+    edit: impl FnOnce(String) -> Result<String>,
+) -> Result<()> {
+    let content = fs::read_to_string(path).context(format!("Failed to read {}", path.display()))?;
+    let content = edit(content).context(format!(
+        "Failed to update frontmatter of {}",
+        path.display()
+    ))?;
+
+    let tmp_path = path.with_extension("md.tmp");
+    fs::write(&tmp_path, content).context(format!("Failed to write {}", tmp_path.display()))?;
+    fs::rename(&tmp_path, path).context(format!("Failed to replace {}", path.display()))?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parsers::parse_markdown;
+
+    #[test]
+    fn test_set_frontmatter_field_appends() {
+        let content = "---\ntitle: \"Hello\"\ntags: [rust]\n---\n\nBody with\n---\nrule.\n";
+        let result = set_frontmatter_field(content, "slug", "hello").unwrap();
+
+        assert_eq!(
+            result,
+            "---\ntitle: \"Hello\"\ntags: [rust]\nslug: hello\n---\n\nBody with\n---\nrule.\n"
+        );
+        assert_eq!(
+            parse_markdown(&result).unwrap().slug.as_deref(),
+            Some("hello")
+        );
+    }
+
+    #[test]
+    fn test_set_frontmatter_field_replaces() {
+        let content = "---\r\nslug: old\r\ntitle: T\r\n---\r\nBody\r\n";
+        let result = set_frontmatter_field(content, "slug", "new-slug").unwrap();
+
+        assert_eq!(
+            result,
+            "---\r\nslug: new-slug\r\ntitle: T\r\n---\r\nBody\r\n"
+        );
+    }
+
+    #[test]
+    fn test_set_frontmatter_list() {
+        let content = "---\ntitle: T\ntags: [old]\n---\nBody\n";
+        let tags = vec!["rust".to_string(), "c++".to_string()];
+        let result = set_frontmatter_list(content, "tags", &tags).unwrap();
+
+        assert_eq!(result, "---\ntitle: T\ntags: [rust, \"c++\"]\n---\nBody\n");
+        assert_eq!(parse_markdown(&result).unwrap().tags, tags);
+    }
+
+    #[test]
+    fn test_set_frontmatter_list_replaces_block_list() {
+        let content = "---\ntitle: T\ntags:\n  - rust\n  - cli\nslug: t\n---\nBody\n";
+        let tags = vec!["a".to_string(), "b".to_string()];
+        let result = set_frontmatter_list(content, "tags", &tags).unwrap();
+
+        assert_eq!(result, "---\ntitle: T\ntags: [a, b]\nslug: t\n---\nBody\n");
+        assert_eq!(parse_markdown(&result).unwrap().tags, tags);
+
+        let unindented = "---\ntitle: T\ntags:\n- rust\n- cli\n---\nBody\n";
+        let result = set_frontmatter_list(unindented, "tags", &tags).unwrap();
+        assert_eq!(result, "---\ntitle: T\ntags: [a, b]\n---\nBody\n");
+    }
+
+    #[test]
+    fn test_set_frontmatter_field_replaces_folded_scalar() {
+        let content =
+            "---\ntitle: T\ndescription: >\n  A long\n  description.\ntags: [rust]\n---\nBody\n";
+        let result = set_frontmatter_field(content, "description", "Short.").unwrap();
+
+        assert_eq!(
+            result,
+            "---\ntitle: T\ndescription: \"Short.\"\ntags: [rust]\n---\nBody\n"
+        );
+        let article = parse_markdown(&result).unwrap();
+        assert_eq!(article.description.as_deref(), Some("Short."));
+        assert_eq!(article.tags, vec!["rust"]);
+    }
+
+    #[test]
+    fn test_set_frontmatter_field_requires_frontmatter() {
+        assert!(set_frontmatter_field("# Title\n", "slug", "x").is_err());
+        assert!(set_frontmatter_field("---\ntitle: T\n", "slug", "x").is_err());
+    }
+}
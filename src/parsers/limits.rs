@@ -0,0 +1,242 @@
+use pulldown_cmark::{Event, HeadingLevel, Options, Parser, Tag};
+
+use crate::models::Article;
+use crate::parsers::validator::Severity;
+
+/// Size and length limits a platform enforces on an article
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PlatformLimits {
+    /// Platform name for messages
+    pub platform: &'static str,
+
+    /// Longest accepted title (characters)
+    pub max_title_chars: usize,
+
+    /// Most tags accepted
+    pub max_tags: usize,
+
+    /// Longest accepted tag (characters)
+    pub max_tag_chars: usize,
+
+    /// True if longer tags are dropped by the platform rather than rejected
+    pub long_tags_ignored: bool,
+
+    /// Longest accepted description (characters); `None` if the platform has no description
+    pub max_description_chars: Option<usize>,
+
+    /// Largest accepted body (bytes)
+    pub max_body_bytes: usize,
+}
+
+/// dev.to (Forem) article validations
+pub const DEVTO_LIMITS: PlatformLimits = PlatformLimits {
+    platform: "dev.to",
+    max_title_chars: 128,
+    max_tags: 4,
+    max_tag_chars: 30,
+    long_tags_ignored: false,
+    max_description_chars: Some(170),
+    max_body_bytes: 800 * 1024,
+};
+
+/// Medium API limits; the body limit is approximate and applies to the HTML as well
+pub const MEDIUM_LIMITS: PlatformLimits = PlatformLimits {
+    platform: "Medium",
+    max_title_chars: 100,
+    max_tags: 5,
+    max_tag_chars: 25,
+    long_tags_ignored: true,
+    max_description_chars: None,
+    max_body_bytes: 1024 * 1024,
+};
+
+/// Check the title, tags, description and body of a sanitized article against a platform's limits
+///
+/// Each issue names the element and by how much it is over.
+pub fn check_limits(article: &Article, limits: &PlatformLimits) -> Vec<(Severity, String)> {
+    let platform = limits.platform;
+    let mut issues = Vec::new();
+
+    let title_chars = article.title.chars().count();
+    if title_chars > limits.max_title_chars {
+        issues.push((
+            Severity::Error,
+            format!(
+                "Title is {} characters; {} allows at most {} - shorten it by {}",
+                title_chars,
+                platform,
+                limits.max_title_chars,
+                title_chars - limits.max_title_chars
+            ),
+        ));
+    }
+
+    if article.tags.len() > limits.max_tags {
+        issues.push((
+            Severity::Error,
+            format!(
+                "{} allows maximum {} tags, found {} (remove: {})",
+                platform,
+                limits.max_tags,
+                article.tags.len(),
+                article.tags[limits.max_tags..].join(", ")
+            ),
+        ));
+    }
+
+    for tag in &article.tags {
+        let tag_chars = tag.chars().count();
+        if tag_chars > limits.max_tag_chars {
+            let (severity, consequence) = if limits.long_tags_ignored {
+                (Severity::Warning, "it will be ignored")
+            } else {
+                (Severity::Error, "shorten it")
+            };
+            issues.push((
+                severity,
+                format!(
+                    "Tag '{}' is {} characters; {} allows at most {} - {}",
+                    tag, tag_chars, platform, limits.max_tag_chars, consequence
+                ),
+            ));
+        }
+    }
+
+    if let (Some(max), Some(description)) =
+        (limits.max_description_chars, article.description.as_deref())
+    {
+        let description_chars = description.chars().count();
+        if description_chars > max {
+            issues.push((
+                Severity::Error,
+                format!(
+                    "Description is {} characters; {} allows at most {} - shorten it by {}",
+                    description_chars,
+                    platform,
+                    max,
+                    description_chars - max
+                ),
+            ));
+        }
+    }
+
+    if let Some(message) = body_size_issue(&article.content, "Body", limits) {
+        issues.push((Severity::Error, message));
+    }
+
+    issues
+}
+
+/// Error message for a body over the platform's size limit, with where to split it
+///
+/// `what` names the checked text (`Body`, `Converted HTML`).
+pub fn body_size_issue(content: &str, what: &str, limits: &PlatformLimits) -> Option<String> {
+    let size = content.len();
+    if size <= limits.max_body_bytes {
+        return None;
+    }
+
+    let mut message = format!(
+        "{} is {} KB; {} accepts at most {} KB ({} KB over)",
+        what,
+        size.div_ceil(1024),
+        limits.platform,
+        limits.max_body_bytes / 1024,
+        (size - limits.max_body_bytes).div_ceil(1024)
+    );
+
+    message.push_str(" - split the article into a series (set `series` in the frontmatter)");
+    if let Some(heading) = split_point(content, limits.max_body_bytes) {
+        message.push_str(&format!(", e.g. starting part 2 at \"{}\"", heading));
+    }
+
+    Some(message)
+}
+
+/// Last top-level section heading (H1/H2) that starts within the first `max_bytes`
+///
+/// Everything before it fits in one part. Headings inside code blocks are not considered.
+fn split_point(content: &str, max_bytes: usize) -> Option<String> {
+    let mut options = Options::empty();
+    options.insert(Options::ENABLE_FOOTNOTES);
+    options.insert(Options::ENABLE_TABLES);
+
+    let mut best = None;
+    let mut current: Option<String> = None;
+
+    for (event, range) in Parser::new_ext(content, options).into_offset_iter() {
+        match event {
+            Event::Start(Tag::Heading { level, .. })
+                if level <= HeadingLevel::H2 && range.start > 0 && range.start <= max_bytes =>
+            {
+                current = Some(String::new());
+            }
+            Event::Text(text) | Event::Code(text) => {
+                if let Some(heading) = current.as_mut() {
+                    heading.push_str(&text);
+                }
+            }
+            Event::End(pulldown_cmark::TagEnd::Heading(_)) => {
+                if let Some(heading) = current.take() {
+                    best = Some(heading.trim().to_string());
+                }
+            }
+            _ => {}
+        }
+    }
+
+    best.filter(|heading| !heading.is_empty())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_check_limits_reports_each_element() {
+        let article = Article::new("T".repeat(130), "Body".to_string())
+            .with_tags(vec![
+                "rust".to_string(),
+                "a".repeat(31),
+                "c".to_string(),
+                "d".to_string(),
+                "e".to_string(),
+            ])
+            .with_description("d".repeat(200));
+
+        let issues = check_limits(&article, &DEVTO_LIMITS);
+        let messages: Vec<&str> = issues.iter().map(|(_, m)| m.as_str()).collect();
+
+        assert_eq!(issues.len(), 4);
+        assert!(issues.iter().all(|(s, _)| *s == Severity::Error));
+        assert!(messages[0].contains("Title is 130 characters") && messages[0].contains("by 2"));
+        assert!(messages[1].contains("maximum 4 tags, found 5 (remove: e)"));
+        assert!(messages[2].contains("is 31 characters"));
+        assert!(messages[3].contains("Description is 200 characters"));
+
+        // Medium drops long tags and has no description
+        let issues = check_limits(&article, &MEDIUM_LIMITS);
+        assert_eq!(issues.len(), 2);
+        assert_eq!(issues[1].0, Severity::Warning);
+        assert!(issues[1].1.contains("it will be ignored"));
+    }
+
+    #[test]
+    fn test_body_size_issue_suggests_split() {
+        let limits = PlatformLimits {
+            max_body_bytes: 100,
+            ..MEDIUM_LIMITS
+        };
+        let section = "word ".repeat(10);
+        let content = format!(
+            "# Intro\n\n{}\n\n## Part Two\n\n{}\n\n```\n## not a heading\n```\n\n## Part Three\n\n{}",
+            section, section, section
+        );
+
+        let message = body_size_issue(&content, "Body", &limits).unwrap();
+        assert!(message.starts_with("Body is 1 KB; Medium accepts at most 0 KB"));
+        assert!(message.contains("starting part 2 at \"Part Two\""));
+
+        assert_eq!(body_size_issue("short", "Body", &MEDIUM_LIMITS), None);
+    }
+}
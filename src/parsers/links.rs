@@ -0,0 +1,155 @@
+use pulldown_cmark::{Event, LinkType, Options, Parser, Tag};
+use reqwest::Url;
+use std::ops::Range;
+
+/// Rewrite relative link and image URLs to absolute ones, resolved against `base`
+///
+/// Root-relative paths (`/posts/other/`) resolve against the base's origin, other
+/// relative paths (`../other/`, `diagram.png`) against the base itself, the way a
+/// browser would on the original page. Inline links, images and reference definitions
+/// are rewritten; anchors (`#section`), absolute URLs and code are left alone.
+/// Returns the new content and the number of rewritten URLs.
+pub fn resolve_relative_links(content: &str, base: &Url) -> (String, usize) {
+    let mut output = String::with_capacity(content.len());
+    let mut cursor = 0;
+    let mut rewritten = 0;
+
+    for (range, url) in relative_urls(content) {
+        let Ok(absolute) = base.join(&url) else {
+            continue;
+        };
+        output.push_str(&content[cursor..range.start]);
+        output.push_str(absolute.as_str());
+        cursor = range.end;
+        rewritten += 1;
+    }
+    output.push_str(&content[cursor..]);
+
+    (output, rewritten)
+}
+
+/// Count relative link and image URLs (dead links once the article leaves its site)
+pub fn count_relative_links(content: &str) -> usize {
+    relative_urls(content).len()
+}
+
+/// Relative URLs in links, images and reference definitions, with their source ranges
+fn relative_urls(content: &str) -> Vec<(Range<usize>, String)> {
+    link_destinations(content, true)
+        .into_iter()
+        .filter(|(_, url)| is_relative(url))
+        .collect()
+}
+
+/// URLs of inline links, autolinks and reference definitions (and inline images, if
+/// `images`), with their source ranges, in order; URLs in code are not links
+pub(crate) fn link_destinations(content: &str, images: bool) -> Vec<(Range<usize>, String)> {
+    let mut options = Options::empty();
+    options.insert(Options::ENABLE_FOOTNOTES);
+    options.insert(Options::ENABLE_TABLES);
+    options.insert(Options::ENABLE_STRIKETHROUGH);
+    options.insert(Options::ENABLE_TASKLISTS);
+
+    let mut urls = Vec::new();
+    let mut parser = Parser::new_ext(content, options).into_offset_iter();
+
+    for (event, range) in parser.by_ref() {
+        let dest_url = match event {
+            Event::Start(Tag::Link {
+                link_type: LinkType::Inline | LinkType::Autolink,
+                dest_url,
+                ..
+            }) => dest_url,
+            Event::Start(Tag::Image {
+                link_type: LinkType::Inline,
+                dest_url,
+                ..
+            }) if images => dest_url,
+            _ => continue,
+        };
+
+        // The destination follows the link text, so search from the end
+        if let Some(start) = content[range.clone()].rfind(dest_url.as_ref()) {
+            let start = range.start + start;
+            urls.push((start..start + dest_url.len(), dest_url.to_string()));
+        }
+    }
+
+    for (_, definition) in parser.reference_definitions().iter() {
+        let span = definition.span.clone();
+        let dest = definition.dest.as_ref();
+        // `[label]: dest "title"` - the destination follows the label
+        let Some(label_end) = content[span.clone()].find("]:") else {
+            continue;
+        };
+        let search_start = span.start + label_end + 2;
+        if let Some(start) = content[search_start..span.end].find(dest) {
+            let start = search_start + start;
+            urls.push((start..start + dest.len(), dest.to_string()));
+        }
+    }
+
+    urls.sort_by_key(|(range, _)| range.start);
+    urls.dedup_by_key(|(range, _)| range.start);
+    urls
+}
+
+/// True for URLs without a scheme, other than in-page anchors
+fn is_relative(url: &str) -> bool {
+    if url.is_empty() || url.starts_with('#') {
+        return false;
+    }
+
+    // A scheme is a letter followed by letters, digits, `+`, `-` or `.`, then `:`
+    let has_scheme = url.find(':').is_some_and(|end| {
+        url[..end].starts_with(|c: char| c.is_ascii_alphabetic())
+            && url[..end]
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || matches!(c, '+' | '-' | '.'))
+    });
+    !has_scheme
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn base() -> Url {
+        Url::parse("https://blog.example.com/posts/my-post/").unwrap()
+    }
+
+    #[test]
+    fn test_resolve_relative_links() {
+        let content = "See [other](/posts/other-post/), [sibling](../sibling/ \"Sibling\"), \
+                       [section](#intro), [mail](mailto:me@example.com) and \
+                       [abs](https://dev.to/x).\n\n![Diagram](diagram.png)\n";
+
+        let (resolved, count) = resolve_relative_links(content, &base());
+
+        assert_eq!(count, 3);
+        assert_eq!(
+            resolved,
+            "See [other](https://blog.example.com/posts/other-post/), \
+             [sibling](https://blog.example.com/posts/sibling/ \"Sibling\"), \
+             [section](#intro), [mail](mailto:me@example.com) and \
+             [abs](https://dev.to/x).\n\n\
+             ![Diagram](https://blog.example.com/posts/my-post/diagram.png)\n"
+        );
+    }
+
+    #[test]
+    fn test_resolve_relative_links_nested_and_references() {
+        let content = "[![Logo](/logo.png)](/about/) and [ref][r]. `[code](/x)`\n\n\
+                       [r]: /posts/ref/\n";
+
+        let (resolved, count) = resolve_relative_links(content, &base());
+
+        assert_eq!(count, 3);
+        assert_eq!(
+            resolved,
+            "[![Logo](https://blog.example.com/logo.png)](https://blog.example.com/about/) \
+             and [ref][r]. `[code](/x)`\n\n[r]: https://blog.example.com/posts/ref/\n"
+        );
+        assert_eq!(count_relative_links("[a](https://x.dev) [b](#c)"), 0);
+    }
+}
@@ -1,8 +1,20 @@
-use anyhow::Result;
+use anyhow::{Context, Result};
+use chrono::{DateTime, NaiveDate, NaiveDateTime, Utc};
 use gray_matter::Matter;
+use once_cell::sync::OnceCell;
 use serde::{Deserialize, Serialize};
 
-use crate::models::Article;
+use crate::cli::Platform;
+use crate::models::{Article, MediumLicense, Syndication};
+use crate::parsers::schema::check_frontmatter;
+
+/// `published` for articles whose frontmatter doesn't set it (set once at startup)
+static DEFAULT_PUBLISHED: OnceCell<bool> = OnceCell::new();
+
+/// Parse articles without a `published` field as drafts (`[defaults] draft = true`)
+pub fn init_drafts_by_default(draft: bool) {
+    let _ = DEFAULT_PUBLISHED.set(!draft);
+}
 
 /// Frontmatter metadata extracted from markdown
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
@@ -26,10 +38,113 @@ pub struct Frontmatter {
 
     /// Article description
     pub description: Option<String>,
+
+    /// URL slug
+    pub slug: Option<String>,
+
+    /// Series name
+    pub series: Option<String>,
+
+    /// Position within the series
+    pub series_part: Option<u32>,
+
+    /// Medium post license (`cc-40-by`, `public-domain`, ...)
+    pub medium_license: Option<MediumLicense>,
+
+    /// Whether Medium notifies followers when the post is published
+    pub notify_followers: Option<bool>,
+
+    /// Platforms to post to when `--to` is not given (`devto`, `medium`)
+    #[serde(default)]
+    pub platforms: Vec<String>,
+
+    /// Platforms never to post to unless named with `--to`
+    #[serde(default)]
+    pub exclude_platforms: Vec<String>,
+
+    /// dev.to article URL (written back after posting)
+    pub devto_url: Option<String>,
+
+    /// dev.to article ID (written back after posting)
+    #[serde(default, deserialize_with = "string_or_number")]
+    pub devto_id: Option<String>,
+
+    /// Medium post URL (written back after posting)
+    pub medium_url: Option<String>,
+
+    /// Medium post ID (written back after posting)
+    #[serde(default, deserialize_with = "string_or_number")]
+    pub medium_id: Option<String>,
+
+    /// Original publication date (takes precedence over `date`)
+    pub published_at: Option<String>,
+
+    /// Publication date, as written by `new`, `import` and static site generators
+    pub date: Option<String>,
+
+    /// Language code of a translation, as written by `post --translate`
+    pub lang: Option<String>,
 }
 
 fn default_published() -> bool {
-    true
+    DEFAULT_PUBLISHED.get().copied().unwrap_or(true)
+}
+
+/// Validate platform names from a frontmatter field, normalized to platform IDs
+fn platform_ids(names: &[String], field: &str) -> Result<Vec<String>> {
+    names
+        .iter()
+        .map(|name| {
+            name.parse::<Platform>()
+                .map(|platform| platform.id().to_string())
+                .map_err(|e| anyhow::anyhow!("Invalid `{}` in frontmatter: {}", field, e))
+        })
+        .collect()
+}
+
+/// Accept IDs written either as strings or as plain YAML numbers (`devto_id: 123`)
+fn string_or_number<'de, D>(deserializer: D) -> Result<Option<String>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum Id {
+        String(String),
+        Number(u64),
+    }
+
+    Ok(Option::<Id>::deserialize(deserializer)?.map(|id| match id {
+        Id::String(id) => id,
+        Id::Number(id) => id.to_string(),
+    }))
+}
+
+/// Parse a frontmatter date: RFC 3339, Jekyll's `YYYY-MM-DD HH:MM:SS +ZZZZ`, or
+/// `YYYY-MM-DD[ HH:MM[:SS]]` taken as UTC
+fn parse_article_date(value: &str) -> Result<DateTime<Utc>> {
+    let value = value.trim();
+
+    if let Ok(time) = DateTime::parse_from_rfc3339(value)
+        .or_else(|_| DateTime::parse_from_str(value, "%Y-%m-%d %H:%M:%S %z"))
+    {
+        return Ok(time.with_timezone(&Utc));
+    }
+
+    let naive = ["%Y-%m-%d %H:%M", "%Y-%m-%dT%H:%M", "%Y-%m-%d %H:%M:%S"]
+        .iter()
+        .find_map(|format| NaiveDateTime::parse_from_str(value, format).ok())
+        .or_else(|| {
+            NaiveDate::parse_from_str(value, "%Y-%m-%d")
+                .ok()
+                .and_then(|date| date.and_hms_opt(0, 0, 0))
+        })
+        .context(format!(
+            "'{}' is not a date (expected YYYY-MM-DD, YYYY-MM-DD HH:MM or RFC 3339)",
+            value
+        ))?;
+
+    Ok(naive.and_utc())
 }
 
 /// Extract the first H1 heading from markdown content
@@ -47,11 +162,19 @@ fn extract_first_h1(content: &str) -> Option<String> {
 }
 
 /// Parse markdown file with frontmatter
+///
+/// If the frontmatter can't be parsed, the error lists each problem the frontmatter
+/// schema finds, with its line and how to fix it.
 pub fn parse_markdown(content: &str) -> Result<Article> {
     let matter = Matter::<gray_matter::engine::YAML>::new();
-    let result = matter
-        .parse_with_struct::<Frontmatter>(content)
-        .ok_or_else(|| anyhow::anyhow!("Failed to parse frontmatter"))?;
+    let Some(result) = matter.parse_with_struct::<Frontmatter>(content) else {
+        let issues = check_frontmatter(content);
+        if issues.is_empty() {
+            anyhow::bail!("Failed to parse frontmatter");
+        }
+        let issues: Vec<String> = issues.iter().map(|issue| issue.to_string()).collect();
+        anyhow::bail!("Invalid frontmatter:\n  {}", issues.join("\n  "));
+    };
 
     let frontmatter = result.data;
     let body = result.content;
@@ -107,6 +230,50 @@ pub fn parse_markdown(content: &str) -> Result<Article> {
         article = article.with_description(description);
     }
 
+    if let Some(slug) = frontmatter.slug {
+        article = article.with_slug(slug);
+    }
+
+    if let Some(series) = frontmatter.series {
+        article = article.with_series(series, frontmatter.series_part);
+    }
+
+    if let Some(license) = frontmatter.medium_license {
+        article = article.with_medium_license(license);
+    }
+
+    if let Some(notify) = frontmatter.notify_followers {
+        article = article.with_notify_followers(notify);
+    }
+
+    if let Some(lang) = frontmatter.lang {
+        article = article.with_lang(lang);
+    }
+
+    article = article
+        .with_platforms(platform_ids(&frontmatter.platforms, "platforms")?)
+        .with_excluded_platforms(platform_ids(
+            &frontmatter.exclude_platforms,
+            "exclude_platforms",
+        )?);
+
+    let date = match frontmatter.published_at {
+        Some(value) => Some(("published_at", value)),
+        None => frontmatter.date.map(|value| ("date", value)),
+    };
+    if let Some((field, value)) = date {
+        let published_at = parse_article_date(&value)
+            .map_err(|e| anyhow::anyhow!("Invalid `{}` in frontmatter: {}", field, e))?;
+        article = article.with_published_at(published_at);
+    }
+
+    article = article.with_syndication(Syndication {
+        devto_url: frontmatter.devto_url,
+        devto_id: frontmatter.devto_id,
+        medium_url: frontmatter.medium_url,
+        medium_id: frontmatter.medium_id,
+    });
+
     Ok(article)
 }
 
@@ -232,6 +399,61 @@ Content here."#;
         assert!(!article.published);
     }
 
+    #[test]
+    fn test_parse_syndication_fields() {
+        let content = r#"---
+title: Posted
+devto_url: https://dev.to/me/posted-1a2b
+devto_id: 123456
+medium_id: "abc123"
+---
+
+Content here."#;
+
+        let article = parse_markdown(content).unwrap();
+        assert_eq!(
+            article.syndication.devto_url.as_deref(),
+            Some("https://dev.to/me/posted-1a2b")
+        );
+        assert_eq!(article.syndication.devto_id.as_deref(), Some("123456"));
+        assert_eq!(article.syndication.medium_id.as_deref(), Some("abc123"));
+        assert_eq!(article.syndication.medium_url, None);
+    }
+
+    #[test]
+    fn test_parse_platforms_fields() {
+        let content = "---\ntitle: Targets\nplatforms: [dev.to, Medium]\nexclude_platforms: [medium]\n---\n\nBody";
+        let article = parse_markdown(content).unwrap();
+
+        assert_eq!(article.platforms, vec!["devto", "medium"]);
+        assert_eq!(article.exclude_platforms, vec!["medium"]);
+
+        let invalid = "---\ntitle: Targets\nplatforms: [hashnode]\n---\n\nBody";
+        let error = parse_markdown(invalid).unwrap_err().to_string();
+        assert!(error.contains("Invalid `platforms` in frontmatter: Unknown platform: 'hashnode'"));
+    }
+
+    #[test]
+    fn test_parse_publication_date() {
+        let parse = |fields: &str| parse_markdown(&format!("---\ntitle: T\n{}---\n\nBody", fields));
+
+        let article = parse("date: 2019-05-04\n").unwrap();
+        assert_eq!(
+            article.published_at.unwrap().to_rfc3339(),
+            "2019-05-04T00:00:00+00:00"
+        );
+
+        let article = parse("date: 2019-05-04\npublished_at: 2019-05-06 08:30:00 +0200\n").unwrap();
+        assert_eq!(
+            article.published_at.unwrap().to_rfc3339(),
+            "2019-05-06T06:30:00+00:00"
+        );
+        assert_eq!(parse("").unwrap().published_at, None);
+
+        let error = parse("date: May 4th\n").unwrap_err().to_string();
+        assert!(error.contains("Invalid `date` in frontmatter: 'May 4th' is not a date"));
+    }
+
     #[test]
     fn test_parse_title_with_colon_unquoted_fails() {
         // This test documents that unquoted values with colons fail to parse
@@ -247,6 +469,10 @@ Content here."#;
             result.is_err(),
             "YAML requires quotes around values containing colons"
         );
+
+        let error = result.unwrap_err().to_string();
+        assert!(error.contains("line 2, `title`"));
+        assert!(error.contains("quote values containing ':'"));
     }
 
     #[test]
@@ -0,0 +1,297 @@
+use anyhow::{Context, Result};
+use once_cell::sync::Lazy;
+use regex::Regex;
+use std::fs;
+use std::io::Read;
+use std::path::Path;
+
+use crate::models::{Article, Syndication};
+use crate::platforms::ImportedArticle;
+
+/// Title of an exported post
+static TITLE_PATTERN: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r#"(?s)<h1 class="p-name">(.*?)</h1>"#).unwrap());
+
+/// Subtitle of an exported post (used as the description)
+static SUBTITLE_PATTERN: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r#"(?s)<section data-field="subtitle" class="p-summary">(.*?)</section>"#).unwrap()
+});
+
+/// Body of an exported post, up to the metadata footer
+static BODY_PATTERN: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r#"(?s)<section data-field="body" class="e-content">(.*)</section>\s*<footer>"#)
+        .unwrap()
+});
+
+/// Publication timestamp in the export footer (missing for drafts)
+static PUBLISHED_PATTERN: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r#"<time class="dt-published" datetime="([^"]+)""#).unwrap());
+
+/// Canonical Medium URL in the export footer
+static CANONICAL_PATTERN: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r#"<a href="([^"]+)" class="p-canonical""#).unwrap());
+
+/// Post ID at the end of a Medium URL (`.../my-post-1a2b3c4d5e6f` or `.../p/1a2b3c4d5e6f`)
+static POST_ID_PATTERN: Lazy<Regex> = Lazy::new(|| Regex::new(r"[-/]([0-9a-f]{8,16})/?$").unwrap());
+
+/// Any HTML tag (for plain-text title and subtitle)
+static HTML_TAG_PATTERN: Lazy<Regex> = Lazy::new(|| Regex::new(r"<[^>]+>").unwrap());
+
+/// Runs of more than one blank line
+static BLANK_LINES_PATTERN: Lazy<Regex> = Lazy::new(|| Regex::new(r"\n{3,}").unwrap());
+
+/// Convert post HTML to markdown, tidying headings, blank lines and trailing whitespace
+pub fn html_to_markdown(html: &str) -> String {
+    let markdown = html2md::parse_html(html);
+    let trimmed = markdown
+        .lines()
+        .map(|line| {
+            // `### Heading ###` -> `### Heading`
+            if line.starts_with('#') {
+                let line = line.trim_end();
+                let text = line.trim_end_matches('#');
+                if text.ends_with(' ') {
+                    text.trim_end()
+                } else {
+                    line
+                }
+            } else {
+                line.trim_end()
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    BLANK_LINES_PATTERN
+        .replace_all(trimmed.trim(), "\n\n")
+        .into_owned()
+}
+
+/// Drop a leading heading that repeats the title (Medium puts the title in the body)
+pub fn strip_title_heading(markdown: &str, title: &str) -> String {
+    let trimmed = markdown.trim_start();
+    let (first, rest) = trimmed.split_once('\n').unwrap_or((trimmed, ""));
+
+    let heading = first.trim_start_matches('#');
+    if heading.len() < first.len() && heading.trim() == title.trim() {
+        rest.trim_start().to_string()
+    } else {
+        markdown.to_string()
+    }
+}
+
+/// Medium post ID from a post URL
+pub fn medium_post_id(url: &str) -> Option<String> {
+    let path = url.split(['?', '#']).next().unwrap_or(url);
+
+    POST_ID_PATTERN
+        .captures(path)
+        .map(|captures| captures[1].to_string())
+}
+
+/// Parse one post from Medium's export archive (`posts/*.html`)
+///
+/// Exports carry no tags; drafts (`draft_*.html`) have no publication date or URL.
+pub fn parse_medium_export(html: &str) -> Result<ImportedArticle> {
+    let title = TITLE_PATTERN
+        .captures(html)
+        .map(|captures| decode_text(&captures[1]))
+        .filter(|title| !title.is_empty())
+        .context("Exported post has no title")?;
+
+    let body = BODY_PATTERN
+        .captures(html)
+        .map(|captures| captures[1].to_string())
+        .context("Exported post has no body")?;
+    let content = strip_title_heading(&html_to_markdown(&body), &title);
+
+    let published_at = PUBLISHED_PATTERN
+        .captures(html)
+        .map(|captures| captures[1].to_string());
+    let url = CANONICAL_PATTERN
+        .captures(html)
+        .map(|captures| captures[1].to_string());
+
+    let mut article = Article::new(title, content).with_published(published_at.is_some());
+    if let Some(subtitle) = SUBTITLE_PATTERN.captures(html) {
+        let description = decode_text(&subtitle[1]);
+        if !description.is_empty() {
+            article = article.with_description(description);
+        }
+    }
+    if let Some(url) = url {
+        article = article.with_syndication(Syndication {
+            medium_id: medium_post_id(&url),
+            medium_url: Some(url),
+            ..Default::default()
+        });
+    }
+
+    Ok(ImportedArticle {
+        article,
+        published_at,
+        engagement: None,
+    })
+}
+
+/// Read every post from a Medium export: the `.zip` archive, its extracted
+/// directory, or a single exported `.html` file
+pub fn read_medium_export(path: &Path) -> Result<Vec<ImportedArticle>> {
+    let mut posts = Vec::new();
+
+    if path.is_dir() {
+        let dir = if path.join("posts").is_dir() {
+            path.join("posts")
+        } else {
+            path.to_path_buf()
+        };
+
+        let mut files: Vec<_> = fs::read_dir(&dir)
+            .context(format!("Failed to read directory: {}", dir.display()))?
+            .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+            .filter(|file| file.extension().is_some_and(|ext| ext == "html"))
+            .collect();
+        files.sort();
+
+        for file in files {
+            let html =
+                fs::read_to_string(&file).context(format!("Failed to read {}", file.display()))?;
+            posts.push(
+                parse_medium_export(&html)
+                    .context(format!("Failed to parse {}", file.display()))?,
+            );
+        }
+    } else if path.extension().is_some_and(|ext| ext == "zip") {
+        let file = fs::File::open(path).context(format!("Failed to open {}", path.display()))?;
+        let mut archive = zip::ZipArchive::new(file)
+            .context(format!("Failed to read zip archive {}", path.display()))?;
+
+        let mut names: Vec<String> = archive
+            .file_names()
+            .filter(|name| name.starts_with("posts/") && name.ends_with(".html"))
+            .map(str::to_string)
+            .collect();
+        names.sort();
+
+        for name in names {
+            let mut html = String::new();
+            archive
+                .by_name(&name)
+                .context(format!("Failed to open {} in the archive", name))?
+                .read_to_string(&mut html)
+                .context(format!("Failed to read {} from the archive", name))?;
+            posts.push(parse_medium_export(&html).context(format!("Failed to parse {}", name))?);
+        }
+    } else {
+        let html =
+            fs::read_to_string(path).context(format!("Failed to read {}", path.display()))?;
+        posts.push(parse_medium_export(&html)?);
+    }
+
+    Ok(posts)
+}
+
+/// Plain text of an HTML fragment (tags removed, common entities decoded)
+fn decode_text(html: &str) -> String {
+    HTML_TAG_PATTERN
+        .replace_all(html, "")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&amp;", "&")
+        .trim()
+        .to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXPORTED_POST: &str = r#"<!DOCTYPE html><html><head><title>Rust &amp; You</title></head><body><article class="h-entry">
+<header><h1 class="p-name">Rust &amp; You</h1></header>
+<section data-field="subtitle" class="p-summary">Why ownership matters</section>
+<section data-field="body" class="e-content"><section name="a1" class="section"><div class="section-content"><div class="section-inner">
+<h3 name="b2" class="graf graf--h3 graf--title">Rust &amp; You</h3>
+<p name="c3" class="graf graf--p">Ownership is <strong>great</strong>.</p>
+<pre name="d4" class="graf graf--pre">let x = 1;</pre>
+</div></div></section></section>
+<footer><p>By <a href="https://medium.com/@jane" class="p-author h-card">Jane</a> on <a href="https://medium.com/p/8f1e2d3c4b5a"><time class="dt-published" datetime="2021-04-02T10:00:00.000Z">April 2, 2021</time></a>.</p><p><a href="https://medium.com/@jane/rust-you-8f1e2d3c4b5a" class="p-canonical">Canonical link</a></p></footer></article></body></html>"#;
+
+    #[test]
+    fn test_parse_medium_export() {
+        let imported = parse_medium_export(EXPORTED_POST).unwrap();
+        let article = &imported.article;
+
+        assert_eq!(article.title, "Rust & You");
+        assert_eq!(
+            article.description.as_deref(),
+            Some("Why ownership matters")
+        );
+        assert!(article.published);
+        assert_eq!(
+            imported.published_at.as_deref(),
+            Some("2021-04-02T10:00:00.000Z")
+        );
+        assert_eq!(
+            article.syndication.medium_url.as_deref(),
+            Some("https://medium.com/@jane/rust-you-8f1e2d3c4b5a")
+        );
+        assert_eq!(
+            article.syndication.medium_id.as_deref(),
+            Some("8f1e2d3c4b5a")
+        );
+        assert_eq!(
+            article.content,
+            "Ownership is **great**.\n\n```\nlet x = 1;\n```"
+        );
+        assert!(!article.content.contains("Rust & You"));
+    }
+
+    #[test]
+    fn test_parse_medium_export_draft() {
+        let draft = EXPORTED_POST
+            .replace(
+                r#"<time class="dt-published" datetime="2021-04-02T10:00:00.000Z">"#,
+                "<time>",
+            )
+            .replace(r#" class="p-canonical""#, "");
+
+        let imported = parse_medium_export(&draft).unwrap();
+
+        assert!(!imported.article.published);
+        assert_eq!(imported.published_at, None);
+        assert_eq!(imported.article.syndication, Syndication::default());
+    }
+
+    #[test]
+    fn test_read_medium_export_directory() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let posts = dir.path().join("posts");
+        fs::create_dir(&posts).unwrap();
+        fs::write(
+            posts.join("2021-04-02_Rust-You-8f1e2d3c4b5a.html"),
+            EXPORTED_POST,
+        )
+        .unwrap();
+        fs::write(posts.join("notes.txt"), "ignored").unwrap();
+
+        let imported = read_medium_export(dir.path()).unwrap();
+
+        assert_eq!(imported.len(), 1);
+        assert!(parse_medium_export("<html></html>").is_err());
+    }
+
+    #[test]
+    fn test_medium_post_id() {
+        assert_eq!(
+            medium_post_id("https://medium.com/@jane/my-post-1a2b3c4d5e6f?source=rss-1"),
+            Some("1a2b3c4d5e6f".to_string())
+        );
+        assert_eq!(
+            medium_post_id("https://medium.com/p/1a2b3c4d5e6f"),
+            Some("1a2b3c4d5e6f".to_string())
+        );
+        assert_eq!(medium_post_id("https://medium.com/@jane"), None);
+    }
+}
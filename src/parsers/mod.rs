@@ -1,10 +1,58 @@
 pub mod cleaner;
 pub mod converter;
 pub mod devto;
+pub mod embeds;
+pub mod footer;
+pub mod footnotes;
+pub mod frontmatter;
+pub mod limits;
+pub mod links;
 pub mod markdown;
+pub mod medium_import;
+pub mod preview;
+pub mod references;
 pub mod sanitizer;
+pub mod scaffold;
+pub mod schema;
+pub mod segments;
+pub mod series;
+pub mod slug;
+pub mod stats;
+pub mod summary;
+pub mod tables;
+pub mod tasklists;
+pub mod terminal;
+pub mod utm;
+pub mod validator;
 
-pub use cleaner::clean_ai_artifacts;
-pub use converter::{ensure_title_in_content, markdown_to_html};
-pub use devto::{fetch_from_devto_url, parse_devto_url};
-pub use markdown::parse_markdown;
+pub use cleaner::{clean_ai_artifacts, Cleaner, CleanerRule};
+pub use converter::{ensure_title_in_content, markdown_to_html, strip_title_from_content};
+pub use devto::{
+    fetch_devto_draft, fetch_from_devto_url, is_devto_input, parse_devto_draft_ref, parse_devto_url,
+};
+pub use embeds::{embed_media_urls, EmbedStyle};
+pub use footer::{append_footer, render_footer, RenderedFooter, FOOTER_VARIABLES};
+pub use footnotes::{convert_footnotes, FootnoteStyle};
+pub use frontmatter::{
+    edit_frontmatter_file, set_frontmatter_field, set_frontmatter_list, update_frontmatter_file,
+};
+pub use limits::{body_size_issue, check_limits, PlatformLimits, DEVTO_LIMITS, MEDIUM_LIMITS};
+pub use links::{count_relative_links, resolve_relative_links};
+pub use markdown::{init_drafts_by_default, parse_markdown};
+pub use medium_import::{html_to_markdown, read_medium_export};
+pub use preview::{preview_file_name, render_html_page};
+pub use references::inline_reference_links;
+pub use sanitizer::SanitizeOptions;
+pub use scaffold::{default_file_name, render_article, scaffold_article, ScaffoldVars};
+pub use schema::{check_frontmatter, FrontmatterIssue};
+pub use series::{
+    add_series_links, find_series_parts, load_series, SeriesLink, SeriesManifest, SeriesPart,
+};
+pub use slug::{canonical_url, slug_from_path, slugify, SlugSource};
+pub use stats::{compute_stats, ContentStats, HeadingInfo};
+pub use summary::{summarize, SocialSummary};
+pub use tables::{extract_tables, tables_to_images, TableStyle};
+pub use tasklists::{convert_task_lists, TaskListStyle, TaskMarkers};
+pub use terminal::render_terminal;
+pub use utm::{add_utm_params, utm_campaign, UtmOptions, UtmParams};
+pub use validator::{collect_markdown_files, validate_path, Severity, ValidationReport};
@@ -0,0 +1,137 @@
+use anyhow::Result;
+
+use crate::models::Article;
+use crate::parsers::converter::markdown_to_html;
+use crate::parsers::slug::slugify;
+
+/// Styles of the HTML preview page (article column similar to the platforms' reading view)
+const PREVIEW_STYLE: &str = "\
+body { margin: 0; background: #f6f6f6; color: #242424; \
+font: 18px/1.6 Georgia, Cambria, \"Times New Roman\", serif; }
+main { max-width: 720px; margin: 2rem auto; padding: 2rem 3rem; background: #fff; \
+border-radius: 8px; box-shadow: 0 1px 4px rgba(0, 0, 0, 0.08); }
+h1, h2, h3, h4, h5, h6 { font-family: -apple-system, \"Segoe UI\", Helvetica, Arial, sans-serif; \
+line-height: 1.25; }
+.meta { color: #6b6b6b; font: 14px/1.5 -apple-system, \"Segoe UI\", Helvetica, Arial, sans-serif; }
+.tag { display: inline-block; margin-right: 0.5rem; }
+.cover { width: 100%; border-radius: 4px; }
+img { max-width: 100%; }
+pre { background: #f2f2f2; padding: 1rem; overflow-x: auto; border-radius: 4px; }
+code { font: 15px/1.45 Menlo, Consolas, monospace; background: #f2f2f2; padding: 0.1em 0.3em; \
+border-radius: 3px; }
+pre code { padding: 0; }
+blockquote { margin-left: 0; padding-left: 1.2rem; border-left: 3px solid #242424; color: #555; }
+table { border-collapse: collapse; }
+th, td { border: 1px solid #ddd; padding: 0.4rem 0.8rem; }
+";
+
+/// Render an article as a standalone, styled HTML page for previewing in a browser
+///
+/// The body goes through `markdown_to_html`, the conversion used for Medium HTML
+/// requests. The title and metadata are shown above it.
+pub fn render_html_page(article: &Article) -> Result<String> {
+    let body = markdown_to_html(&article.content)?;
+
+    let mut meta = Vec::new();
+    if !article.tags.is_empty() {
+        let tags: Vec<String> = article
+            .tags
+            .iter()
+            .map(|tag| format!("<span class=\"tag\">#{}</span>", escape_html(tag)))
+            .collect();
+        meta.push(tags.join(""));
+    }
+    if let Some(ref canonical) = article.canonical_url {
+        meta.push(format!(
+            "Originally published at <a href=\"{0}\">{0}</a>",
+            escape_html(canonical)
+        ));
+    }
+    if !article.published {
+        meta.push("Draft".to_string());
+    }
+
+    let mut header = String::new();
+    if let Some(ref cover) = article.cover_image {
+        header.push_str(&format!(
+            "<img class=\"cover\" src=\"{}\" alt=\"Cover image\">\n",
+            escape_html(cover)
+        ));
+    }
+    header.push_str(&format!("<h1>{}</h1>\n", escape_html(&article.title)));
+    if let Some(ref description) = article.description {
+        header.push_str(&format!(
+            "<p class=\"meta\">{}</p>\n",
+            escape_html(description)
+        ));
+    }
+    for line in meta {
+        header.push_str(&format!("<p class=\"meta\">{}</p>\n", line));
+    }
+
+    Ok(format!(
+        "<!DOCTYPE html>\n<html lang=\"en\">\n<head>\n<meta charset=\"utf-8\">\n\
+         <meta name=\"viewport\" content=\"width=device-width, initial-scale=1\">\n\
+         <title>{} (preview)</title>\n<style>\n{}</style>\n</head>\n<body>\n<main>\n{}\n{}</main>\n\
+         </body>\n</html>\n",
+        escape_html(&article.title),
+        PREVIEW_STYLE,
+        header,
+        body
+    ))
+}
+
+/// File name for an article's HTML preview (`preview-<title-slug>.html`)
+pub fn preview_file_name(article: &Article) -> String {
+    let stem = article
+        .slug
+        .clone()
+        .unwrap_or_else(|| slugify(&article.title));
+
+    if stem.is_empty() {
+        "preview.html".to_string()
+    } else {
+        format!("preview-{}.html", stem)
+    }
+}
+
+/// Escape text for HTML element content and double-quoted attributes
+fn escape_html(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render_html_page() {
+        let article = Article::new(
+            "Rust <Tips> & Tricks".to_string(),
+            "Some **bold** text.\n".to_string(),
+        )
+        .with_tags(vec!["rust".to_string()])
+        .with_canonical_url("https://blog.example.com/tips/".to_string());
+
+        let page = render_html_page(&article).unwrap();
+
+        assert!(page.starts_with("<!DOCTYPE html>"));
+        assert!(page.contains("<h1>Rust &lt;Tips&gt; &amp; Tricks</h1>"));
+        assert!(page.contains("<strong>bold</strong>"));
+        assert!(page.contains("#rust"));
+        assert!(page.contains("href=\"https://blog.example.com/tips/\""));
+    }
+
+    #[test]
+    fn test_preview_file_name() {
+        let article = Article::new("Hello, World!".to_string(), String::new());
+        assert_eq!(preview_file_name(&article), "preview-hello-world.html");
+        assert_eq!(
+            preview_file_name(&article.with_slug("custom".to_string())),
+            "preview-custom.html"
+        );
+    }
+}
@@ -0,0 +1,185 @@
+use pulldown_cmark::{Event, LinkType, Options, Parser, Tag};
+use std::collections::BTreeSet;
+use std::ops::Range;
+
+/// Resolve reference-style links and images to inline ones
+///
+/// `[text][ref]`, `[text][]` and `[text]` (and their `![alt]` image forms) become
+/// `[text](url "title")`, and the definitions they used are removed. Links are found
+/// with the markdown parser, so code and footnotes (`[^1]`) are left alone. Unused
+/// definitions and references without a definition are kept as they are.
+pub fn inline_reference_links(content: &str) -> String {
+    let mut options = Options::empty();
+    options.insert(Options::ENABLE_FOOTNOTES);
+    options.insert(Options::ENABLE_TABLES);
+    options.insert(Options::ENABLE_STRIKETHROUGH);
+    options.insert(Options::ENABLE_TASKLISTS);
+
+    let mut links: Vec<(Range<usize>, LinkType, String, String, String)> = Vec::new();
+    let mut parser = Parser::new_ext(content, options).into_offset_iter();
+
+    for (event, range) in parser.by_ref() {
+        if let Event::Start(
+            Tag::Link {
+                link_type,
+                dest_url,
+                title,
+                id,
+            }
+            | Tag::Image {
+                link_type,
+                dest_url,
+                title,
+                id,
+            },
+        ) = event
+        {
+            if matches!(
+                link_type,
+                LinkType::Reference | LinkType::Collapsed | LinkType::Shortcut
+            ) {
+                links.push((
+                    range,
+                    link_type,
+                    dest_url.to_string(),
+                    title.to_string(),
+                    id.to_string(),
+                ));
+            }
+        }
+    }
+
+    if links.is_empty() {
+        return content.to_string();
+    }
+
+    let definitions = parser.reference_definitions();
+    let mut edits: Vec<(Range<usize>, String)> = Vec::new();
+    let mut used = BTreeSet::new();
+
+    for (range, link_type, url, title, id) in &links {
+        let source = &content[range.clone()];
+        // Only the `[ref]` / `[]` suffix is replaced, so links nested in link text
+        // (e.g. a reference image inside a reference link) never produce overlapping edits
+        let suffix = match link_type {
+            LinkType::Reference => source
+                .rfind('[')
+                .map(|start| range.start + start..range.end),
+            // The parser's range for `[text][]` may stop before the `[]`
+            LinkType::Collapsed if source.ends_with("[]") => Some(range.end - 2..range.end),
+            LinkType::Collapsed if content[range.end..].starts_with("[]") => {
+                Some(range.end..range.end + 2)
+            }
+            LinkType::Collapsed => None,
+            _ => Some(range.end..range.end),
+        };
+        let Some(suffix) = suffix else {
+            continue;
+        };
+
+        edits.push((suffix, inline_target(url, title)));
+        if let Some(definition) = definitions.get(id) {
+            used.insert((definition.span.start, definition.span.end));
+        }
+    }
+
+    // Remove the used definitions with their line break
+    for (start, end) in used {
+        let end = if content[end..].starts_with('\n') {
+            end + 1
+        } else {
+            end
+        };
+        edits.push((start..end, String::new()));
+    }
+    edits.sort_by_key(|(range, _)| range.start);
+
+    let mut output = String::with_capacity(content.len());
+    let mut cursor = 0;
+    for (range, replacement) in edits {
+        if range.start < cursor {
+            continue;
+        }
+        output.push_str(&content[cursor..range.start]);
+        output.push_str(&replacement);
+        cursor = range.end;
+    }
+    output.push_str(&content[cursor..]);
+
+    // Definitions usually sit at the end, after a blank line
+    if content.ends_with('\n') {
+        format!("{}\n", output.trim_end())
+    } else {
+        output.trim_end().to_string()
+    }
+}
+
+/// Inline link destination: `(url)` or `(url "title")`
+fn inline_target(url: &str, title: &str) -> String {
+    let url = if url.is_empty() || url.contains(|c: char| c.is_whitespace() || c == '(' || c == ')')
+    {
+        format!("<{}>", url)
+    } else {
+        url.to_string()
+    };
+
+    if title.is_empty() {
+        format!("({})", url)
+    } else {
+        format!("({} \"{}\")", url, title.replace('"', "\\\""))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_inline_reference_links() {
+        let content = "See [the docs][docs], [Rust][] and [crates].\n\n\
+                       [docs]: https://doc.rust-lang.org \"Rust docs\"\n\
+                       [rust]: https://www.rust-lang.org\n\
+                       [crates]: <https://crates.io/search?q=a b>\n";
+
+        assert_eq!(
+            inline_reference_links(content),
+            "See [the docs](https://doc.rust-lang.org \"Rust docs\"), \
+             [Rust](https://www.rust-lang.org) and [crates](<https://crates.io/search?q=a b>).\n"
+        );
+    }
+
+    #[test]
+    fn test_inline_reference_images() {
+        let content = "![Logo][logo]\n\n[![Badge][badge]][ci]\n\n\
+                       [logo]: https://example.com/logo.png\n\
+                       [badge]: https://example.com/badge.svg\n\
+                       [ci]: https://example.com/ci\n";
+
+        assert_eq!(
+            inline_reference_links(content),
+            "![Logo](https://example.com/logo.png)\n\n\
+             [![Badge](https://example.com/badge.svg)](https://example.com/ci)\n"
+        );
+    }
+
+    #[test]
+    fn test_inline_reference_links_with_footnotes() {
+        let content = "Claim[^1] from [source].\n\n\
+                       [^1]: Details in [the paper][paper].\n\n\
+                       [source]: https://example.com/source\n\
+                       [paper]: https://example.com/paper\n";
+
+        assert_eq!(
+            inline_reference_links(content),
+            "Claim[^1] from [source](https://example.com/source).\n\n\
+             [^1]: Details in [the paper](https://example.com/paper).\n"
+        );
+    }
+
+    #[test]
+    fn test_inline_reference_links_keeps_code_and_unknown_refs() {
+        let content = "Use `[x][y]` and [missing][nope].\n\n```\n[a]: not a definition\n```\n";
+
+        assert_eq!(inline_reference_links(content), content);
+    }
+}
@@ -3,10 +3,24 @@
 use anyhow::{bail, Result};
 use once_cell::sync::Lazy;
 use regex::Regex;
+use reqwest::Url;
 
 use crate::models::Article;
-
-/// Maximum allowed content size (10MB)
+use crate::parsers::converter::strip_title_from_content;
+use crate::parsers::embeds::{embed_media_urls, EmbedStyle};
+use crate::parsers::footer::{append_footer, render_footer};
+use crate::parsers::footnotes::{convert_footnotes, FootnoteStyle};
+use crate::parsers::limits::{check_limits, PlatformLimits, DEVTO_LIMITS, MEDIUM_LIMITS};
+use crate::parsers::links::{count_relative_links, resolve_relative_links};
+use crate::parsers::references::inline_reference_links;
+use crate::parsers::tables::TableStyle;
+use crate::parsers::tasklists::{convert_task_lists, TaskListStyle, TaskMarkers};
+use crate::parsers::utm::{add_utm_params, utm_campaign, UtmOptions, UtmParams};
+use crate::parsers::validator::Severity;
+
+/// Maximum content size accepted for processing at all (10MB)
+///
+/// Platform limits are lower and checked after the platform transforms.
 const MAX_CONTENT_SIZE: usize = 10 * 1024 * 1024;
 
 /// Lazy-compiled regex for liquid tag removal (prevents ReDoS)
@@ -24,8 +38,48 @@ pub enum Platform {
     Medium,
 }
 
+/// Per-platform content transform settings
+///
+/// `None` fields fall back to the platform default.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct SanitizeOptions {
+    /// Footnote rendering (default: keep on dev.to, endnotes on Medium)
+    pub footnotes: Option<FootnoteStyle>,
+
+    /// Table handling for platforms without table support (default: html)
+    pub tables: Option<TableStyle>,
+
+    /// Task list rendering (default: keep on dev.to, convert on Medium)
+    pub task_lists: Option<TaskListStyle>,
+
+    /// Markers used when converting task lists (default: ✓ / ✗)
+    pub task_markers: Option<TaskMarkers>,
+
+    /// Base URL for relative links in articles without a canonical URL
+    /// (`[canonical] base_url`, up to any `{slug}` placeholder)
+    pub link_base: Option<String>,
+
+    /// Footer template appended to the body (`{{canonical_url}}` etc., see `render_footer`)
+    pub footer: Option<String>,
+
+    /// UTM parameters for links to the author's own site (`[utm]`, off by default)
+    pub utm: Option<UtmOptions>,
+
+    /// Turn paragraphs holding only a YouTube, Twitter/X or GitHub URL into the
+    /// platform's embed (default: on)
+    pub embeds: Option<bool>,
+}
+
 /// Sanitize article for specific platform
-pub fn sanitize_for_platform(article: &mut Article, platform: Platform) -> Result<()> {
+///
+/// Returns warnings about changes that were made to the article. Fails if the
+/// sanitized article exceeds any of the platform's limits (see `PlatformLimits`),
+/// listing every element that is over.
+pub fn sanitize_for_platform(
+    article: &mut Article,
+    platform: Platform,
+    options: &SanitizeOptions,
+) -> Result<Vec<String>> {
     // Validate content size
     if article.content.len() > MAX_CONTENT_SIZE {
         bail!(
@@ -37,10 +91,9 @@ pub fn sanitize_for_platform(article: &mut Article, platform: Platform) -> Resul
     }
 
     match platform {
-        Platform::DevTo => sanitize_for_devto(article)?,
-        Platform::Medium => sanitize_for_medium(article)?,
+        Platform::DevTo => sanitize_for_devto(article, options),
+        Platform::Medium => sanitize_for_medium(article, options),
     }
-    Ok(())
 }
 
 /// Sanitize tags for dev.to (remove non-alphanumeric characters)
@@ -58,11 +111,8 @@ fn sanitize_devto_tags(tags: &[String]) -> Vec<String> {
 }
 
 /// Sanitize for dev.to platform
-fn sanitize_for_devto(article: &mut Article) -> Result<()> {
-    // Validate tag count (max 4 for dev.to)
-    if article.tags.len() > 4 {
-        bail!("dev.to allows maximum 4 tags, found {}", article.tags.len());
-    }
+fn sanitize_for_devto(article: &mut Article, options: &SanitizeOptions) -> Result<Vec<String>> {
+    let mut warnings = Vec::new();
 
     // Sanitize tags - dev.to only allows alphanumeric characters
     let original_tags = article.tags.clone();
@@ -70,36 +120,206 @@ fn sanitize_for_devto(article: &mut Article) -> Result<()> {
 
     // Warn if tags were modified
     if original_tags != article.tags {
-        eprintln!("⚠️  Warning: dev.to tags sanitized (only alphanumeric characters allowed):");
-        for (orig, sanitized) in original_tags.iter().zip(article.tags.iter()) {
-            if orig != sanitized {
-                eprintln!("   '{}' → '{}'", orig, sanitized);
-            }
-        }
+        let changes = original_tags
+            .iter()
+            .zip(article.tags.iter())
+            .filter(|(orig, sanitized)| orig != sanitized)
+            .map(|(orig, sanitized)| format!("'{}' → '{}'", orig, sanitized))
+            .collect::<Vec<_>>();
+        warnings.push(format!(
+            "dev.to tags sanitized (only alphanumeric characters allowed): {}",
+            changes.join(", ")
+        ));
     }
 
+    // dev.to shows the title above the body, so a leading `# Title` would appear twice
+    article.content = strip_title_from_content(&article.title, &article.content);
+    apply_embeds(article, options, EmbedStyle::LiquidTag);
+
+    // dev.to renders footnotes and task lists natively
+    let footnotes = options.footnotes.unwrap_or(FootnoteStyle::Keep);
+    article.content = convert_footnotes(&article.content, footnotes);
+    apply_task_lists(article, options, TaskListStyle::Keep);
+    warnings.extend(apply_link_base(article, options, "dev.to"));
+    warnings.extend(apply_footer(article, options, "dev.to")?);
+    warnings.extend(apply_utm(article, options));
+
     // Validate URLs in content
     validate_image_urls(&article.content)?;
+    enforce_limits(article, &DEVTO_LIMITS, &mut warnings)?;
 
-    Ok(())
+    Ok(warnings)
 }
 
 /// Sanitize for Medium platform
-fn sanitize_for_medium(article: &mut Article) -> Result<()> {
-    // Validate tag count (max 5 for Medium)
-    if article.tags.len() > 5 {
-        bail!("Medium allows maximum 5 tags, found {}", article.tags.len());
-    }
+fn sanitize_for_medium(article: &mut Article, options: &SanitizeOptions) -> Result<Vec<String>> {
+    let mut warnings = Vec::new();
 
     // Remove dev.to liquid tags ({% ... %})
     article.content = remove_liquid_tags(&article.content);
+    apply_embeds(article, options, EmbedStyle::BareUrl);
+
+    // Medium's markdown import drops reference-style links - resolve them first, so
+    // links inside footnote text are inline before footnotes are moved around
+    article.content = inline_reference_links(&article.content);
+    warnings.extend(apply_link_base(article, options, "Medium"));
+
+    // Medium has no footnote support - turn them into numbered end notes by default
+    let footnotes = options.footnotes.unwrap_or(FootnoteStyle::Endnotes);
+    article.content = convert_footnotes(&article.content, footnotes);
+
+    // Medium shows task list checkboxes as literal "[x]" text
+    apply_task_lists(article, options, TaskListStyle::Convert);
+
+    // After the end notes, so attribution is the last thing on the page
+    warnings.extend(apply_footer(article, options, "Medium")?);
+    warnings.extend(apply_utm(article, options));
 
     // Validate URLs in content
     validate_image_urls(&article.content)?;
+    enforce_limits(article, &MEDIUM_LIMITS, &mut warnings)?;
+
+    Ok(warnings)
+}
+
+/// Check the sanitized article against the platform's limits
+///
+/// Limit warnings are added to `warnings`; all limit errors are reported together.
+fn enforce_limits(
+    article: &Article,
+    limits: &PlatformLimits,
+    warnings: &mut Vec<String>,
+) -> Result<()> {
+    let mut errors = Vec::new();
+    for (severity, message) in check_limits(article, limits) {
+        match severity {
+            Severity::Error => errors.push(message),
+            Severity::Warning => warnings.push(message),
+        }
+    }
+
+    if !errors.is_empty() {
+        bail!(
+            "Article exceeds {} limits:\n  - {}",
+            limits.platform,
+            errors.join("\n  - ")
+        );
+    }
 
     Ok(())
 }
 
+/// Turn lone media URLs into embeds, unless turned off
+fn apply_embeds(article: &mut Article, options: &SanitizeOptions, style: EmbedStyle) {
+    if options.embeds.unwrap_or(true) {
+        article.content = embed_media_urls(&article.content, style).0;
+    }
+}
+
+/// Convert task lists to plain bullets if configured (or by platform default)
+fn apply_task_lists(article: &mut Article, options: &SanitizeOptions, default: TaskListStyle) {
+    if options.task_lists.unwrap_or(default) == TaskListStyle::Convert {
+        let markers = options.task_markers.clone().unwrap_or_default();
+        article.content = convert_task_lists(&article.content, &markers);
+    }
+}
+
+/// Resolve relative links against the canonical URL (or the configured link base)
+///
+/// Relative links point at the original blog and would be dead on the platform.
+/// Returns a warning when relative links are left because there is no base URL.
+fn apply_link_base(
+    article: &mut Article,
+    options: &SanitizeOptions,
+    platform: &str,
+) -> Option<String> {
+    let base = article
+        .canonical_url
+        .as_deref()
+        .or(options.link_base.as_deref())
+        .and_then(|base| Url::parse(base.split("{slug}").next().unwrap_or(base)).ok());
+
+    match base {
+        Some(base) => {
+            article.content = resolve_relative_links(&article.content, &base).0;
+            None
+        }
+        None => {
+            let count = count_relative_links(&article.content);
+            (count > 0).then(|| {
+                format!(
+                    "{} relative link(s) will not work on {} - set canonical_url or \
+                     [canonical] base_url to resolve them",
+                    count, platform
+                )
+            })
+        }
+    }
+}
+
+/// Append the configured footer, filled in with the article's values
+///
+/// A footer using a variable the article has no value for (e.g. `{{canonical_url}}`
+/// without a canonical URL) is left out with a warning rather than published half empty.
+fn apply_footer(
+    article: &mut Article,
+    options: &SanitizeOptions,
+    platform: &str,
+) -> Result<Option<String>> {
+    let Some(ref template) = options.footer else {
+        return Ok(None);
+    };
+
+    let footer = render_footer(template, article)?;
+    if !footer.missing.is_empty() {
+        return Ok(Some(format!(
+            "{} footer not added - the article has no {}",
+            platform,
+            footer.missing.join(", ")
+        )));
+    }
+    article.content = append_footer(&article.content, &footer.text);
+
+    Ok(None)
+}
+
+/// Tag links to the author's own site with UTM parameters, if configured
+///
+/// Runs after link resolution and the footer, so resolved relative links and footer
+/// links are tagged too. Without configured domains, the canonical URL's domain is used.
+fn apply_utm(article: &mut Article, options: &SanitizeOptions) -> Option<String> {
+    let utm = options.utm.as_ref()?;
+
+    let domains = if utm.domains.is_empty() {
+        let host = article
+            .canonical_url
+            .as_deref()
+            .and_then(|url| Url::parse(url).ok())
+            .and_then(|url| url.host_str().map(str::to_string));
+        match host {
+            Some(host) => vec![host],
+            None => {
+                return Some(
+                    "UTM parameters not added - set canonical_url or [utm] domains to say \
+                     which links are yours"
+                        .to_string(),
+                )
+            }
+        }
+    } else {
+        utm.domains.clone()
+    };
+
+    let params = UtmParams {
+        source: utm.source.clone(),
+        medium: utm.medium.clone(),
+        campaign: utm_campaign(article),
+    };
+    article.content = add_utm_params(&article.content, &domains, &params).0;
+
+    None
+}
+
 /// Remove Liquid tags from content
 fn remove_liquid_tags(content: &str) -> String {
     LIQUID_TAG_PATTERN.replace_all(content, "").to_string()
@@ -133,7 +353,7 @@ mod tests {
             "tag5".to_string(),
         ]);
 
-        let result = sanitize_for_devto(&mut article);
+        let result = sanitize_for_devto(&mut article, &SanitizeOptions::default());
         assert!(result.is_err());
         assert!(result.unwrap_err().to_string().contains("maximum 4 tags"));
     }
@@ -146,7 +366,7 @@ mod tests {
             "tag3".to_string(),
         ]);
 
-        let result = sanitize_for_devto(&mut article);
+        let result = sanitize_for_devto(&mut article, &SanitizeOptions::default());
         assert!(result.is_ok());
     }
 
@@ -161,7 +381,7 @@ mod tests {
             "tag6".to_string(),
         ]);
 
-        let result = sanitize_for_medium(&mut article);
+        let result = sanitize_for_medium(&mut article, &SanitizeOptions::default());
         assert!(result.is_err());
         assert!(result.unwrap_err().to_string().contains("maximum 5 tags"));
     }
@@ -196,10 +416,215 @@ mod tests {
         )
         .with_tags(vec!["tag1".to_string()]);
 
-        sanitize_for_medium(&mut article).unwrap();
+        sanitize_for_medium(&mut article, &SanitizeOptions::default()).unwrap();
         assert_eq!(article.content, "Content  here");
     }
 
+    #[test]
+    fn test_sanitize_for_devto_reports_tag_changes() {
+        let mut article = Article::new("Test".to_string(), "Content".to_string())
+            .with_tags(vec!["web-dev".to_string(), "rust".to_string()]);
+
+        let warnings = sanitize_for_devto(&mut article, &SanitizeOptions::default()).unwrap();
+
+        assert_eq!(warnings.len(), 1);
+        assert!(warnings[0].contains("'web-dev' → 'webdev'"));
+        assert!(!warnings[0].contains("rust"));
+    }
+
+    #[test]
+    fn test_sanitize_footnotes_per_platform() {
+        let content = "Claim[^1].\n\n[^1]: Source.\n";
+        let defaults = SanitizeOptions::default();
+
+        let mut medium = Article::new("Test".to_string(), content.to_string());
+        sanitize_for_medium(&mut medium, &defaults).unwrap();
+        assert!(medium.content.starts_with("Claim[1]."));
+        assert!(medium.content.contains("1. Source."));
+
+        let mut devto = Article::new("Test".to_string(), content.to_string());
+        sanitize_for_devto(&mut devto, &defaults).unwrap();
+        assert_eq!(devto.content, content);
+
+        let inline = SanitizeOptions {
+            footnotes: Some(FootnoteStyle::Inline),
+            ..Default::default()
+        };
+        let mut medium = Article::new("Test".to_string(), content.to_string());
+        sanitize_for_medium(&mut medium, &inline).unwrap();
+        assert!(medium.content.starts_with("Claim (Source.)."));
+    }
+
+    #[test]
+    fn test_sanitize_reference_links_for_medium() {
+        let content = "See [docs][d].[^1]\n\n[^1]: From [the paper][p].\n\n\
+                       [d]: https://example.com/docs\n[p]: https://example.com/paper\n";
+        let defaults = SanitizeOptions::default();
+
+        let mut medium = Article::new("Test".to_string(), content.to_string());
+        sanitize_for_medium(&mut medium, &defaults).unwrap();
+        assert!(medium
+            .content
+            .starts_with("See [docs](https://example.com/docs).[1]"));
+        assert!(medium
+            .content
+            .contains("1. From [the paper](https://example.com/paper)."));
+        assert!(!medium.content.contains("[p]:"));
+
+        let mut devto = Article::new("Test".to_string(), content.to_string());
+        sanitize_for_devto(&mut devto, &defaults).unwrap();
+        assert_eq!(devto.content, content);
+    }
+
+    #[test]
+    fn test_sanitize_strips_title_for_devto() {
+        let content = "# Test\n\nBody.\n";
+        let defaults = SanitizeOptions::default();
+
+        let mut devto = Article::new("Test".to_string(), content.to_string());
+        sanitize_for_devto(&mut devto, &defaults).unwrap();
+        assert_eq!(devto.content, "Body.\n");
+
+        let mut medium = Article::new("Test".to_string(), content.to_string());
+        sanitize_for_medium(&mut medium, &defaults).unwrap();
+        assert_eq!(medium.content, content);
+    }
+
+    #[test]
+    fn test_sanitize_relative_links() {
+        let content = "Read [part one](/posts/part-one/).\n";
+
+        let mut article = Article::new("Test".to_string(), content.to_string())
+            .with_canonical_url("https://blog.example.com/posts/part-two/".to_string());
+        let warnings = sanitize_for_medium(&mut article, &SanitizeOptions::default()).unwrap();
+        assert_eq!(
+            article.content,
+            "Read [part one](https://blog.example.com/posts/part-one/).\n"
+        );
+        assert!(warnings.is_empty());
+
+        let with_base = SanitizeOptions {
+            link_base: Some("https://blog.example.com/{slug}/".to_string()),
+            ..Default::default()
+        };
+        let mut article = Article::new("Test".to_string(), content.to_string());
+        sanitize_for_devto(&mut article, &with_base).unwrap();
+        assert!(article
+            .content
+            .contains("(https://blog.example.com/posts/part-one/)"));
+
+        let mut article = Article::new("Test".to_string(), content.to_string());
+        let warnings = sanitize_for_devto(&mut article, &SanitizeOptions::default()).unwrap();
+        assert_eq!(article.content, content);
+        assert!(warnings[0].contains("1 relative link(s)"));
+    }
+
+    #[test]
+    fn test_sanitize_task_lists_per_platform() {
+        let content = "- [x] Done\n- [ ] Todo\n";
+        let defaults = SanitizeOptions::default();
+
+        let mut medium = Article::new("Test".to_string(), content.to_string());
+        sanitize_for_medium(&mut medium, &defaults).unwrap();
+        assert_eq!(medium.content, "- ✓ Done\n- ✗ Todo\n");
+
+        let mut devto = Article::new("Test".to_string(), content.to_string());
+        sanitize_for_devto(&mut devto, &defaults).unwrap();
+        assert_eq!(devto.content, content);
+
+        let custom = SanitizeOptions {
+            task_lists: Some(TaskListStyle::Convert),
+            task_markers: Some(TaskMarkers {
+                checked: "☑".to_string(),
+                unchecked: "☐".to_string(),
+            }),
+            ..Default::default()
+        };
+        let mut devto = Article::new("Test".to_string(), content.to_string());
+        sanitize_for_devto(&mut devto, &custom).unwrap();
+        assert_eq!(devto.content, "- ☑ Done\n- ☐ Todo\n");
+    }
+
+    #[test]
+    fn test_sanitize_footer_per_platform() {
+        let content = "Claim.[^1]\n\n[^1]: Source.\n";
+        let options = SanitizeOptions {
+            footer: Some("*Originally published at {{canonical_url}}*".to_string()),
+            ..Default::default()
+        };
+
+        let mut medium = Article::new("Test".to_string(), content.to_string())
+            .with_canonical_url("https://blog.example.com/test/".to_string());
+        let warnings = sanitize_for_medium(&mut medium, &options).unwrap();
+        assert!(warnings.is_empty());
+        assert!(medium
+            .content
+            .ends_with("1. Source.\n\n*Originally published at https://blog.example.com/test/*\n"));
+
+        let mut devto = Article::new("Test".to_string(), content.to_string());
+        let warnings = sanitize_for_devto(&mut devto, &options).unwrap();
+        assert_eq!(devto.content, content);
+        assert_eq!(
+            warnings,
+            vec!["dev.to footer not added - the article has no canonical_url"]
+        );
+    }
+
+    #[test]
+    fn test_sanitize_embeds_per_platform() {
+        let content = "Watch this:\n\n<https://youtu.be/dQw4w9WgXcQ>\n";
+
+        let mut devto = Article::new("Test".to_string(), content.to_string());
+        sanitize_for_devto(&mut devto, &SanitizeOptions::default()).unwrap();
+        assert_eq!(devto.content, "Watch this:\n\n{% youtube dQw4w9WgXcQ %}\n");
+
+        let mut medium = Article::new("Test".to_string(), content.to_string());
+        sanitize_for_medium(&mut medium, &SanitizeOptions::default()).unwrap();
+        assert_eq!(
+            medium.content,
+            "Watch this:\n\nhttps://youtu.be/dQw4w9WgXcQ\n"
+        );
+
+        let off = SanitizeOptions {
+            embeds: Some(false),
+            ..Default::default()
+        };
+        let mut devto = Article::new("Test".to_string(), content.to_string());
+        sanitize_for_devto(&mut devto, &off).unwrap();
+        assert_eq!(devto.content, content);
+    }
+
+    #[test]
+    fn test_sanitize_utm_links() {
+        let content = "See [part one](/posts/part-one/) and [docs](https://docs.rs/).\n";
+        let options = SanitizeOptions {
+            utm: Some(UtmOptions {
+                domains: Vec::new(),
+                source: "devto".to_string(),
+                medium: "referral".to_string(),
+            }),
+            ..Default::default()
+        };
+
+        let mut devto = Article::new("Test".to_string(), content.to_string())
+            .with_canonical_url("https://blog.example.com/posts/part-two/".to_string());
+        sanitize_for_devto(&mut devto, &options).unwrap();
+        assert_eq!(
+            devto.content,
+            "See [part one](https://blog.example.com/posts/part-one/\
+             ?utm_source=devto&utm_medium=referral&utm_campaign=part-two) \
+             and [docs](https://docs.rs/).\n"
+        );
+
+        let mut devto = Article::new("Test".to_string(), content.to_string());
+        let warnings = sanitize_for_devto(&mut devto, &options).unwrap();
+        assert!(warnings.contains(
+            &"UTM parameters not added - set canonical_url or [utm] domains to say \
+              which links are yours"
+                .to_string()
+        ));
+    }
+
     #[test]
     fn test_sanitize_devto_tags() {
         let tags = vec![
@@ -0,0 +1,224 @@
+use crate::models::Article;
+use crate::parsers::slugify;
+
+/// Default article body used when no template is given
+const DEFAULT_BODY: &str = "Write your article here.\n";
+
+/// Values available to article templates
+///
+/// Templates may reference them as `{{title}}`, `{{date}}` and `{{tags}}`.
+#[derive(Debug, Clone)]
+pub struct ScaffoldVars<'a> {
+    /// Article title
+    pub title: &'a str,
+
+    /// Article tags
+    pub tags: &'a [String],
+
+    /// Creation date (YYYY-MM-DD)
+    pub date: &'a str,
+}
+
+/// Build a new markdown article with well-formed frontmatter
+///
+/// The frontmatter is always generated (title quoted, `published: false`) so that
+/// templates only need to provide the body.
+pub fn scaffold_article(vars: &ScaffoldVars, template: Option<&str>) -> String {
+    let tags = vars
+        .tags
+        .iter()
+        .map(|tag| yaml_scalar(tag))
+        .collect::<Vec<_>>()
+        .join(", ");
+
+    let body = template
+        .map(|template| render_template(template, vars))
+        .unwrap_or_else(|| DEFAULT_BODY.to_string());
+
+    format!(
+        "---\ntitle: {}\ntags: [{}]\npublished: false\ndate: {}\n---\n\n{}",
+        yaml_quote(vars.title),
+        tags,
+        vars.date,
+        body
+    )
+}
+
+/// Render an existing article (e.g. one imported from a platform) as markdown
+///
+/// Writes frontmatter for every field that is set, including the platform URLs and
+/// IDs in `syndication`. `date` is written as given (YYYY-MM-DD).
+pub fn render_article(article: &Article, date: Option<&str>) -> String {
+    let mut fields = vec![format!("title: {}", yaml_quote(&article.title))];
+
+    if let Some(description) = &article.description {
+        fields.push(format!("description: {}", yaml_quote(description)));
+    }
+
+    let tags = article
+        .tags
+        .iter()
+        .map(|tag| yaml_scalar(tag))
+        .collect::<Vec<_>>()
+        .join(", ");
+    fields.push(format!("tags: [{}]", tags));
+
+    let optional = [
+        ("canonical_url", &article.canonical_url),
+        ("cover_image", &article.cover_image),
+        ("series", &article.series),
+    ];
+    for (key, value) in optional {
+        if let Some(value) = value {
+            fields.push(format!("{}: {}", key, yaml_scalar(value)));
+        }
+    }
+
+    fields.push(format!("published: {}", article.published));
+    if let Some(date) = date {
+        fields.push(format!("date: {}", date));
+    }
+
+    let syndication = &article.syndication;
+    let platform_fields = [
+        ("devto_url", &syndication.devto_url),
+        ("devto_id", &syndication.devto_id),
+        ("medium_url", &syndication.medium_url),
+        ("medium_id", &syndication.medium_id),
+    ];
+    for (key, value) in platform_fields {
+        if let Some(value) = value {
+            fields.push(format!("{}: {}", key, yaml_scalar(value)));
+        }
+    }
+
+    format!(
+        "---\n{}\n---\n\n{}\n",
+        fields.join("\n"),
+        article.content.trim()
+    )
+}
+
+/// Derive a markdown file name from an article title (`My Title` -> `my-title.md`)
+pub fn default_file_name(title: &str) -> String {
+    let stem = slugify(title);
+
+    if stem.is_empty() {
+        "article.md".to_string()
+    } else {
+        format!("{}.md", stem)
+    }
+}
+
+/// Substitute template placeholders with article values
+fn render_template(template: &str, vars: &ScaffoldVars) -> String {
+    template
+        .replace("{{title}}", vars.title)
+        .replace("{{date}}", vars.date)
+        .replace("{{tags}}", &vars.tags.join(", "))
+}
+
+/// Quote a string as a YAML double-quoted scalar
+fn yaml_quote(value: &str) -> String {
+    format!("\"{}\"", value.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
+/// Render a string as a plain YAML scalar when safe, quoted otherwise
+pub(crate) fn yaml_scalar(value: &str) -> String {
+    let is_plain = value
+        .chars()
+        .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+        && value.starts_with(|c: char| c.is_ascii_alphanumeric());
+
+    if is_plain {
+        value.to_string()
+    } else {
+        yaml_quote(value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parsers::parse_markdown;
+
+    fn vars<'a>(title: &'a str, tags: &'a [String]) -> ScaffoldVars<'a> {
+        ScaffoldVars {
+            title,
+            tags,
+            date: "2026-01-15",
+        }
+    }
+
+    #[test]
+    fn test_scaffold_default_body() {
+        let tags = vec!["rust".to_string(), "cli".to_string()];
+        let content = scaffold_article(&vars("My Title", &tags), None);
+
+        assert_eq!(
+            content,
+            "---\ntitle: \"My Title\"\ntags: [rust, cli]\npublished: false\ndate: 2026-01-15\n---\n\n\
+             Write your article here.\n"
+        );
+    }
+
+    #[test]
+    fn test_scaffold_parses_back() {
+        let tags = vec!["web dev".to_string()];
+        let content = scaffold_article(&vars("Rust: The \"Good\" Parts", &tags), None);
+
+        let article = parse_markdown(&content).unwrap();
+        assert_eq!(article.title, "Rust: The \"Good\" Parts");
+        assert_eq!(article.tags, vec!["web dev"]);
+        assert!(!article.published);
+    }
+
+    #[test]
+    fn test_scaffold_with_template() {
+        let tags = vec!["rust".to_string()];
+        let template = "# {{title}}\n\nDrafted on {{date}} ({{tags}}).\n";
+        let content = scaffold_article(&vars("Templated", &tags), Some(template));
+
+        assert!(content.ends_with("# Templated\n\nDrafted on 2026-01-15 (rust).\n"));
+        assert_eq!(parse_markdown(&content).unwrap().title, "Templated");
+    }
+
+    #[test]
+    fn test_render_article_parses_back() {
+        let article = Article::new("Imported: \"Post\"".to_string(), "Body.\n".to_string())
+            .with_tags(vec!["rust".to_string(), "c#".to_string()])
+            .with_canonical_url("https://myblog.dev/post".to_string())
+            .with_published(false)
+            .with_syndication(crate::models::Syndication {
+                devto_url: Some("https://dev.to/u/post-1a2b".to_string()),
+                devto_id: Some("42".to_string()),
+                ..Default::default()
+            });
+
+        let content = render_article(&article, Some("2024-03-01"));
+        let parsed = parse_markdown(&content).unwrap();
+
+        assert!(content.contains("date: 2024-03-01\n"));
+        assert_eq!(parsed.title, article.title);
+        assert_eq!(parsed.tags, article.tags);
+        assert_eq!(parsed.canonical_url, article.canonical_url);
+        assert!(!parsed.published);
+        assert_eq!(parsed.syndication, article.syndication);
+        assert_eq!(parsed.content.trim(), "Body.");
+    }
+
+    #[test]
+    fn test_default_file_name() {
+        assert_eq!(default_file_name("My Title"), "my-title.md");
+        assert_eq!(default_file_name("Rust: 10 Tips!"), "rust-10-tips.md");
+        assert_eq!(default_file_name("???"), "article.md");
+    }
+
+    #[test]
+    fn test_yaml_scalar() {
+        assert_eq!(yaml_scalar("rust"), "rust");
+        assert_eq!(yaml_scalar("web-dev"), "web-dev");
+        assert_eq!(yaml_scalar("c#"), "\"c#\"");
+        assert_eq!(yaml_scalar("-leading"), "\"-leading\"");
+    }
+}
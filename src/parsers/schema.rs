@@ -0,0 +1,340 @@
+use once_cell::sync::Lazy;
+use serde_json::{json, Value};
+use std::fmt;
+
+use crate::models::MediumLicense;
+
+/// Platform names accepted in `platforms` / `exclude_platforms` (as parsed by `Platform`)
+const PLATFORM_PATTERN: &str = "(?i)^(devto|dev\\.to|medium)$";
+
+/// JSON Schema of the frontmatter, mirroring the fields of `Frontmatter`
+///
+/// Each property carries an `x-hint` with the fix to suggest when a value doesn't match.
+/// Unknown fields are allowed, as they are when parsing.
+static FRONTMATTER_SCHEMA: Lazy<Value> = Lazy::new(|| {
+    let text = |hint: &str| json!({ "type": ["string", "null"], "x-hint": hint });
+    let flag = json!({
+        "type": ["boolean", "null"],
+        "x-hint": "use true or false (without quotes)"
+    });
+    let platforms = json!({
+        "type": "array",
+        "items": { "type": "string", "pattern": PLATFORM_PATTERN },
+        "x-hint": "write a list of platforms, e.g. [devto, medium]"
+    });
+    let id = json!({
+        "type": ["string", "integer", "null"],
+        "x-hint": "use the ID the platform returned, e.g. 123456"
+    });
+    let licenses: Vec<&str> = MediumLicense::ALL.iter().map(|l| l.as_str()).collect();
+
+    json!({
+        "type": "object",
+        "x-hint": "write the frontmatter as `key: value` lines",
+        "properties": {
+            "title": text("quote titles containing ':' or starting with a special character, e.g. title: \"Rust: The Basics\""),
+            "tags": {
+                "type": "array",
+                "items": { "type": "string" },
+                "x-hint": "write tags as a list, e.g. tags: [rust, webdev]"
+            },
+            "canonical_url": text("use the full URL of the original article"),
+            "published": {
+                "type": "boolean",
+                "x-hint": "use true or false (without quotes)"
+            },
+            "cover_image": text("use an image URL or a path relative to the article"),
+            "description": text("quote descriptions containing ':' or '#'"),
+            "slug": text("use a slug such as my-first-post"),
+            "series": text("use the series name, quoted if it contains ':'"),
+            "series_part": {
+                "type": ["integer", "null"],
+                "minimum": 0,
+                "x-hint": "use the part number, e.g. series_part: 2"
+            },
+            "medium_license": {
+                "enum": licenses,
+                "x-hint": format!("use one of: {}", licenses.join(", "))
+            },
+            "notify_followers": flag,
+            "platforms": platforms.clone(),
+            "exclude_platforms": platforms,
+            "devto_url": text("use the dev.to article URL"),
+            "devto_id": id.clone(),
+            "medium_url": text("use the Medium post URL"),
+            "medium_id": id,
+            "published_at": text("use a date such as 2024-03-01 or 2024-03-01T09:00:00Z"),
+            "date": text("use a date such as 2024-03-01 or 2024-03-01T09:00:00Z"),
+            "lang": text("use a language code such as de or pt-br")
+        }
+    })
+});
+
+static FRONTMATTER_VALIDATOR: Lazy<jsonschema::Validator> = Lazy::new(|| {
+    jsonschema::validator_for(&FRONTMATTER_SCHEMA).expect("frontmatter schema is valid")
+});
+
+/// A problem in an article's frontmatter, located in the file
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FrontmatterIssue {
+    /// Line in the file (1-based), if known
+    pub line: Option<usize>,
+
+    /// Top-level field the issue is about, if known
+    pub field: Option<String>,
+
+    /// What is wrong
+    pub message: String,
+
+    /// How to fix it
+    pub hint: Option<String>,
+}
+
+impl fmt::Display for FrontmatterIssue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match (self.line, &self.field) {
+            (Some(line), Some(field)) => write!(f, "line {}, `{}`: ", line, field)?,
+            (Some(line), None) => write!(f, "line {}: ", line)?,
+            (None, Some(field)) => write!(f, "`{}`: ", field)?,
+            (None, None) => {}
+        }
+        write!(f, "{}", self.message)?;
+        if let Some(ref hint) = self.hint {
+            write!(f, " - {}", hint)?;
+        }
+        Ok(())
+    }
+}
+
+/// Check an article's frontmatter against the frontmatter schema
+///
+/// Reports YAML syntax errors and values of the wrong type with the line, the field
+/// and how to fix them. Content without a frontmatter block has no issues.
+pub fn check_frontmatter(content: &str) -> Vec<FrontmatterIssue> {
+    let Some(block) = frontmatter_block(content) else {
+        return Vec::new();
+    };
+
+    let yaml: serde_yaml::Value = match serde_yaml::from_str(&block) {
+        Ok(yaml) => yaml,
+        Err(e) => return vec![syntax_issue(&block, &e)],
+    };
+    if yaml.is_null() {
+        return Vec::new();
+    }
+
+    let value = match serde_json::to_value(&yaml) {
+        Ok(value) => value,
+        Err(e) => {
+            return vec![FrontmatterIssue {
+                line: Some(2),
+                field: None,
+                message: e.to_string(),
+                hint: Some("use plain names as keys".to_string()),
+            }]
+        }
+    };
+
+    let mut issues: Vec<FrontmatterIssue> = FRONTMATTER_VALIDATOR
+        .iter_errors(&value)
+        .map(|error| {
+            let path = error.instance_path.as_str().to_string();
+            let field = path.split('/').nth(1).map(str::to_string);
+            let hint = match field {
+                Some(ref field) => FRONTMATTER_SCHEMA["properties"][field]["x-hint"].as_str(),
+                None => FRONTMATTER_SCHEMA["x-hint"].as_str(),
+            };
+            // Name the element for values nested in the field (`platforms/1`)
+            let message = match path.matches('/').count() {
+                0 | 1 => error.to_string(),
+                _ => format!("{} (at {})", error, path.trim_start_matches('/')),
+            };
+
+            FrontmatterIssue {
+                line: field.as_deref().and_then(|field| field_line(&block, field)),
+                field,
+                message,
+                hint: hint.map(str::to_string),
+            }
+        })
+        .collect();
+
+    issues.sort_by_key(|issue| issue.line);
+    issues
+}
+
+/// Text between the opening and closing `---` lines, if the content starts with a frontmatter block
+///
+/// Line 1 of the block is line 2 of the file.
+fn frontmatter_block(content: &str) -> Option<String> {
+    let mut lines = content.lines();
+    if lines.next()?.trim_end() != "---" {
+        return None;
+    }
+
+    let mut block = String::new();
+    for line in lines {
+        if line.trim_end() == "---" {
+            return Some(block);
+        }
+        block.push_str(line);
+        block.push('\n');
+    }
+
+    None
+}
+
+/// File line of a top-level field in the frontmatter block
+fn field_line(block: &str, field: &str) -> Option<usize> {
+    block
+        .lines()
+        .position(|line| {
+            line.strip_prefix(field)
+                .is_some_and(|rest| rest.trim_start().starts_with(':'))
+        })
+        .map(|i| i + 2)
+}
+
+/// Issue for a YAML syntax error, with a fix for the usual causes
+fn syntax_issue(block: &str, error: &serde_yaml::Error) -> FrontmatterIssue {
+    // Strip the location serde_yaml appends; the issue reports it as the file line
+    let message = error.to_string();
+    let message = message
+        .split(" at line ")
+        .next()
+        .unwrap_or(&message)
+        .to_string();
+
+    let Some(location) = error.location() else {
+        return FrontmatterIssue {
+            line: None,
+            field: None,
+            message,
+            hint: None,
+        };
+    };
+
+    // The error can be reported after the mistake (an unclosed quote runs to the end)
+    let lines: Vec<&str> = block.lines().collect();
+    let index = location.line().saturating_sub(1);
+    let culprit = (0..=index)
+        .rev()
+        .filter_map(|i| lines.get(i).map(|line| (i, *line)))
+        .find_map(|(i, line)| syntax_hint(line).map(|hint| (i, line, hint)));
+
+    match culprit {
+        Some((i, line, hint)) => FrontmatterIssue {
+            line: Some(i + 2),
+            field: line_key(line),
+            message,
+            hint: Some(hint),
+        },
+        None => FrontmatterIssue {
+            line: Some(index + 2),
+            field: lines.get(index).and_then(|line| line_key(line)),
+            message,
+            hint: None,
+        },
+    }
+}
+
+/// How to fix a frontmatter line that breaks YAML parsing, if it has a known mistake
+fn syntax_hint(line: &str) -> Option<String> {
+    if line.starts_with('\t') {
+        return Some("indent with spaces, not tabs".to_string());
+    }
+
+    let key = line_key(line)?;
+    let value = line.split_once(':')?.1.trim();
+    if value.starts_with(['"', '\'']) {
+        let quote = &value[..1];
+        if value.len() == 1 || !value.ends_with(quote) {
+            return Some(format!("close the {} quote on the `{}` value", quote, key));
+        }
+        return None;
+    }
+
+    if value.contains(": ") {
+        return Some(format!(
+            "quote values containing ':', e.g. {}: \"{}\"",
+            key,
+            value.replace('"', "\\\"")
+        ));
+    }
+    if value.starts_with(['@', '`', '%', '|', '>', '*', '&', '!']) && value.len() > 1 {
+        return Some(format!(
+            "quote values starting with '{}', e.g. {}: \"{}\"",
+            &value[..1],
+            key,
+            value.replace('"', "\\\"")
+        ));
+    }
+
+    None
+}
+
+/// Key of a top-level `key: value` line
+fn line_key(line: &str) -> Option<String> {
+    let (key, _) = line.split_once(':')?;
+    let valid = !key.is_empty()
+        && key
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-');
+    valid.then(|| key.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_check_frontmatter_unquoted_colon() {
+        let content = "---\ntags: [rust]\ntitle: Rust: The Basics\n---\n\nBody";
+        let issues = check_frontmatter(content);
+
+        assert_eq!(issues.len(), 1);
+        assert_eq!(issues[0].line, Some(3));
+        assert_eq!(issues[0].field.as_deref(), Some("title"));
+        assert_eq!(
+            issues[0].hint.as_deref(),
+            Some("quote values containing ':', e.g. title: \"Rust: The Basics\"")
+        );
+        assert!(issues[0].to_string().starts_with("line 3, `title`: "));
+    }
+
+    #[test]
+    fn test_check_frontmatter_types() {
+        let content = "---\ntitle: Types\ntags: rust, web\npublished: \"yes\"\n\
+                       platforms: [devto, hashnode]\nmedium_license: cc-by\nauthor: me\n---\n";
+        let issues = check_frontmatter(content);
+        let fields: Vec<(Option<usize>, &str)> = issues
+            .iter()
+            .map(|issue| (issue.line, issue.field.as_deref().unwrap()))
+            .collect();
+
+        assert_eq!(
+            fields,
+            vec![
+                (Some(3), "tags"),
+                (Some(4), "published"),
+                (Some(5), "platforms"),
+                (Some(6), "medium_license")
+            ]
+        );
+        assert!(issues[0]
+            .hint
+            .as_deref()
+            .unwrap()
+            .contains("tags: [rust, webdev]"));
+        assert!(issues[2].message.contains("(at platforms/1)"));
+    }
+
+    #[test]
+    fn test_check_frontmatter_valid_or_absent() {
+        let content = "---\ntitle: \"A: B\"\ntags: [rust]\npublished: false\n\
+                       platforms: [Dev.to]\ndevto_id: 123\nseries_part: 2\n---\n\nBody";
+        assert!(check_frontmatter(content).is_empty());
+        assert!(check_frontmatter("# Just a heading\n\nBody").is_empty());
+        assert!(check_frontmatter("---\n---\n\nBody").is_empty());
+    }
+}
@@ -0,0 +1,77 @@
+use pulldown_cmark::{Event, Options, Parser, Tag};
+use std::ops::Range;
+
+/// Byte ranges of all code in markdown content: fenced and indented code blocks
+/// (including their fences) and inline code spans (including backticks)
+pub fn code_ranges(markdown: &str) -> Vec<Range<usize>> {
+    let mut options = Options::empty();
+    options.insert(Options::ENABLE_TABLES);
+    options.insert(Options::ENABLE_FOOTNOTES);
+    options.insert(Options::ENABLE_STRIKETHROUGH);
+    options.insert(Options::ENABLE_TASKLISTS);
+
+    let mut ranges: Vec<Range<usize>> = Vec::new();
+    for (event, range) in Parser::new_ext(markdown, options).into_offset_iter() {
+        if matches!(event, Event::Start(Tag::CodeBlock(_)) | Event::Code(_)) {
+            ranges.push(range);
+        }
+    }
+
+    ranges
+}
+
+/// Apply a text transform to everything except code, leaving code byte-for-byte intact
+pub fn map_prose<F>(markdown: &str, mut transform: F) -> String
+where
+    F: FnMut(&str) -> String,
+{
+    let mut output = String::with_capacity(markdown.len());
+    write_prose(markdown, &mut output, |prose, output| {
+        output.push_str(&transform(prose))
+    });
+
+    output
+}
+
+/// Like `map_prose`, but the transform writes each prose segment straight into `output`
+pub fn write_prose<F>(markdown: &str, output: &mut String, mut transform: F)
+where
+    F: FnMut(&str, &mut String),
+{
+    let mut cursor = 0;
+
+    for range in code_ranges(markdown) {
+        if range.start < cursor {
+            continue;
+        }
+        transform(&markdown[cursor..range.start], output);
+        output.push_str(&markdown[range.clone()]);
+        cursor = range.end;
+    }
+    transform(&markdown[cursor..], output);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_code_ranges() {
+        let content = "Text `inline` more\n\n```rust\nlet x = 1;\n```\n\n    indented\n";
+        let ranges = code_ranges(content);
+        let code: Vec<&str> = ranges.iter().map(|r| &content[r.clone()]).collect();
+
+        assert_eq!(code.len(), 3);
+        assert_eq!(code[0], "`inline`");
+        assert!(code[1].starts_with("```rust") && code[1].trim_end().ends_with("```"));
+        assert!(code[2].contains("indented"));
+    }
+
+    #[test]
+    fn test_map_prose_skips_code() {
+        let content = "a `a` a\n\n```\na\n```\n";
+        let result = map_prose(content, |text| text.replace('a', "b"));
+
+        assert_eq!(result, "b `a` b\n\n```\na\n```\n");
+    }
+}
@@ -0,0 +1,264 @@
+use anyhow::{Context, Result};
+use serde::Deserialize;
+use std::collections::BTreeSet;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::models::Article;
+use crate::parsers::parse_markdown;
+use crate::parsers::validator::collect_markdown_files;
+
+/// A link to another part of a series
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SeriesLink {
+    /// Title of the linked part
+    pub title: String,
+
+    /// Published URL of the linked part
+    pub url: String,
+}
+
+/// One article of a series found on disk
+#[derive(Debug, Clone)]
+pub struct SeriesPart {
+    /// Path of the markdown file
+    pub path: PathBuf,
+
+    /// Parsed article
+    pub article: Article,
+}
+
+/// Series manifest listing the parts of a series in publishing order
+///
+/// ```toml
+/// series = "Async Rust"
+/// parts = ["intro.md", "futures.md", "runtimes.md"]
+/// ```
+#[derive(Debug, Clone, Deserialize)]
+pub struct SeriesManifest {
+    /// Series name (replaces the parts' `series` frontmatter)
+    pub series: String,
+
+    /// Part files, relative to the manifest
+    pub parts: Vec<PathBuf>,
+}
+
+/// Load a series from a directory or a manifest file, in publishing order
+///
+/// A directory must contain exactly one series (articles with a `series` field).
+/// A manifest sets the name and order explicitly; each part gets the manifest's
+/// series name and its position as `series_part`. Part paths are canonicalized.
+pub fn load_series(path: &Path) -> Result<(String, Vec<SeriesPart>)> {
+    let path = path
+        .canonicalize()
+        .context(format!("Invalid or inaccessible path: {}", path.display()))?;
+
+    if path.is_dir() {
+        let names: BTreeSet<String> = collect_markdown_files(&path)?
+            .iter()
+            .filter_map(|file| fs::read_to_string(file).ok())
+            .filter_map(|content| parse_markdown(&content).ok()?.series)
+            .collect();
+
+        let name = match names.len() {
+            0 => anyhow::bail!(
+                "No articles with a 'series' frontmatter field found in {}",
+                path.display()
+            ),
+            1 => names.into_iter().next().unwrap_or_default(),
+            _ => anyhow::bail!(
+                "{} contains several series ({}) - use a manifest to pick one",
+                path.display(),
+                names.into_iter().collect::<Vec<_>>().join(", ")
+            ),
+        };
+
+        let parts = find_series_parts(&path, &name)?;
+        return Ok((name, parts));
+    }
+
+    let content = fs::read_to_string(&path).context(format!(
+        "Failed to read series manifest: {}",
+        path.display()
+    ))?;
+    let manifest: SeriesManifest = toml::from_str(&content).context(format!(
+        "Failed to parse series manifest: {}",
+        path.display()
+    ))?;
+    let dir = path.parent().context("Failed to get manifest directory")?;
+
+    let mut parts = Vec::new();
+    for (index, part) in manifest.parts.iter().enumerate() {
+        let part_path = dir
+            .join(part)
+            .canonicalize()
+            .context(format!("Invalid series part: {}", part.display()))?;
+        let content = fs::read_to_string(&part_path)
+            .context(format!("Failed to read {}", part_path.display()))?;
+        let article = parse_markdown(&content)
+            .context(format!("Failed to parse {}", part_path.display()))?
+            .with_series(manifest.series.clone(), Some(index as u32 + 1));
+
+        parts.push(SeriesPart {
+            path: part_path,
+            article,
+        });
+    }
+
+    if parts.is_empty() {
+        anyhow::bail!("Series manifest {} lists no parts", path.display());
+    }
+
+    Ok((manifest.series, parts))
+}
+
+/// Find every part of a series among the markdown files under a directory
+///
+/// Parts are ordered by `series_part`, then by path (parts without a number come last).
+/// Files that fail to parse are skipped.
+pub fn find_series_parts(dir: &Path, series: &str) -> Result<Vec<SeriesPart>> {
+    let mut parts: Vec<SeriesPart> = collect_markdown_files(dir)?
+        .into_iter()
+        .filter_map(|path| {
+            let content = fs::read_to_string(&path).ok()?;
+            let article = parse_markdown(&content).ok()?;
+            (article.series.as_deref() == Some(series)).then_some(SeriesPart { path, article })
+        })
+        .collect();
+
+    parts.sort_by(|a, b| {
+        let key = |part: &SeriesPart| part.article.series_part.unwrap_or(u32::MAX);
+        key(a).cmp(&key(b)).then_with(|| a.path.cmp(&b.path))
+    });
+
+    Ok(parts)
+}
+
+/// Add a previous/next navigation line to the top and bottom of an article
+///
+/// Content is returned unchanged if there is neither a previous nor a next link.
+pub fn add_series_links(
+    content: &str,
+    previous: Option<&SeriesLink>,
+    next: Option<&SeriesLink>,
+) -> String {
+    let mut links = Vec::new();
+    if let Some(link) = previous {
+        links.push(format!("Previous part: {}", markdown_link(link)));
+    }
+    if let Some(link) = next {
+        links.push(format!("Next part: {}", markdown_link(link)));
+    }
+
+    if links.is_empty() {
+        return content.to_string();
+    }
+
+    let navigation = format!("*{}*", links.join(" · "));
+    format!(
+        "{}\n\n{}\n\n---\n\n{}\n",
+        navigation,
+        content.trim(),
+        navigation
+    )
+}
+
+/// Format a markdown link, escaping brackets in the title
+fn markdown_link(link: &SeriesLink) -> String {
+    let title = link.title.replace('[', "\\[").replace(']', "\\]");
+    format!("[{}]({})", title, link.url)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn link(title: &str, url: &str) -> SeriesLink {
+        SeriesLink {
+            title: title.to_string(),
+            url: url.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_add_series_links() {
+        let previous = link("Part [1]", "https://dev.to/u/part-1");
+        let next = link("Part 3", "https://dev.to/u/part-3");
+
+        assert_eq!(
+            add_series_links("Body.\n", Some(&previous), Some(&next)),
+            "*Previous part: [Part \\[1\\]](https://dev.to/u/part-1) · \
+             Next part: [Part 3](https://dev.to/u/part-3)*\n\nBody.\n\n---\n\n\
+             *Previous part: [Part \\[1\\]](https://dev.to/u/part-1) · \
+             Next part: [Part 3](https://dev.to/u/part-3)*\n"
+        );
+        assert_eq!(add_series_links("Body.\n", None, None), "Body.\n");
+    }
+
+    #[test]
+    fn test_load_series_from_manifest() {
+        let dir = TempDir::new().unwrap();
+        fs::write(dir.path().join("b.md"), "---\ntitle: Intro\n---\n\nBody.").unwrap();
+        fs::write(
+            dir.path().join("a.md"),
+            "---\ntitle: Deep Dive\nseries: Old Name\n---\n\nBody.",
+        )
+        .unwrap();
+        fs::write(
+            dir.path().join("series.toml"),
+            "series = \"Async Rust\"\nparts = [\"b.md\", \"a.md\"]\n",
+        )
+        .unwrap();
+
+        let (name, parts) = load_series(&dir.path().join("series.toml")).unwrap();
+
+        assert_eq!(name, "Async Rust");
+        assert_eq!(parts[0].article.title, "Intro");
+        assert_eq!(parts[1].article.series.as_deref(), Some("Async Rust"));
+        assert_eq!(parts[1].article.series_part, Some(2));
+    }
+
+    #[test]
+    fn test_load_series_from_directory() {
+        let dir = TempDir::new().unwrap();
+        fs::write(
+            dir.path().join("one.md"),
+            "---\ntitle: One\nseries: Rust\n---\n\nBody.",
+        )
+        .unwrap();
+
+        let (name, parts) = load_series(dir.path()).unwrap();
+        assert_eq!(name, "Rust");
+        assert_eq!(parts.len(), 1);
+
+        fs::write(
+            dir.path().join("two.md"),
+            "---\ntitle: Two\nseries: Go\n---\n\nBody.",
+        )
+        .unwrap();
+        let err = load_series(dir.path()).unwrap_err().to_string();
+        assert!(err.contains("Go, Rust"));
+    }
+
+    #[test]
+    fn test_find_series_parts() {
+        let dir = TempDir::new().unwrap();
+        let write = |name: &str, frontmatter: &str| {
+            fs::write(
+                dir.path().join(name),
+                format!("---\n{}\n---\n\nBody.", frontmatter),
+            )
+            .unwrap();
+        };
+        write("a.md", "title: Second\nseries: Rust\nseries_part: 2");
+        write("b.md", "title: First\nseries: Rust\nseries_part: 1");
+        write("c.md", "title: Other\nseries: Go");
+        write("d.md", "title: Unnumbered\nseries: Rust");
+
+        let parts = find_series_parts(dir.path(), "Rust").unwrap();
+        let titles: Vec<&str> = parts.iter().map(|p| p.article.title.as_str()).collect();
+
+        assert_eq!(titles, vec!["First", "Second", "Unnumbered"]);
+    }
+}
@@ -0,0 +1,101 @@
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+
+/// Placeholder in a canonical base URL that is replaced with the slug
+const SLUG_PLACEHOLDER: &str = "{slug}";
+
+/// Where an article's slug comes from when deriving its canonical URL
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SlugSource {
+    /// The markdown file name (`my-post.md` -> `my-post`, `my-post/index.md` -> `my-post`)
+    #[default]
+    Filename,
+
+    /// The article title
+    Title,
+}
+
+/// Turn text into a lowercase kebab-case slug (`My Title!` -> `my-title`)
+///
+/// Non-ASCII characters are transliterated first (`Crème brûlée` -> `creme-brulee`).
+pub fn slugify(text: &str) -> String {
+    deunicode::deunicode(text)
+        .split(|c: char| !c.is_ascii_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .map(|word| word.to_ascii_lowercase())
+        .collect::<Vec<_>>()
+        .join("-")
+}
+
+/// Slug for a markdown file, taken from its name
+///
+/// Page bundles (`my-post/index.md`) use the directory name instead.
+pub fn slug_from_path(path: &Path) -> String {
+    let stem = path.file_stem().and_then(|s| s.to_str()).unwrap_or("");
+
+    let name = if stem.eq_ignore_ascii_case("index") || stem.eq_ignore_ascii_case("_index") {
+        path.parent()
+            .and_then(|dir| dir.file_name())
+            .and_then(|s| s.to_str())
+            .unwrap_or(stem)
+    } else {
+        stem
+    };
+
+    slugify(name)
+}
+
+/// Build a canonical URL from a base URL and a slug
+///
+/// The slug replaces a `{slug}` placeholder if the base URL has one
+/// (`https://myblog.dev/{slug}/`), otherwise it is appended as the last path segment.
+pub fn canonical_url(base_url: &str, slug: &str) -> String {
+    if base_url.contains(SLUG_PLACEHOLDER) {
+        base_url.replace(SLUG_PLACEHOLDER, slug)
+    } else {
+        format!("{}/{}", base_url.trim_end_matches('/'), slug)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_slugify() {
+        assert_eq!(slugify("Rust: The \"Good\" Parts"), "rust-the-good-parts");
+        assert_eq!(slugify("  10x   faster builds "), "10x-faster-builds");
+        assert_eq!(slugify("!!!"), "");
+        assert_eq!(
+            slugify("Crème Brûlée für Straße"),
+            "creme-brulee-fur-strasse"
+        );
+        assert_eq!(slugify("Привет, мир"), "privet-mir");
+    }
+
+    #[test]
+    fn test_slug_from_path() {
+        assert_eq!(slug_from_path(Path::new("posts/My_Post.md")), "my-post");
+        assert_eq!(
+            slug_from_path(Path::new("content/rust-tips/index.md")),
+            "rust-tips"
+        );
+    }
+
+    #[test]
+    fn test_canonical_url() {
+        assert_eq!(
+            canonical_url("https://myblog.dev/posts/", "my-post"),
+            "https://myblog.dev/posts/my-post"
+        );
+        assert_eq!(
+            canonical_url("https://myblog.dev/posts", "my-post"),
+            "https://myblog.dev/posts/my-post"
+        );
+        assert_eq!(
+            canonical_url("https://myblog.dev/{slug}/", "my-post"),
+            "https://myblog.dev/my-post/"
+        );
+    }
+}
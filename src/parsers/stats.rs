@@ -0,0 +1,154 @@
+use pulldown_cmark::{Event, HeadingLevel, Options, Parser, Tag, TagEnd};
+use unicode_segmentation::UnicodeSegmentation;
+
+/// A heading found in the article body
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HeadingInfo {
+    /// Heading level (1-6)
+    pub level: u8,
+
+    /// Plain heading text
+    pub text: String,
+}
+
+/// Content statistics used to sanity check an article before posting
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ContentStats {
+    /// Number of words in prose (code and image alt text are not counted)
+    pub word_count: usize,
+
+    /// Heading outline in document order
+    pub headings: Vec<HeadingInfo>,
+
+    /// Number of images
+    pub image_count: usize,
+
+    /// Number of fenced or indented code blocks
+    pub code_block_count: usize,
+
+    /// Number of links (inline, reference and autolinks)
+    pub link_count: usize,
+}
+
+/// Compute content statistics for a markdown body
+pub fn compute_stats(markdown: &str) -> ContentStats {
+    let mut options = Options::empty();
+    options.insert(Options::ENABLE_STRIKETHROUGH);
+    options.insert(Options::ENABLE_TABLES);
+    options.insert(Options::ENABLE_FOOTNOTES);
+    options.insert(Options::ENABLE_TASKLISTS);
+
+    let mut stats = ContentStats::default();
+    let mut in_code_block = false;
+    let mut in_image = false;
+    let mut current_heading: Option<HeadingInfo> = None;
+
+    for event in Parser::new_ext(markdown, options) {
+        match event {
+            Event::Start(Tag::Heading { level, .. }) => {
+                current_heading = Some(HeadingInfo {
+                    level: heading_level_number(level),
+                    text: String::new(),
+                });
+            }
+            Event::End(TagEnd::Heading(_)) => {
+                if let Some(mut heading) = current_heading.take() {
+                    heading.text = heading.text.trim().to_string();
+                    stats.headings.push(heading);
+                }
+            }
+            Event::Start(Tag::CodeBlock(_)) => {
+                stats.code_block_count += 1;
+                in_code_block = true;
+            }
+            Event::End(TagEnd::CodeBlock) => in_code_block = false,
+            Event::Start(Tag::Image { .. }) => {
+                stats.image_count += 1;
+                in_image = true;
+            }
+            Event::End(TagEnd::Image) => in_image = false,
+            Event::Start(Tag::Link { .. }) => stats.link_count += 1,
+            Event::Text(text) => {
+                // Code and image alt text are not part of the prose
+                if in_code_block || in_image {
+                    continue;
+                }
+                stats.word_count += text.unicode_words().count();
+                if let Some(ref mut heading) = current_heading {
+                    heading.text.push_str(&text);
+                }
+            }
+            Event::Code(code) => {
+                if let Some(ref mut heading) = current_heading {
+                    heading.text.push_str(&code);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    stats
+}
+
+/// Convert a pulldown-cmark heading level to its numeric value
+fn heading_level_number(level: HeadingLevel) -> u8 {
+    match level {
+        HeadingLevel::H1 => 1,
+        HeadingLevel::H2 => 2,
+        HeadingLevel::H3 => 3,
+        HeadingLevel::H4 => 4,
+        HeadingLevel::H5 => 5,
+        HeadingLevel::H6 => 6,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_word_count_ignores_code_blocks() {
+        let markdown = "One two three.\n\n```rust\nfn main() { let x = 1; }\n```\n\nFour five.";
+        let stats = compute_stats(markdown);
+
+        assert_eq!(stats.word_count, 5);
+        assert_eq!(stats.code_block_count, 1);
+    }
+
+    #[test]
+    fn test_heading_outline() {
+        let markdown = "# Title\n\nIntro\n\n## Setup `cargo`\n\n### Details\n\n## Usage";
+        let stats = compute_stats(markdown);
+
+        let outline: Vec<(u8, &str)> = stats
+            .headings
+            .iter()
+            .map(|h| (h.level, h.text.as_str()))
+            .collect();
+        assert_eq!(
+            outline,
+            vec![
+                (1, "Title"),
+                (2, "Setup cargo"),
+                (3, "Details"),
+                (2, "Usage")
+            ]
+        );
+    }
+
+    #[test]
+    fn test_images_and_links() {
+        let markdown = "See [docs](https://example.com) and <https://rust-lang.org>.\n\n\
+            ![diagram](https://example.com/a.png)\n\n![photo](https://example.com/b.jpg)";
+        let stats = compute_stats(markdown);
+
+        assert_eq!(stats.image_count, 2);
+        assert_eq!(stats.link_count, 2);
+    }
+
+    #[test]
+    fn test_empty_content() {
+        let stats = compute_stats("");
+        assert_eq!(stats, ContentStats::default());
+    }
+}
@@ -0,0 +1,252 @@
+use pulldown_cmark::{Event, Options, Parser, Tag, TagEnd};
+
+use crate::models::Article;
+
+/// Most sentences taken for a blurb
+const BLURB_SENTENCES: usize = 2;
+
+/// Most hashtags added to a post
+const MAX_HASHTAGS: usize = 3;
+
+/// Paragraphs shorter than this (captions, "TL;DR") are skipped when looking for a blurb
+const MIN_PARAGRAPH_CHARS: usize = 40;
+
+/// Short announcement text for an article: a blurb and hashtags
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SocialSummary {
+    /// One or two sentences about the article
+    pub blurb: String,
+
+    /// Hashtags, with the `#`
+    pub hashtags: Vec<String>,
+}
+
+impl SocialSummary {
+    /// Post text with an optional link, fitted into `max_chars` characters
+    ///
+    /// The blurb is shortened at a word boundary first; hashtags are dropped from the end
+    /// if even a one-word blurb doesn't fit.
+    pub fn post_text(&self, url: Option<&str>, max_chars: usize) -> String {
+        let mut hashtags = self.hashtags.clone();
+
+        loop {
+            let footer = [url.unwrap_or(""), &hashtags.join(" ")]
+                .into_iter()
+                .filter(|part| !part.is_empty())
+                .collect::<Vec<_>>()
+                .join("\n");
+            if footer.is_empty() {
+                if let Some(blurb) = shorten(&self.blurb, max_chars) {
+                    return blurb;
+                }
+            } else {
+                let room = max_chars.saturating_sub(footer.chars().count() + 2);
+                if let Some(blurb) = shorten(&self.blurb, room) {
+                    return format!("{}\n\n{}", blurb, footer);
+                }
+            }
+            if hashtags.pop().is_none() {
+                return footer;
+            }
+        }
+    }
+}
+
+/// Summarize an article for an announcement without any network access
+///
+/// The blurb is the description, or else the first sentences of the body's opening
+/// prose (headings, code, lists, quotes and short captions are skipped). Hashtags come
+/// from the tags.
+pub fn summarize(article: &Article) -> SocialSummary {
+    let source = match article.description {
+        Some(ref description) if !description.trim().is_empty() => description.clone(),
+        _ => opening_prose(&article.content),
+    };
+    let blurb = first_sentences(&source, BLURB_SENTENCES);
+
+    SocialSummary {
+        blurb: if blurb.is_empty() {
+            article.title.clone()
+        } else {
+            blurb
+        },
+        hashtags: hashtags(&article.tags),
+    }
+}
+
+/// Hashtags for tags (`web-dev` -> `#webdev`), at most three
+pub fn hashtags(tags: &[String]) -> Vec<String> {
+    let mut hashtags: Vec<String> = Vec::new();
+    for tag in tags {
+        let tag: String = tag.chars().filter(|c| c.is_alphanumeric()).collect();
+        let hashtag = format!("#{}", tag);
+        if !tag.is_empty() && !hashtags.contains(&hashtag) {
+            hashtags.push(hashtag);
+        }
+    }
+
+    hashtags.truncate(MAX_HASHTAGS);
+    hashtags
+}
+
+/// Plain text of the first top-level paragraphs, up to a few sentences' worth
+fn opening_prose(markdown: &str) -> String {
+    let mut options = Options::empty();
+    options.insert(Options::ENABLE_TABLES);
+    options.insert(Options::ENABLE_FOOTNOTES);
+    options.insert(Options::ENABLE_STRIKETHROUGH);
+
+    let mut prose = String::new();
+    let mut paragraph: Option<String> = None;
+    let mut nesting = 0;
+
+    for event in Parser::new_ext(markdown, options) {
+        match event {
+            Event::Start(Tag::BlockQuote(_) | Tag::List(_) | Tag::Table(_))
+            | Event::Start(Tag::FootnoteDefinition(_)) => nesting += 1,
+            Event::End(TagEnd::BlockQuote(_) | TagEnd::List(_) | TagEnd::Table)
+            | Event::End(TagEnd::FootnoteDefinition) => nesting -= 1,
+            Event::Start(Tag::Paragraph) if nesting == 0 => paragraph = Some(String::new()),
+            Event::End(TagEnd::Paragraph) => {
+                if let Some(text) = paragraph.take() {
+                    let text = text.trim();
+                    if text.chars().count() >= MIN_PARAGRAPH_CHARS {
+                        prose.push_str(text);
+                        prose.push(' ');
+                    }
+                }
+                if sentence_ends(&prose).len() >= BLURB_SENTENCES {
+                    break;
+                }
+            }
+            Event::Text(text) | Event::Code(text) => {
+                if let Some(ref mut paragraph) = paragraph {
+                    paragraph.push_str(&text);
+                }
+            }
+            Event::SoftBreak | Event::HardBreak => {
+                if let Some(ref mut paragraph) = paragraph {
+                    paragraph.push(' ');
+                }
+            }
+            _ => {}
+        }
+    }
+
+    prose.trim().to_string()
+}
+
+/// The first `count` sentences of a text, with whitespace collapsed
+fn first_sentences(text: &str, count: usize) -> String {
+    let text = text.split_whitespace().collect::<Vec<_>>().join(" ");
+
+    match sentence_ends(&text).get(count.saturating_sub(1)) {
+        Some(&end) => text[..end].to_string(),
+        None => text,
+    }
+}
+
+/// Byte offsets just past each sentence end (`.`, `!` or `?` after a word and before
+/// whitespace or the end)
+fn sentence_ends(text: &str) -> Vec<usize> {
+    let mut ends = Vec::new();
+    let mut chars = text.char_indices().peekable();
+    let mut after_word = false;
+
+    while let Some((i, c)) = chars.next() {
+        let at_break = match chars.peek() {
+            Some((_, next)) => next.is_whitespace(),
+            None => true,
+        };
+        if matches!(c, '.' | '!' | '?') && after_word && at_break {
+            ends.push(i + c.len_utf8());
+        }
+        after_word = !c.is_whitespace();
+    }
+
+    ends
+}
+
+/// Shorten text to at most `max_chars` characters at a word boundary, ending with `…`
+///
+/// Returns `None` if not even the first word fits.
+fn shorten(text: &str, max_chars: usize) -> Option<String> {
+    if text.chars().count() <= max_chars {
+        return Some(text.to_string());
+    }
+
+    let mut shortened = String::new();
+    for word in text.split(' ') {
+        let candidate = if shortened.is_empty() {
+            word.to_string()
+        } else {
+            format!("{} {}", shortened, word)
+        };
+        if candidate.chars().count() + 1 > max_chars {
+            break;
+        }
+        shortened = candidate;
+    }
+
+    if shortened.is_empty() {
+        return None;
+    }
+    let shortened = shortened.trim_end_matches([',', ';', ':', ' ']);
+    Some(format!("{}…", shortened))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_summarize_from_body() {
+        let content = "# Intro\n\n![cover](c.png)\n\nPhoto by someone.\n\n\
+                       Rust's `?` operator makes error handling short. It works with\n\
+                       `Result` and `Option`! Here is how.\n\n```rust\nlet x = y?;\n```\n";
+        let article = Article::new("Errors".to_string(), content.to_string())
+            .with_tags(vec!["rust".to_string(), "error-handling".to_string()]);
+
+        let summary = summarize(&article);
+
+        assert_eq!(
+            summary.blurb,
+            "Rust's ? operator makes error handling short. It works with Result and Option!"
+        );
+        assert_eq!(summary.hashtags, vec!["#rust", "#errorhandling"]);
+    }
+
+    #[test]
+    fn test_summarize_prefers_description() {
+        let article = Article::new(
+            "T".to_string(),
+            "Body text that is long enough to be a blurb.".to_string(),
+        )
+        .with_description("A short description. Second sentence. Third.".to_string());
+
+        assert_eq!(
+            summarize(&article).blurb,
+            "A short description. Second sentence."
+        );
+    }
+
+    #[test]
+    fn test_post_text_fits_limit() {
+        let summary = SocialSummary {
+            blurb: "Rust's question mark operator makes error handling short and sweet."
+                .to_string(),
+            hashtags: vec!["#rust".to_string(), "#errors".to_string()],
+        };
+        let url = "https://blog.example.com/errors/";
+
+        assert_eq!(
+            summary.post_text(Some(url), 500),
+            format!("{}\n\n{}\n#rust #errors", summary.blurb, url)
+        );
+
+        let text = summary.post_text(Some(url), 80);
+        assert!(text.chars().count() <= 80);
+        assert!(text.starts_with("Rust's question mark operator…\n\n"));
+        assert!(text.ends_with("#rust #errors"));
+    }
+}
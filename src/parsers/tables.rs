@@ -0,0 +1,194 @@
+use anyhow::{Context, Result};
+use pulldown_cmark::{Alignment, Event, Options, Parser, Tag, TagEnd};
+use serde::{Deserialize, Serialize};
+use std::ops::Range;
+
+use crate::images::render_table_png;
+use crate::models::Asset;
+
+/// How markdown tables are handled for a platform without table support
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum TableStyle {
+    /// Rely on HTML conversion (`--format html`); warn when posting markdown
+    #[default]
+    Html,
+
+    /// Render each table to a PNG image that is uploaded before publishing
+    Image,
+
+    /// Leave tables untouched without warning
+    Keep,
+}
+
+impl std::str::FromStr for TableStyle {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "html" => Ok(TableStyle::Html),
+            "image" => Ok(TableStyle::Image),
+            "keep" => Ok(TableStyle::Keep),
+            _ => Err(format!(
+                "Unknown table style: '{}'. Valid options: html, image, keep",
+                s
+            )),
+        }
+    }
+}
+
+/// Horizontal alignment of a table column
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ColumnAlignment {
+    Left,
+    Center,
+    Right,
+}
+
+/// A markdown table reduced to plain-text cells
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Table {
+    /// Alignment of each column
+    pub alignments: Vec<ColumnAlignment>,
+
+    /// Header cells
+    pub header: Vec<String>,
+
+    /// Body rows
+    pub rows: Vec<Vec<String>>,
+}
+
+/// Find all tables in markdown content, with their byte ranges in the source
+pub fn extract_tables(content: &str) -> Vec<(Range<usize>, Table)> {
+    let mut options = Options::empty();
+    options.insert(Options::ENABLE_TABLES);
+    options.insert(Options::ENABLE_STRIKETHROUGH);
+
+    let mut tables = Vec::new();
+    let mut current: Option<(Range<usize>, Table)> = None;
+    let mut row: Vec<String> = Vec::new();
+    let mut cell = String::new();
+
+    for (event, range) in Parser::new_ext(content, options).into_offset_iter() {
+        match event {
+            Event::Start(Tag::Table(alignments)) => {
+                let alignments = alignments
+                    .into_iter()
+                    .map(|alignment| match alignment {
+                        Alignment::Center => ColumnAlignment::Center,
+                        Alignment::Right => ColumnAlignment::Right,
+                        Alignment::Left | Alignment::None => ColumnAlignment::Left,
+                    })
+                    .collect();
+                current = Some((
+                    range,
+                    Table {
+                        alignments,
+                        header: Vec::new(),
+                        rows: Vec::new(),
+                    },
+                ));
+            }
+            Event::End(TagEnd::Table) => tables.extend(current.take()),
+            Event::End(TagEnd::TableCell) => row.push(cell.trim().to_string()),
+            Event::End(TagEnd::TableHead) => {
+                if let Some((_, table)) = current.as_mut() {
+                    table.header = std::mem::take(&mut row);
+                }
+            }
+            Event::End(TagEnd::TableRow) => {
+                if let Some((_, table)) = current.as_mut() {
+                    table.rows.push(std::mem::take(&mut row));
+                }
+            }
+            Event::Start(Tag::TableCell) => cell.clear(),
+            Event::Text(text) | Event::Code(text) if current.is_some() => cell.push_str(&text),
+            Event::SoftBreak | Event::HardBreak if current.is_some() => cell.push(' '),
+            _ => {}
+        }
+    }
+
+    tables
+}
+
+/// Replace every table with an image reference, returning the content and the rendered images
+///
+/// Images are referenced by their asset placeholder URL until they are uploaded.
+pub fn tables_to_images(content: &str) -> Result<(String, Vec<Asset>)> {
+    let mut output = String::with_capacity(content.len());
+    let mut assets = Vec::new();
+    let mut cursor = 0;
+
+    for (index, (range, table)) in extract_tables(content).into_iter().enumerate() {
+        let number = index + 1;
+        let png = render_table_png(&table)
+            .with_context(|| format!("Failed to render table {} as an image", number))?;
+        let asset = Asset::new(&format!("table-{}", number), "png", "image/png", png);
+
+        output.push_str(&content[cursor..range.start]);
+        output.push_str(&format!("![Table {}]({})", number, asset.placeholder()));
+        if content[range.clone()].ends_with('\n') {
+            output.push('\n');
+        }
+        cursor = range.end;
+        assets.push(asset);
+    }
+    output.push_str(&content[cursor..]);
+
+    Ok((output, assets))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CONTENT: &str = "Intro\n\n\
+        | Name | Speed |\n\
+        |:-----|------:|\n\
+        | `Rust` | **fast** |\n\
+        | Python | ok |\n\
+        \n\
+        Outro\n";
+
+    #[test]
+    fn test_extract_tables() {
+        let tables = extract_tables(CONTENT);
+
+        assert_eq!(tables.len(), 1);
+        let (range, table) = &tables[0];
+        assert!(CONTENT[range.clone()].starts_with("| Name"));
+        assert_eq!(
+            table.alignments,
+            vec![ColumnAlignment::Left, ColumnAlignment::Right]
+        );
+        assert_eq!(table.header, vec!["Name", "Speed"]);
+        assert_eq!(table.rows, vec![vec!["Rust", "fast"], vec!["Python", "ok"]]);
+    }
+
+    #[test]
+    fn test_tables_in_code_are_ignored() {
+        let content = "```\n| a | b |\n|---|---|\n| 1 | 2 |\n```\n";
+        assert!(extract_tables(content).is_empty());
+    }
+
+    #[test]
+    fn test_tables_to_images() {
+        let (content, assets) = tables_to_images(CONTENT).unwrap();
+
+        assert_eq!(assets.len(), 1);
+        assert!(assets[0].data.starts_with(b"\x89PNG"));
+        assert_eq!(
+            content,
+            format!(
+                "Intro\n\n![Table 1]({})\n\nOutro\n",
+                assets[0].placeholder()
+            )
+        );
+    }
+
+    #[test]
+    fn test_table_style_from_str() {
+        assert_eq!("image".parse::<TableStyle>().unwrap(), TableStyle::Image);
+        assert!("png".parse::<TableStyle>().is_err());
+    }
+}
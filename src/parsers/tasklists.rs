@@ -0,0 +1,100 @@
+use pulldown_cmark::{Event, Options, Parser};
+use serde::{Deserialize, Serialize};
+
+/// How GitHub-style task lists (`- [x] item`) are rendered for a platform
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum TaskListStyle {
+    /// Leave task lists untouched (platform renders checkboxes)
+    #[default]
+    Keep,
+
+    /// Turn them into plain bullet lists prefixed with markers
+    Convert,
+}
+
+/// Markers substituted for task list checkboxes
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TaskMarkers {
+    /// Replacement for `[x]`
+    pub checked: String,
+
+    /// Replacement for `[ ]`
+    pub unchecked: String,
+}
+
+impl Default for TaskMarkers {
+    fn default() -> Self {
+        Self {
+            checked: "✓".to_string(),
+            unchecked: "✗".to_string(),
+        }
+    }
+}
+
+/// Replace task list checkboxes with plain-text markers
+///
+/// Checkboxes are located with the markdown parser, so `[x]` inside code is left alone.
+pub fn convert_task_lists(content: &str, markers: &TaskMarkers) -> String {
+    let mut options = Options::empty();
+    options.insert(Options::ENABLE_TASKLISTS);
+    options.insert(Options::ENABLE_TABLES);
+    options.insert(Options::ENABLE_FOOTNOTES);
+    options.insert(Options::ENABLE_STRIKETHROUGH);
+
+    let mut output = String::with_capacity(content.len());
+    let mut cursor = 0;
+
+    for (event, range) in Parser::new_ext(content, options).into_offset_iter() {
+        if let Event::TaskListMarker(checked) = event {
+            output.push_str(&content[cursor..range.start]);
+            output.push_str(if checked {
+                &markers.checked
+            } else {
+                &markers.unchecked
+            });
+            cursor = range.end;
+        }
+    }
+    output.push_str(&content[cursor..]);
+
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_convert_task_lists() {
+        let content = "Plan:\n\n- [x] Write\n- [ ] Publish\n  * [X] Nested\n- Plain item\n";
+        let result = convert_task_lists(content, &TaskMarkers::default());
+
+        assert_eq!(
+            result,
+            "Plan:\n\n- ✓ Write\n- ✗ Publish\n  * ✓ Nested\n- Plain item\n"
+        );
+    }
+
+    #[test]
+    fn test_custom_markers() {
+        let markers = TaskMarkers {
+            checked: "[done]".to_string(),
+            unchecked: "[todo]".to_string(),
+        };
+
+        assert_eq!(
+            convert_task_lists("1. [ ] First\n2. [x] Second\n", &markers),
+            "1. [todo] First\n2. [done] Second\n"
+        );
+    }
+
+    #[test]
+    fn test_task_syntax_in_code_is_ignored() {
+        let content = "```\n- [x] not a task\n```\n\nUse `- [ ]` for tasks.\n";
+        assert_eq!(
+            convert_task_lists(content, &TaskMarkers::default()),
+            content
+        );
+    }
+}
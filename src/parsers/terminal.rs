@@ -0,0 +1,518 @@
+use once_cell::sync::Lazy;
+use pulldown_cmark::{Alignment, CodeBlockKind, Event, HeadingLevel, Options, Parser, Tag, TagEnd};
+use regex::Regex;
+use syntect::easy::HighlightLines;
+use syntect::highlighting::{Theme, ThemeSet};
+use syntect::parsing::SyntaxSet;
+use syntect::util::{as_24_bit_terminal_escaped, LinesWithEndings};
+use unicode_segmentation::UnicodeSegmentation;
+
+static SYNTAXES: Lazy<SyntaxSet> = Lazy::new(SyntaxSet::load_defaults_newlines);
+static THEME: Lazy<Theme> = Lazy::new(|| {
+    let mut themes = ThemeSet::load_defaults();
+    themes
+        .themes
+        .remove("base16-ocean.dark")
+        .unwrap_or_default()
+});
+
+/// ANSI escape sequences, for measuring the visible width of styled text
+static ANSI_ESCAPE: Lazy<Regex> = Lazy::new(|| Regex::new(r"\x1b\[[0-9;]*m").unwrap());
+
+const RESET: &str = "\x1b[0m";
+
+/// Width of horizontal rules
+const RULE_WIDTH: usize = 60;
+
+/// Render markdown for a terminal with ANSI styling
+///
+/// Headings, emphasis, links, lists, block quotes and tables are styled; fenced code
+/// blocks are syntax-highlighted by their language tag. Lines are not re-wrapped.
+pub fn render_terminal(markdown: &str) -> String {
+    let mut options = Options::empty();
+    options.insert(Options::ENABLE_TABLES);
+    options.insert(Options::ENABLE_FOOTNOTES);
+    options.insert(Options::ENABLE_STRIKETHROUGH);
+    options.insert(Options::ENABLE_TASKLISTS);
+
+    let mut renderer = TerminalRenderer::default();
+    for event in Parser::new_ext(markdown, options) {
+        renderer.event(event);
+    }
+
+    let mut out = renderer.out.trim_end().to_string();
+    out.push('\n');
+    out
+}
+
+/// A table being collected; rendered once its end is reached
+#[derive(Default)]
+struct Table {
+    alignments: Vec<Alignment>,
+    rows: Vec<Vec<String>>,
+    row: Vec<String>,
+    cell: String,
+}
+
+/// A link or image being rendered; the destination is shown after the text
+struct Link {
+    url: String,
+    text: String,
+    image: bool,
+}
+
+struct TerminalRenderer {
+    out: String,
+
+    /// Active SGR parameters, re-applied after each reset
+    styles: Vec<&'static str>,
+
+    /// Line prefixes of the enclosing block quotes and list items
+    prefixes: Vec<String>,
+
+    /// Enclosing lists; the next number for ordered lists
+    lists: Vec<Option<u64>>,
+
+    /// Fenced code block being collected (language, code)
+    code: Option<(String, String)>,
+
+    table: Option<Table>,
+    links: Vec<Link>,
+
+    /// Nothing written on the current line yet (not even its prefix)
+    at_line_start: bool,
+
+    /// A blank line is due before the next block
+    pending_blank: bool,
+
+    /// A list marker was just written; the item's first block continues its line
+    after_marker: bool,
+}
+
+impl Default for TerminalRenderer {
+    fn default() -> Self {
+        Self {
+            out: String::new(),
+            styles: Vec::new(),
+            prefixes: Vec::new(),
+            lists: Vec::new(),
+            code: None,
+            table: None,
+            links: Vec::new(),
+            at_line_start: true,
+            pending_blank: false,
+            after_marker: false,
+        }
+    }
+}
+
+impl TerminalRenderer {
+    fn event(&mut self, event: Event) {
+        match event {
+            Event::Start(tag) => self.start(tag),
+            Event::End(tag) => self.end(tag),
+            Event::Text(text) => {
+                if let Some((_, code)) = self.code.as_mut() {
+                    code.push_str(&text);
+                } else {
+                    self.text(&text);
+                }
+            }
+            Event::Code(code) => {
+                self.push_style("33");
+                self.text(&code);
+                self.pop_style();
+            }
+            Event::Html(html) | Event::InlineHtml(html) => {
+                self.push_style("2");
+                self.write(html.trim_end_matches('\n'));
+                self.pop_style();
+            }
+            Event::FootnoteReference(label) => {
+                self.push_style("36");
+                self.write(&format!("[{}]", label));
+                self.pop_style();
+            }
+            Event::SoftBreak | Event::HardBreak => self.write("\n"),
+            Event::Rule => {
+                self.start_block();
+                self.push_style("2");
+                self.write(&"─".repeat(RULE_WIDTH));
+                self.pop_style();
+                self.end_block();
+            }
+            Event::TaskListMarker(checked) => {
+                self.write(if checked { "[x] " } else { "[ ] " });
+            }
+            _ => {}
+        }
+    }
+
+    fn start(&mut self, tag: Tag) {
+        match tag {
+            Tag::Paragraph => self.start_block(),
+            Tag::Heading { level, .. } => {
+                self.start_block();
+                self.push_style(match level {
+                    HeadingLevel::H1 => "1;4;35",
+                    HeadingLevel::H2 => "1;36",
+                    _ => "1",
+                });
+            }
+            Tag::BlockQuote(_) => {
+                self.start_block();
+                self.prefixes.push("│ ".to_string());
+                self.push_style("3");
+            }
+            Tag::CodeBlock(kind) => {
+                self.start_block();
+                let language = match kind {
+                    CodeBlockKind::Fenced(info) => {
+                        info.split_whitespace().next().unwrap_or("").to_string()
+                    }
+                    CodeBlockKind::Indented => String::new(),
+                };
+                self.code = Some((language, String::new()));
+            }
+            Tag::List(start) => {
+                if self.lists.is_empty() {
+                    self.start_block();
+                }
+                self.lists.push(start);
+            }
+            Tag::Item => {
+                self.new_line();
+                if self.pending_blank {
+                    self.blank_line();
+                }
+                let marker = match self.lists.last_mut() {
+                    Some(Some(number)) => {
+                        *number += 1;
+                        format!("{}. ", *number - 1)
+                    }
+                    _ => "• ".to_string(),
+                };
+                self.write(&marker);
+                self.prefixes.push(" ".repeat(marker.chars().count()));
+                self.after_marker = true;
+            }
+            Tag::FootnoteDefinition(label) => {
+                self.start_block();
+                self.push_style("36");
+                self.write(&format!("[{}]: ", label));
+                self.pop_style();
+                self.after_marker = true;
+            }
+            Tag::Table(alignments) => {
+                self.start_block();
+                self.table = Some(Table {
+                    alignments,
+                    ..Default::default()
+                });
+            }
+            Tag::Emphasis => self.push_style("3"),
+            Tag::Strong => self.push_style("1"),
+            Tag::Strikethrough => self.push_style("9"),
+            Tag::Link { dest_url, .. } => {
+                self.links.push(Link {
+                    url: dest_url.to_string(),
+                    text: String::new(),
+                    image: false,
+                });
+                self.push_style("4;34");
+            }
+            Tag::Image { dest_url, .. } => {
+                self.links.push(Link {
+                    url: dest_url.to_string(),
+                    text: String::new(),
+                    image: true,
+                });
+                self.push_style("35");
+                self.write("[image: ");
+            }
+            _ => {}
+        }
+    }
+
+    fn end(&mut self, tag: TagEnd) {
+        match tag {
+            TagEnd::Paragraph | TagEnd::FootnoteDefinition => self.end_block(),
+            TagEnd::Heading(_) => {
+                self.pop_style();
+                self.end_block();
+            }
+            TagEnd::BlockQuote(_) => {
+                self.pop_style();
+                self.prefixes.pop();
+                self.end_block();
+            }
+            TagEnd::CodeBlock => {
+                if let Some((language, code)) = self.code.take() {
+                    self.write_code(&language, &code);
+                }
+                self.end_block();
+            }
+            TagEnd::List(_) => {
+                self.lists.pop();
+                if self.lists.is_empty() {
+                    self.end_block();
+                }
+            }
+            TagEnd::Item => {
+                self.prefixes.pop();
+                self.after_marker = false;
+                self.new_line();
+            }
+            TagEnd::TableCell => {
+                if let Some(table) = self.table.as_mut() {
+                    let cell = std::mem::take(&mut table.cell);
+                    table.row.push(cell.trim().to_string());
+                }
+            }
+            TagEnd::TableHead | TagEnd::TableRow => {
+                if let Some(table) = self.table.as_mut() {
+                    let row = std::mem::take(&mut table.row);
+                    table.rows.push(row);
+                }
+            }
+            TagEnd::Table => {
+                if let Some(table) = self.table.take() {
+                    self.write_table(&table);
+                }
+                self.end_block();
+            }
+            TagEnd::Emphasis | TagEnd::Strong | TagEnd::Strikethrough => self.pop_style(),
+            TagEnd::Link | TagEnd::Image => {
+                self.pop_style();
+                if let Some(link) = self.links.pop() {
+                    if link.image {
+                        self.write("]");
+                    }
+                    if !link.url.is_empty() && link.url != link.text {
+                        self.push_style("2");
+                        self.write(&format!(" ({})", link.url));
+                        self.pop_style();
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// Write inline text, also recording it as the text of the enclosing link
+    fn text(&mut self, text: &str) {
+        if let Some(link) = self.links.last_mut() {
+            link.text.push_str(text);
+        }
+        self.write(text);
+    }
+
+    /// Write text, starting each new line with the block prefixes (into the table cell inside tables)
+    fn write(&mut self, text: &str) {
+        if let Some(table) = self.table.as_mut() {
+            table.cell.push_str(&text.replace('\n', " "));
+            return;
+        }
+
+        self.after_marker = false;
+        for (i, line) in text.split('\n').enumerate() {
+            if i > 0 {
+                self.out.push('\n');
+                self.at_line_start = true;
+            }
+            if line.is_empty() {
+                continue;
+            }
+            if self.at_line_start {
+                self.out.push_str(&self.prefixes.concat());
+                self.at_line_start = false;
+            }
+            self.out.push_str(line);
+        }
+    }
+
+    fn new_line(&mut self) {
+        if !self.at_line_start && !self.out.is_empty() {
+            self.out.push('\n');
+            self.at_line_start = true;
+        }
+    }
+
+    /// Empty line that keeps the block quote bars
+    fn blank_line(&mut self) {
+        let prefix = self.prefixes.concat();
+        self.out.push_str(prefix.trim_end());
+        self.out.push('\n');
+        self.at_line_start = true;
+        self.pending_blank = false;
+    }
+
+    fn start_block(&mut self) {
+        if self.after_marker {
+            // The first block of a list item continues the marker's line
+            self.after_marker = false;
+            self.pending_blank = false;
+            return;
+        }
+        self.new_line();
+        if self.pending_blank {
+            self.blank_line();
+        }
+    }
+
+    fn end_block(&mut self) {
+        self.new_line();
+        self.pending_blank = true;
+    }
+
+    fn push_style(&mut self, style: &'static str) {
+        self.styles.push(style);
+        self.write_escape(&format!("\x1b[{}m", style));
+    }
+
+    fn pop_style(&mut self) {
+        self.styles.pop();
+        let mut sequence = RESET.to_string();
+        for style in &self.styles {
+            sequence.push_str(&format!("\x1b[{}m", style));
+        }
+        self.write_escape(&sequence);
+    }
+
+    /// Write an escape sequence; unlike text it does not start a line with the prefixes
+    fn write_escape(&mut self, sequence: &str) {
+        match self.table.as_mut() {
+            Some(table) => table.cell.push_str(sequence),
+            None => self.out.push_str(sequence),
+        }
+    }
+
+    /// Write a code block indented, highlighted if its language is known
+    fn write_code(&mut self, language: &str, code: &str) {
+        self.prefixes.push("    ".to_string());
+
+        let syntax = SYNTAXES
+            .find_syntax_by_token(language)
+            .filter(|_| !language.is_empty());
+        match syntax {
+            Some(syntax) => {
+                let mut highlighter = HighlightLines::new(syntax, &THEME);
+                for line in LinesWithEndings::from(code) {
+                    match highlighter.highlight_line(line, &SYNTAXES) {
+                        Ok(ranges) => {
+                            let escaped = as_24_bit_terminal_escaped(&ranges, false);
+                            self.write(&escaped.replace('\n', &format!("{}\n", RESET)));
+                        }
+                        Err(_) => self.write(line),
+                    }
+                }
+                self.write_escape(RESET);
+            }
+            None => {
+                self.push_style("33");
+                self.write(code);
+                self.pop_style();
+            }
+        }
+
+        self.prefixes.pop();
+    }
+
+    /// Write a collected table with box-drawing borders; the first row is the header
+    fn write_table(&mut self, table: &Table) {
+        let columns = table.rows.iter().map(Vec::len).max().unwrap_or(0);
+        let mut widths = vec![0; columns];
+        for row in &table.rows {
+            for (i, cell) in row.iter().enumerate() {
+                widths[i] = widths[i].max(visible_width(cell));
+            }
+        }
+
+        let border = |left: &str, middle: &str, right: &str| {
+            let segments: Vec<String> = widths.iter().map(|w| "─".repeat(w + 2)).collect();
+            format!("{}{}{}\n", left, segments.join(middle), right)
+        };
+
+        let mut lines = border("┌", "┬", "┐");
+        for (r, row) in table.rows.iter().enumerate() {
+            let mut line = String::from("│");
+            for (i, width) in widths.iter().enumerate() {
+                let cell = row.get(i).map(String::as_str).unwrap_or("");
+                let padding = width - visible_width(cell);
+                let (left, right) = match table.alignments.get(i) {
+                    Some(Alignment::Right) => (padding, 0),
+                    Some(Alignment::Center) => (padding / 2, padding - padding / 2),
+                    _ => (0, padding),
+                };
+                let cell = if r == 0 {
+                    format!("\x1b[1m{}{}", cell, RESET)
+                } else {
+                    cell.to_string()
+                };
+                line.push_str(&format!(
+                    " {}{}{} │",
+                    " ".repeat(left),
+                    cell,
+                    " ".repeat(right)
+                ));
+            }
+            lines.push_str(&line);
+            lines.push('\n');
+            if r == 0 && table.rows.len() > 1 {
+                lines.push_str(&border("├", "┼", "┤"));
+            }
+        }
+        lines.push_str(&border("└", "┴", "┘"));
+
+        self.write(lines.trim_end());
+    }
+}
+
+/// Number of characters a styled string takes up on screen
+fn visible_width(text: &str) -> usize {
+    ANSI_ESCAPE.replace_all(text, "").graphemes(true).count()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render_terminal() {
+        let markdown =
+            "# Title\n\nSome **bold** and *italic* with a [link](https://example.com).\n\n\
+                        - one\n- two\n\n\
+                        ```rust\nfn main() {}\n```\n\n\
+                        | Name | Size |\n|------|-----:|\n| a | 1 |\n| bb | 22 |\n";
+
+        let rendered = render_terminal(markdown);
+        let plain = ANSI_ESCAPE.replace_all(&rendered, "");
+
+        assert!(rendered.contains("\x1b[1;4;35mTitle"));
+        assert!(rendered.contains("\x1b[1mbold\x1b[0m"));
+        assert!(rendered.contains("\x1b[3mitalic\x1b[0m"));
+        assert!(plain.contains("link (https://example.com)"));
+        assert!(plain.contains("• one\n• two"));
+
+        // Code is highlighted with 24-bit colours and indented
+        assert!(rendered.contains("\x1b[38;2;"));
+        assert!(plain.contains("    fn main() {}"));
+
+        assert!(plain.contains("│ Name │ Size │"));
+        assert!(plain.contains("│ a    │    1 │"));
+        assert!(!plain.contains('#') && !plain.contains("**"));
+    }
+
+    #[test]
+    fn test_render_terminal_nested_blocks() {
+        let markdown = "> Quoted\n> text\n\n1. First\n2. Second\n   - nested\n";
+        let plain = ANSI_ESCAPE
+            .replace_all(&render_terminal(markdown), "")
+            .to_string();
+
+        assert_eq!(
+            plain,
+            "│ Quoted\n│ text\n\n1. First\n2. Second\n   • nested\n"
+        );
+    }
+}
@@ -0,0 +1,193 @@
+use reqwest::Url;
+
+use crate::models::Article;
+use crate::parsers::links::link_destinations;
+use crate::parsers::slugify;
+
+/// Per-platform UTM tagging settings (the campaign comes from each article)
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct UtmOptions {
+    /// Domains whose links are tagged; empty means the canonical URL's domain
+    pub domains: Vec<String>,
+
+    /// `utm_source` for the platform
+    pub source: String,
+
+    /// `utm_medium`
+    pub medium: String,
+}
+
+/// UTM parameters added to links back to the author's own site
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UtmParams {
+    /// `utm_source`, the platform the copy is on (`devto`, `medium`)
+    pub source: String,
+
+    /// `utm_medium`
+    pub medium: String,
+
+    /// `utm_campaign`, the article's slug
+    pub campaign: String,
+}
+
+/// Add UTM parameters to links pointing at one of `domains` (or their subdomains)
+///
+/// Inline links, autolinks and reference definitions are tagged; images, links in code
+/// and URLs that already carry a `utm_*` parameter are left alone. Returns the new
+/// content and the number of tagged links.
+pub fn add_utm_params(content: &str, domains: &[String], params: &UtmParams) -> (String, usize) {
+    let mut output = String::with_capacity(content.len());
+    let mut cursor = 0;
+    let mut tagged = 0;
+
+    for (range, url) in link_destinations(content, false) {
+        if !is_own_link(&url, domains) {
+            continue;
+        }
+        output.push_str(&content[cursor..range.start]);
+        output.push_str(&with_utm_params(&url, params));
+        cursor = range.end;
+        tagged += 1;
+    }
+    output.push_str(&content[cursor..]);
+
+    (output, tagged)
+}
+
+/// Campaign name for an article: its slug, else the last segment of its canonical URL,
+/// else its slugified title
+pub fn utm_campaign(article: &Article) -> String {
+    if let Some(ref slug) = article.slug {
+        return slug.clone();
+    }
+
+    let from_canonical = article
+        .canonical_url
+        .as_deref()
+        .and_then(|url| Url::parse(url).ok())
+        .and_then(|url| {
+            url.path_segments()?
+                .rev()
+                .find(|segment| !segment.is_empty())
+                .map(|segment| segment.trim_end_matches(".html").to_string())
+        });
+
+    from_canonical.unwrap_or_else(|| slugify(&article.title))
+}
+
+/// True for untagged http(s) URLs on one of the domains
+fn is_own_link(url: &str, domains: &[String]) -> bool {
+    let Ok(parsed) = Url::parse(url) else {
+        return false;
+    };
+    if !matches!(parsed.scheme(), "http" | "https")
+        || parsed.query_pairs().any(|(key, _)| key.starts_with("utm_"))
+    {
+        return false;
+    }
+
+    let Some(host) = parsed.host_str() else {
+        return false;
+    };
+    domains.iter().any(|domain| {
+        let domain = domain.trim().trim_start_matches("www.").to_lowercase();
+        let host = host.trim_start_matches("www.");
+        !domain.is_empty() && (host == domain || host.ends_with(&format!(".{}", domain)))
+    })
+}
+
+/// The URL with UTM parameters added to its query, before any `#fragment`
+///
+/// The rest of the URL is kept exactly as written.
+fn with_utm_params(url: &str, params: &UtmParams) -> String {
+    let mut query = Url::parse("https://localhost/").expect("valid URL");
+    query
+        .query_pairs_mut()
+        .append_pair("utm_source", &params.source)
+        .append_pair("utm_medium", &params.medium)
+        .append_pair("utm_campaign", &params.campaign);
+    let query = query.query().unwrap_or_default();
+
+    let (base, fragment) = match url.find('#') {
+        Some(hash) => url.split_at(hash),
+        None => (url, ""),
+    };
+    let separator = if !base.contains('?') {
+        "?"
+    } else if base.ends_with('?') || base.ends_with('&') {
+        ""
+    } else {
+        "&"
+    };
+
+    format!("{}{}{}{}", base, separator, query, fragment)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn params() -> UtmParams {
+        UtmParams {
+            source: "devto".to_string(),
+            medium: "referral".to_string(),
+            campaign: "rust-errors".to_string(),
+        }
+    }
+
+    #[test]
+    fn test_add_utm_params() {
+        let content = "Read [part one](https://blog.example.com/part-one/#setup), \
+                       [search](https://www.example.com/?q=rust \"Search\"), \
+                       [tagged](https://example.com/x?utm_source=rss), \
+                       [docs](https://doc.rust-lang.org/) and <https://example.com>.\n\n\
+                       ![Chart](https://example.com/chart.png)\n\n\
+                       ```\ncurl https://example.com/api\n```\n\n`[x](https://example.com/y)`\n\n\
+                       [ref]: https://example.com/ref\n";
+        let (tagged, count) = add_utm_params(content, &["example.com".to_string()], &params());
+
+        assert_eq!(count, 4);
+        assert_eq!(
+            tagged,
+            "Read [part one](https://blog.example.com/part-one/\
+             ?utm_source=devto&utm_medium=referral&utm_campaign=rust-errors#setup), \
+             [search](https://www.example.com/?q=rust\
+             &utm_source=devto&utm_medium=referral&utm_campaign=rust-errors \"Search\"), \
+             [tagged](https://example.com/x?utm_source=rss), \
+             [docs](https://doc.rust-lang.org/) and \
+             <https://example.com?utm_source=devto&utm_medium=referral&utm_campaign=rust-errors>.\n\n\
+             ![Chart](https://example.com/chart.png)\n\n\
+             ```\ncurl https://example.com/api\n```\n\n`[x](https://example.com/y)`\n\n\
+             [ref]: https://example.com/ref?utm_source=devto&utm_medium=referral&utm_campaign=rust-errors\n"
+        );
+    }
+
+    #[test]
+    fn test_utm_values_are_encoded() {
+        let params = UtmParams {
+            campaign: "spring sale&more".to_string(),
+            ..params()
+        };
+        assert_eq!(
+            with_utm_params("https://example.com/a?", &params),
+            "https://example.com/a?utm_source=devto&utm_medium=referral&utm_campaign=spring+sale%26more"
+        );
+        assert!(!is_own_link(
+            "https://notexample.com/",
+            &["example.com".to_string()]
+        ));
+    }
+
+    #[test]
+    fn test_utm_campaign() {
+        let article = Article::new("Rust Errors".to_string(), String::new());
+        assert_eq!(utm_campaign(&article), "rust-errors");
+
+        let article =
+            article.with_canonical_url("https://blog.example.com/posts/errors/".to_string());
+        assert_eq!(utm_campaign(&article), "errors");
+
+        let article = article.with_slug("rust-errors-explained".to_string());
+        assert_eq!(utm_campaign(&article), "rust-errors-explained");
+    }
+}
@@ -0,0 +1,377 @@
+use anyhow::{Context, Result};
+use serde::Serialize;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::cli::{Config, ContentFormat, Platform};
+use crate::parsers::{check_frontmatter, parse_markdown};
+use crate::platforms::{DevToClient, MediumClient};
+
+/// Severity of a validation issue
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Severity {
+    Error,
+    Warning,
+}
+
+/// A single problem found while validating an article
+#[derive(Debug, Clone, Serialize)]
+pub struct Issue {
+    /// Whether the issue blocks publishing
+    pub severity: Severity,
+
+    /// Platform the issue applies to (`None` for platform-independent issues)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub platform: Option<String>,
+
+    /// Human-readable description
+    pub message: String,
+}
+
+/// Validation result for one file
+#[derive(Debug, Clone, Serialize)]
+pub struct FileReport {
+    /// Path of the validated file
+    pub path: String,
+
+    /// Issues found, in the order they were detected
+    pub issues: Vec<Issue>,
+}
+
+/// Validation result for a set of files
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct ValidationReport {
+    /// Per-file results
+    pub files: Vec<FileReport>,
+
+    /// Total number of errors across all files
+    pub error_count: usize,
+
+    /// Total number of warnings across all files
+    pub warning_count: usize,
+}
+
+impl FileReport {
+    /// Number of issues with the given severity
+    pub fn count(&self, severity: Severity) -> usize {
+        self.issues
+            .iter()
+            .filter(|issue| issue.severity == severity)
+            .count()
+    }
+}
+
+impl ValidationReport {
+    /// Add a file report and update the totals
+    pub fn push(&mut self, report: FileReport) {
+        self.error_count += report.count(Severity::Error);
+        self.warning_count += report.count(Severity::Warning);
+        self.files.push(report);
+    }
+
+    /// True if no file has errors
+    pub fn is_ok(&self) -> bool {
+        self.error_count == 0
+    }
+}
+
+/// Validate a markdown file, or every markdown file under a directory
+///
+/// Runs frontmatter parsing and each platform's full request preparation
+/// (sanitization, tag handling, format conversion) without any network access.
+pub fn validate_path(
+    path: &Path,
+    platforms: &[Platform],
+    format: &ContentFormat,
+    config: &Config,
+) -> Result<ValidationReport> {
+    let files = if path.is_dir() {
+        collect_markdown_files(path)?
+    } else {
+        vec![path.to_path_buf()]
+    };
+
+    let mut report = ValidationReport::default();
+    for file in files {
+        let label = file.display().to_string();
+        let file_report = match fs::read_to_string(&file) {
+            Ok(content) => validate_content(&label, &content, platforms, format, config),
+            Err(e) => FileReport {
+                path: label,
+                issues: vec![Issue {
+                    severity: Severity::Error,
+                    platform: None,
+                    message: format!("Failed to read file: {}", e),
+                }],
+            },
+        };
+        report.push(file_report);
+    }
+
+    Ok(report)
+}
+
+/// Validate markdown content as if it were about to be posted to the given platforms
+pub fn validate_content(
+    path: &str,
+    content: &str,
+    platforms: &[Platform],
+    format: &ContentFormat,
+    config: &Config,
+) -> FileReport {
+    let mut issues = Vec::new();
+
+    match parse_markdown(content) {
+        Ok(mut article) => {
+            if let Some(dir) = Path::new(path).parent() {
+                article.resolve_cover_path(dir);
+            }
+
+            if article.canonical_url.is_none() {
+                match config.canonical.derive_url(&article, Some(Path::new(path))) {
+                    Ok(url) => article.canonical_url = url,
+                    Err(e) => issues.push(Issue {
+                        severity: Severity::Error,
+                        platform: None,
+                        message: format!("{:#}", e),
+                    }),
+                }
+            }
+            config.apply_medium_defaults(&mut article);
+
+            for platform in platforms {
+                let options = config.sanitize_options(platform);
+                let prepared = match platform {
+                    Platform::DevTo => {
+                        DevToClient::build_publish_request(&article, &options).map(|p| p.warnings)
+                    }
+                    Platform::Medium => {
+                        MediumClient::build_publish_request(&article, format, &options)
+                            .map(|p| p.warnings)
+                    }
+                };
+
+                match prepared {
+                    Ok(warnings) => {
+                        issues.extend(warnings.into_iter().map(|message| Issue {
+                            severity: Severity::Warning,
+                            platform: Some(platform.id().to_string()),
+                            message,
+                        }));
+                    }
+                    Err(e) => issues.push(Issue {
+                        severity: Severity::Error,
+                        platform: Some(platform.id().to_string()),
+                        message: format!("{:#}", e),
+                    }),
+                }
+            }
+        }
+        Err(e) => {
+            // Report each frontmatter problem on its own, with its line
+            let frontmatter_issues = check_frontmatter(content);
+            if frontmatter_issues.is_empty() {
+                issues.push(Issue {
+                    severity: Severity::Error,
+                    platform: None,
+                    message: format!("{:#}", e),
+                });
+            }
+            issues.extend(frontmatter_issues.into_iter().map(|issue| Issue {
+                severity: Severity::Error,
+                platform: None,
+                message: format!("Invalid frontmatter at {}", issue),
+            }));
+        }
+    }
+
+    FileReport {
+        path: path.to_string(),
+        issues,
+    }
+}
+
+/// Recursively collect markdown files (`.md`, `.markdown`) under a directory, sorted by path
+///
+/// Hidden files and directories (names starting with `.`) are skipped.
+pub fn collect_markdown_files(dir: &Path) -> Result<Vec<PathBuf>> {
+    let mut files = Vec::new();
+    let mut pending = vec![dir.to_path_buf()];
+
+    while let Some(current) = pending.pop() {
+        let entries = fs::read_dir(&current)
+            .context(format!("Failed to read directory: {}", current.display()))?;
+
+        for entry in entries {
+            let path = entry?.path();
+            let is_hidden = path
+                .file_name()
+                .and_then(|name| name.to_str())
+                .is_some_and(|name| name.starts_with('.'));
+
+            if is_hidden {
+                continue;
+            }
+
+            if path.is_dir() {
+                pending.push(path);
+            } else if path
+                .extension()
+                .and_then(|ext| ext.to_str())
+                .is_some_and(|ext| ext == "md" || ext == "markdown")
+            {
+                files.push(path);
+            }
+        }
+    }
+
+    files.sort();
+    Ok(files)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    const ALL_PLATFORMS: [Platform; 2] = [Platform::DevTo, Platform::Medium];
+
+    #[test]
+    fn test_validate_valid_article() {
+        let content = "---\ntitle: Valid\ntags: [rust]\n---\n\nBody.";
+        let report = validate_content(
+            "a.md",
+            content,
+            &ALL_PLATFORMS,
+            &ContentFormat::Markdown,
+            &Config::default(),
+        );
+
+        assert!(report.issues.is_empty());
+    }
+
+    #[test]
+    fn test_validate_reports_parse_error() {
+        let content = "---\ntitle: Broken: title\n---\n\nBody.";
+        let report = validate_content(
+            "a.md",
+            content,
+            &ALL_PLATFORMS,
+            &ContentFormat::Markdown,
+            &Config::default(),
+        );
+
+        assert_eq!(report.count(Severity::Error), 1);
+        assert!(report.issues[0].platform.is_none());
+    }
+
+    #[test]
+    fn test_validate_reports_platform_errors_and_warnings() {
+        let content = "---\ntitle: Tags\ntags: [a, b, c, d, e-f]\n---\n\nBody.";
+        let report = validate_content(
+            "a.md",
+            content,
+            &ALL_PLATFORMS,
+            &ContentFormat::Markdown,
+            &Config::default(),
+        );
+
+        // dev.to rejects 5 tags, Medium accepts them
+        assert_eq!(report.count(Severity::Error), 1);
+        assert_eq!(report.issues[0].platform.as_deref(), Some("devto"));
+        assert_eq!(report.count(Severity::Warning), 0);
+    }
+
+    #[test]
+    fn test_validate_report_totals_and_json() {
+        let mut report = ValidationReport::default();
+        report.push(validate_content(
+            "bad.md",
+            "no frontmatter",
+            &ALL_PLATFORMS,
+            &ContentFormat::Markdown,
+            &Config::default(),
+        ));
+        report.push(validate_content(
+            "tags.md",
+            "---\ntitle: T\ntags: [web-dev]\n---\n\nBody.",
+            &[Platform::DevTo],
+            &ContentFormat::Markdown,
+            &Config::default(),
+        ));
+
+        assert!(!report.is_ok());
+        assert_eq!(report.error_count, 1);
+        assert_eq!(report.warning_count, 1);
+
+        let json = serde_json::to_value(&report).unwrap();
+        assert_eq!(json["files"][1]["issues"][0]["severity"], "warning");
+        assert_eq!(json["files"][1]["issues"][0]["platform"], "devto");
+    }
+
+    #[test]
+    fn test_validate_local_cover_image() {
+        let dir = TempDir::new().unwrap();
+        fs::write(dir.path().join("cover.png"), b"png").unwrap();
+        fs::write(
+            dir.path().join("ok.md"),
+            "---\ntitle: A\ncover_image: cover.png\n---\n\nBody.",
+        )
+        .unwrap();
+        fs::write(
+            dir.path().join("missing.md"),
+            "---\ntitle: B\ncover_image: nope.png\n---\n\nBody.",
+        )
+        .unwrap();
+
+        let report = validate_path(
+            dir.path(),
+            &[Platform::DevTo],
+            &ContentFormat::Markdown,
+            &Config::default(),
+        )
+        .unwrap();
+
+        assert_eq!(report.files.len(), 2);
+        assert!(report.files[0].path.ends_with("missing.md"));
+        assert_eq!(report.files[0].count(Severity::Error), 1);
+        assert!(report.files[1].issues.is_empty());
+    }
+
+    #[test]
+    fn test_validate_reports_invalid_canonical_base_url() {
+        let mut config = Config::default();
+        config.canonical.base_url = Some("myblog.dev/posts".to_string());
+
+        let report = validate_content(
+            "post.md",
+            "---\ntitle: A\n---\n\nBody.",
+            &[Platform::DevTo],
+            &ContentFormat::Markdown,
+            &config,
+        );
+
+        assert_eq!(report.count(Severity::Error), 1);
+        assert!(report.issues[0].message.contains("base_url"));
+    }
+
+    #[test]
+    fn test_collect_markdown_files() {
+        let dir = TempDir::new().unwrap();
+        fs::create_dir_all(dir.path().join("nested")).unwrap();
+        fs::create_dir_all(dir.path().join(".git")).unwrap();
+        fs::write(dir.path().join("b.md"), "").unwrap();
+        fs::write(dir.path().join("nested/a.markdown"), "").unwrap();
+        fs::write(dir.path().join("notes.txt"), "").unwrap();
+        fs::write(dir.path().join(".git/ignored.md"), "").unwrap();
+
+        let files = collect_markdown_files(dir.path()).unwrap();
+        let names: Vec<_> = files
+            .iter()
+            .map(|f| f.strip_prefix(dir.path()).unwrap().display().to_string())
+            .collect();
+
+        assert_eq!(names, vec!["b.md", "nested/a.markdown"]);
+    }
+}
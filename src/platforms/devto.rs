@@ -1,12 +1,31 @@
 use anyhow::{Context, Result};
+use chrono::{DateTime, SecondsFormat, Utc};
 use reqwest::Client;
 use serde::{Deserialize, Serialize};
+use std::path::Path;
 
-use crate::models::{Article, ArticleSummary};
-use crate::parsers::sanitizer::{sanitize_for_platform, Platform as SanitizerPlatform};
+use crate::cli::DevToConfig;
+use crate::models::{is_local_path, Article, ArticleSummary, Asset, Syndication};
+use crate::parsers::sanitizer::{
+    sanitize_for_platform, Platform as SanitizerPlatform, SanitizeOptions,
+};
+use crate::parsers::{parse_markdown, DEVTO_LIMITS};
+use crate::platforms::rate_limit::{
+    CreateCheck, CreateResponse, CREATE_CLOCK_TOLERANCE_SECS, DEVTO_RATE_LIMIT,
+};
+use crate::platforms::{
+    http_client, Engagement, ImportedArticle, PreparedRequest, PreviewRequest, PublishedArticle,
+    RequestPreview, ResolveAssets,
+};
 
 /// Maximum number of tags allowed by dev.to
-const DEVTO_MAX_TAGS: usize = 4;
+const DEVTO_MAX_TAGS: usize = DEVTO_LIMITS.max_tags;
+
+/// Page size used when scanning all of the user's articles (dev.to maximum is 1000)
+const DEVTO_SCAN_PAGE_SIZE: u32 = 1000;
+
+/// dev.to API base URL
+pub const DEVTO_API_URL: &str = "https://dev.to/api";
 
 /// dev.to API client
 pub struct DevToClient {
@@ -24,6 +43,99 @@ struct DevToListArticleResponse {
     published_at: Option<String>,
     #[serde(default)]
     tag_list: Vec<String>,
+    canonical_url: Option<String>,
+    #[serde(default)]
+    body_markdown: String,
+    cover_image: Option<String>,
+    description: Option<String>,
+    #[serde(default)]
+    published: bool,
+    #[serde(default)]
+    public_reactions_count: u64,
+    #[serde(default)]
+    comments_count: u64,
+}
+
+/// A comment from dev.to GET /api/comments, with its replies
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DevToComment {
+    /// Comment ID, as used in comment URLs
+    pub id_code: String,
+
+    /// When the comment was written (ISO 8601)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<String>,
+
+    /// Comment body as HTML (`[deleted]` for deleted comments)
+    #[serde(default)]
+    pub body_html: String,
+
+    /// Author, missing for deleted comments
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub user: Option<DevToCommentUser>,
+
+    /// Replies, oldest first
+    #[serde(default)]
+    pub children: Vec<DevToComment>,
+}
+
+/// Author of a dev.to comment
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DevToCommentUser {
+    #[serde(default)]
+    pub name: String,
+    pub username: String,
+}
+
+impl DevToListArticleResponse {
+    /// Convert to an importable article
+    ///
+    /// Articles written in dev.to's v1 editor keep their own frontmatter in the body;
+    /// it is dropped, since the importer writes fresh frontmatter.
+    fn into_imported(self) -> ImportedArticle {
+        let content = if self.body_markdown.trim_start().starts_with("---") {
+            parse_markdown(&self.body_markdown)
+                .map(|parsed| parsed.content)
+                .unwrap_or(self.body_markdown)
+        } else {
+            self.body_markdown
+        };
+
+        let article = Article::new(self.title, content)
+            .with_tags(self.tag_list)
+            .with_published(self.published)
+            .with_syndication(Syndication {
+                devto_url: Some(self.url),
+                devto_id: Some(self.id.to_string()),
+                ..Default::default()
+            });
+
+        ImportedArticle {
+            article: Article {
+                canonical_url: self.canonical_url,
+                cover_image: self.cover_image,
+                description: self.description,
+                ..article
+            },
+            published_at: self.published_at,
+            engagement: Some(Engagement {
+                reactions: self.public_reactions_count,
+                comments: self.comments_count,
+            }),
+        }
+    }
+}
+
+impl From<DevToListArticleResponse> for ArticleSummary {
+    fn from(a: DevToListArticleResponse) -> Self {
+        ArticleSummary {
+            id: a.id.to_string(),
+            title: a.title,
+            url: a.url,
+            published_at: a.published_at.unwrap_or_default(),
+            tags: a.tag_list,
+        }
+    }
 }
 
 /// Response from dev.to GET /api/articles/{id}
@@ -39,9 +151,24 @@ struct DevToArticleResponse {
     published: bool,
 }
 
+/// A raw dev.to article response with the validators needed to re-fetch it conditionally
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CachedArticleResponse {
+    /// `ETag` header of the response
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub etag: Option<String>,
+
+    /// `Last-Modified` header of the response
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_modified: Option<String>,
+
+    /// Response body (article JSON)
+    pub body: String,
+}
+
 /// Request body for dev.to POST /api/articles
 #[derive(Debug, Serialize)]
-struct DevToPublishRequest {
+pub struct DevToPublishRequest {
     article: DevToArticleData,
 }
 
@@ -61,18 +188,74 @@ struct DevToArticleData {
     description: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     series: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    published_at: Option<String>,
+}
+
+impl ResolveAssets for DevToPublishRequest {
+    fn resolve_asset(&mut self, placeholder: &str, url: &str) {
+        let data = &mut self.article;
+        if data.main_image.as_deref() == Some(placeholder) {
+            data.main_image = Some(url.to_string());
+        }
+        data.body_markdown = data.body_markdown.replace(placeholder, url);
+    }
+}
+
+impl PreviewRequest for DevToPublishRequest {
+    fn preview(&self) -> RequestPreview {
+        let data = &self.article;
+        let mut fields = vec![("Title", data.title.clone())];
+        if !data.tags.is_empty() {
+            fields.push(("Tags", data.tags.join(", ")));
+        }
+        let optional = [
+            ("Canonical URL", &data.canonical_url),
+            ("Cover Image", &data.main_image),
+            ("Description", &data.description),
+            ("Series", &data.series),
+            ("Published At", &data.published_at),
+        ];
+        for (label, value) in optional {
+            if let Some(value) = value {
+                fields.push((label, value.clone()));
+            }
+        }
+        fields.push(("Published", data.published.to_string()));
+
+        RequestPreview {
+            fields,
+            format: "markdown".to_string(),
+            body: data.body_markdown.clone(),
+        }
+    }
 }
 
 impl DevToClient {
     /// Create a new dev.to client
     pub fn new(api_key: String) -> Self {
         Self {
-            client: Client::new(),
+            client: http_client(),
             api_key,
-            base_url: "https://dev.to/api".to_string(),
+            base_url: DEVTO_API_URL.to_string(),
         }
     }
 
+    /// Create a client from the `[dev_to]` config section
+    pub fn from_config(config: &DevToConfig) -> Self {
+        let client = Self::new(config.api_key.clone());
+        match config.base_url {
+            Some(ref base_url) => client.with_base_url(base_url),
+            None => client,
+        }
+    }
+
+    /// Send requests to another API base URL (a Forem instance, or a mock server in tests)
+    pub fn with_base_url(mut self, base_url: impl Into<String>) -> Self {
+        self.base_url = base_url.into().trim_end_matches('/').to_string();
+        self
+    }
+
     /// List articles from dev.to
     pub async fn list_articles(
         &self,
@@ -80,6 +263,127 @@ impl DevToClient {
         per_page: u32,
         state: &str,
     ) -> Result<Vec<ArticleSummary>> {
+        let articles = self.fetch_article_page(page, per_page, state).await?;
+
+        Ok(articles.into_iter().map(ArticleSummary::from).collect())
+    }
+
+    /// Find an existing article (published or draft) that duplicates the given one
+    ///
+    /// Pages through `/articles/me/all` and matches on title or canonical URL.
+    pub async fn find_existing_article(&self, article: &Article) -> Result<Option<ArticleSummary>> {
+        let mut page = 1;
+
+        loop {
+            let articles = self
+                .fetch_article_page(page, DEVTO_SCAN_PAGE_SIZE, "all")
+                .await?;
+            let page_len = articles.len();
+
+            if let Some(existing) = articles
+                .into_iter()
+                .find(|a| article.is_duplicate_of(&a.title, a.canonical_url.as_deref()))
+            {
+                return Ok(Some(existing.into()));
+            }
+
+            if page_len < DEVTO_SCAN_PAGE_SIZE as usize {
+                return Ok(None);
+            }
+            page += 1;
+        }
+    }
+
+    /// Download all of the user's articles (published and drafts) with their markdown
+    pub async fn import_articles(&self) -> Result<Vec<ImportedArticle>> {
+        let mut imported = Vec::new();
+        let mut page = 1;
+
+        loop {
+            let articles = self
+                .fetch_article_page(page, DEVTO_SCAN_PAGE_SIZE, "all")
+                .await?;
+            let page_len = articles.len();
+
+            imported.extend(articles.into_iter().map(|a| a.into_imported()));
+
+            if page_len < DEVTO_SCAN_PAGE_SIZE as usize {
+                return Ok(imported);
+            }
+            page += 1;
+        }
+    }
+
+    /// Fetch the comments on a published article, threaded
+    ///
+    /// Uses the public `GET /comments?a_id=` endpoint, which returns every top-level
+    /// comment with its replies nested in `children`.
+    pub async fn fetch_comments(&self, article_id: &str) -> Result<Vec<DevToComment>> {
+        let url = format!("{}/comments", self.base_url);
+
+        let response = DEVTO_RATE_LIMIT
+            .send(
+                self.client
+                    .get(&url)
+                    .header("api-key", &self.api_key)
+                    .header("Accept", "application/vnd.forem.api-v1+json")
+                    .header("User-Agent", "article-cross-poster/0.1.0")
+                    .query(&[("a_id", article_id)]),
+            )
+            .await
+            .context("Failed to send comments request to dev.to API")?;
+
+        if !response.status().is_success() {
+            let status = response.status();
+            let error_text = response.text().await.unwrap_or_default();
+            anyhow::bail!("dev.to API error (status {}): {}", status, error_text);
+        }
+
+        response
+            .json()
+            .await
+            .context("Failed to parse dev.to comments response")
+    }
+
+    /// Fetch one of the user's unpublished articles (drafts) by ID
+    ///
+    /// `GET /articles/{id}` only returns published articles, so this pages through
+    /// `/articles/me/unpublished` instead. The returned article records the draft's
+    /// dev.to ID and URL in its syndication fields.
+    pub async fn fetch_draft(&self, article_id: &str) -> Result<Article> {
+        let mut page = 1;
+
+        loop {
+            let articles = self
+                .fetch_article_page(page, DEVTO_SCAN_PAGE_SIZE, "unpublished")
+                .await?;
+            let page_len = articles.len();
+
+            if let Some(draft) = articles
+                .into_iter()
+                .find(|a| a.id.to_string() == article_id)
+            {
+                return Ok(draft.into_imported().article);
+            }
+
+            if page_len < DEVTO_SCAN_PAGE_SIZE as usize {
+                anyhow::bail!(
+                    "No dev.to draft with ID {} - run `list --from devto --state unpublished` \
+                     to see your drafts",
+                    article_id
+                );
+            }
+            page += 1;
+        }
+    }
+
+    /// Fetch one page of the user's articles from a `/articles/me/*` endpoint
+    async fn fetch_article_page(
+        &self,
+        page: u32,
+        per_page: u32,
+        state: &str,
+    ) -> Result<Vec<DevToListArticleResponse>> {
         let endpoint = match state {
             "unpublished" => "articles/me/unpublished",
             "all" => "articles/me/all",
@@ -87,17 +391,18 @@ impl DevToClient {
         };
         let url = format!("{}/{}", self.base_url, endpoint);
 
-        let response = self
-            .client
-            .get(&url)
-            .header("api-key", &self.api_key)
-            .header("Accept", "application/vnd.forem.api-v1+json")
-            .header("User-Agent", "article-cross-poster/0.1.0")
-            .query(&[
-                ("page", page.to_string()),
-                ("per_page", per_page.to_string()),
-            ])
-            .send()
+        let response = DEVTO_RATE_LIMIT
+            .send(
+                self.client
+                    .get(&url)
+                    .header("api-key", &self.api_key)
+                    .header("Accept", "application/vnd.forem.api-v1+json")
+                    .header("User-Agent", "article-cross-poster/0.1.0")
+                    .query(&[
+                        ("page", page.to_string()),
+                        ("per_page", per_page.to_string()),
+                    ]),
+            )
             .await
             .context("Failed to send list request to dev.to API")?;
 
@@ -107,47 +412,87 @@ impl DevToClient {
             anyhow::bail!("dev.to API error (status {}): {}", status, error_text);
         }
 
-        let articles: Vec<DevToListArticleResponse> = response
+        response
             .json()
             .await
-            .context("Failed to parse dev.to article list response")?;
-
-        Ok(articles
-            .into_iter()
-            .map(|a| ArticleSummary {
-                id: a.id.to_string(),
-                title: a.title,
-                url: a.url,
-                published_at: a.published_at.unwrap_or_default(),
-                tags: a.tag_list,
-            })
-            .collect())
+            .context("Failed to parse dev.to article list response")
     }
 
     /// Fetch an article from dev.to by ID
     pub async fn fetch_article(&self, article_id: &str) -> Result<Article> {
+        let response = self.fetch_article_response(article_id, None).await?;
+        Self::parse_article_response(&response.body)
+    }
+
+    /// Fetch the raw article response, revalidating a cached copy
+    ///
+    /// With a cached copy, `If-None-Match` / `If-Modified-Since` are sent and the
+    /// cached copy is returned as-is when dev.to answers 304 Not Modified.
+    pub async fn fetch_article_response(
+        &self,
+        article_id: &str,
+        cached: Option<&CachedArticleResponse>,
+    ) -> Result<CachedArticleResponse> {
         let url = format!("{}/articles/{}", self.base_url, article_id);
 
-        let response = self
+        let mut request = self
             .client
             .get(&url)
             .header("api-key", &self.api_key)
             .header("Accept", "application/vnd.forem.api-v1+json")
-            .header("User-Agent", "article-cross-poster/0.1.0")
-            .send()
+            .header("User-Agent", "article-cross-poster/0.1.0");
+        if let Some(cached) = cached {
+            if let Some(ref etag) = cached.etag {
+                request = request.header("If-None-Match", etag);
+            }
+            if let Some(ref last_modified) = cached.last_modified {
+                request = request.header("If-Modified-Since", last_modified);
+            }
+        }
+
+        let response = DEVTO_RATE_LIMIT
+            .send(request)
             .await
             .context("Failed to send request to dev.to API")?;
 
+        if response.status() == reqwest::StatusCode::NOT_MODIFIED {
+            if let Some(cached) = cached {
+                return Ok(cached.clone());
+            }
+        }
+
         if !response.status().is_success() {
             let status = response.status();
             let error_text = response.text().await.unwrap_or_default();
             anyhow::bail!("dev.to API error (status {}): {}", status, error_text);
         }
 
-        let devto_article: DevToArticleResponse = response
-            .json()
+        let header = |name: &str| {
+            response
+                .headers()
+                .get(name)
+                .and_then(|value| value.to_str().ok())
+                .map(str::to_string)
+        };
+        let etag = header("etag");
+        let last_modified = header("last-modified");
+
+        let body = response
+            .text()
             .await
-            .context("Failed to parse dev.to article response")?;
+            .context("Failed to read dev.to article response")?;
+
+        Ok(CachedArticleResponse {
+            etag,
+            last_modified,
+            body,
+        })
+    }
+
+    /// Parse an article from a dev.to article response body
+    pub fn parse_article_response(body: &str) -> Result<Article> {
+        let devto_article: DevToArticleResponse =
+            serde_json::from_str(body).context("Failed to parse dev.to article response")?;
 
         Ok(Article {
             title: devto_article.title,
@@ -157,17 +502,33 @@ impl DevToClient {
             published: devto_article.published,
             cover_image: devto_article.cover_image,
             description: devto_article.description,
+            slug: None,
+            syndication: Default::default(),
+            series: None,
+            series_part: None,
+            medium_license: None,
+            notify_followers: None,
+            platforms: Vec::new(),
+            exclude_platforms: Vec::new(),
+            published_at: None,
+            lang: None,
         })
     }
 
-    /// Publish an article to dev.to
-    pub async fn publish_article(&self, article: &Article) -> Result<String> {
-        let url = format!("{}/articles", self.base_url);
-
+    /// Build the dev.to publish request for an article
+    ///
+    /// Applies the same sanitization and tag truncation as `publish_article`,
+    /// without touching the network. A local `cover_image` file is returned as an
+    /// asset to upload, with a placeholder in `main_image`.
+    pub fn build_publish_request(
+        article: &Article,
+        options: &SanitizeOptions,
+    ) -> Result<PreparedRequest<DevToPublishRequest>> {
         // Clone article and sanitize for dev.to (fixes tag format, validates content, etc.)
         let mut sanitized_article = article.clone();
-        sanitize_for_platform(&mut sanitized_article, SanitizerPlatform::DevTo)
-            .context("Failed to sanitize article for dev.to")?;
+        let mut warnings =
+            sanitize_for_platform(&mut sanitized_article, SanitizerPlatform::DevTo, options)
+                .context("Failed to sanitize article for dev.to")?;
 
         // dev.to has a max of 4 tags - warn if truncating
         let tags: Vec<String> = sanitized_article
@@ -176,47 +537,217 @@ impl DevToClient {
             .take(DEVTO_MAX_TAGS)
             .cloned()
             .collect();
-        let tags_str = tags.join(", "); // Save before moving
-        let tags_len = tags.len();
 
         if sanitized_article.tags.len() > DEVTO_MAX_TAGS {
-            eprintln!(
-                "⚠️  Warning: dev.to only supports {} tags. Truncating from {} to {} tags.",
+            warnings.push(format!(
+                "dev.to only supports {} tags. Truncating from {} to {} tags (excluded: {})",
                 DEVTO_MAX_TAGS,
                 sanitized_article.tags.len(),
-                DEVTO_MAX_TAGS
-            );
-            eprintln!("   Included: {}", tags_str);
-            eprintln!(
-                "   Excluded: {}",
+                DEVTO_MAX_TAGS,
                 sanitized_article.tags[DEVTO_MAX_TAGS..].join(", ")
-            );
+            ));
         }
 
-        let request_body = DevToPublishRequest {
+        // dev.to needs a hosted cover image URL
+        let mut assets = Vec::new();
+        let main_image = match sanitized_article.cover_image {
+            Some(cover) if is_local_path(&cover) => {
+                let asset = Asset::from_image_file(Path::new(&cover), "cover")
+                    .context("Failed to load local cover image")?;
+                let placeholder = asset.placeholder();
+                assets.push(asset);
+                Some(placeholder)
+            }
+            cover => cover,
+        };
+
+        // Backdating only applies to published articles
+        let published_at = article
+            .backdate()
+            .filter(|_| article.published)
+            .map(|date| date.to_rfc3339_opts(SecondsFormat::Secs, true));
+
+        let request = DevToPublishRequest {
             article: DevToArticleData {
-                title: sanitized_article.title.clone(),
-                body_markdown: sanitized_article.content.clone(),
+                title: sanitized_article.title,
+                body_markdown: sanitized_article.content,
                 published: sanitized_article.published,
                 tags,
-                canonical_url: sanitized_article.canonical_url.clone(),
-                main_image: sanitized_article.cover_image.clone(),
-                description: sanitized_article.description.clone(),
-                series: None,
+                canonical_url: sanitized_article.canonical_url,
+                main_image,
+                description: sanitized_article.description,
+                series: sanitized_article.series,
+                published_at,
             },
         };
 
-        let response = self
-            .client
-            .post(&url)
+        Ok(PreparedRequest {
+            request,
+            warnings,
+            assets,
+        })
+    }
+
+    /// Publish an article to dev.to
+    ///
+    /// dev.to has no image upload API, so articles with a local cover image must be
+    /// prepared with `build_publish_request` and have their assets uploaded first.
+    pub async fn publish_article(&self, article: &Article) -> Result<String> {
+        let prepared = Self::build_publish_request(article, &SanitizeOptions::default())?;
+        prepared.print_warnings();
+
+        if !prepared.assets.is_empty() {
+            anyhow::bail!("Local images must be uploaded through an image backend before publishing to dev.to");
+        }
+
+        Ok(self.publish_request(&prepared.request).await?.url)
+    }
+
+    /// Send a prepared publish request to dev.to
+    ///
+    /// If the request fails in transit, it is only retried once the article is
+    /// confirmed not to exist (see `RateLimiter::send_create`).
+    pub async fn publish_request(
+        &self,
+        request_body: &DevToPublishRequest,
+    ) -> Result<PublishedArticle> {
+        let url = format!("{}/articles", self.base_url);
+        self.send_article(self.client.post(&url), request_body, true)
+            .await
+    }
+
+    /// Look for the article a failed create request may have made
+    ///
+    /// Only an article with exactly the request's title that was published after the
+    /// request was sent (`sent_at`) counts; an older copy, e.g. the original of a
+    /// translation or an article posted again with `--force`, is not the new one. A
+    /// backdated article must carry the requested date instead. Drafts have no date,
+    /// so a draft request with a same-titled draft on dev.to can't be verified.
+    async fn check_created(
+        &self,
+        data: &DevToArticleData,
+        sent_at: DateTime<Utc>,
+    ) -> Result<CreateCheck<PublishedArticle>> {
+        let parse_date = |date: Option<&str>| {
+            date.and_then(|date| DateTime::parse_from_rfc3339(date).ok())
+                .map(|date| date.with_timezone(&Utc))
+        };
+        let backdate = parse_date(data.published_at.as_deref());
+        let earliest = sent_at - chrono::Duration::seconds(CREATE_CLOCK_TOLERANCE_SECS);
+
+        let mut unverifiable = false;
+        let mut page = 1;
+        loop {
+            let articles = self
+                .fetch_article_page(page, DEVTO_SCAN_PAGE_SIZE, "all")
+                .await?;
+            let page_len = articles.len();
+
+            for existing in articles.into_iter().filter(|a| a.title == data.title) {
+                if !data.published {
+                    unverifiable |= !existing.published;
+                    continue;
+                }
+                let created = match (parse_date(existing.published_at.as_deref()), backdate) {
+                    (Some(date), Some(backdate)) => date == backdate,
+                    (Some(date), None) => date >= earliest,
+                    (None, _) => false,
+                };
+                if existing.published && created {
+                    return Ok(CreateCheck::Created(PublishedArticle {
+                        id: existing.id.to_string(),
+                        url: existing.url,
+                    }));
+                }
+            }
+
+            if page_len < DEVTO_SCAN_PAGE_SIZE as usize {
+                return Ok(if unverifiable {
+                    CreateCheck::Unknown
+                } else {
+                    CreateCheck::NotCreated
+                });
+            }
+            page += 1;
+        }
+    }
+
+    /// Replace the content of an existing dev.to article with a prepared request
+    pub async fn update_article(
+        &self,
+        article_id: &str,
+        request_body: &DevToPublishRequest,
+    ) -> Result<PublishedArticle> {
+        let url = format!("{}/articles/{}", self.base_url, article_id);
+        self.send_article(self.client.put(&url), request_body, false)
+            .await
+            .context(format!("Failed to update dev.to article {}", article_id))
+    }
+
+    /// Publish an existing dev.to article, or turn it back into a draft
+    pub async fn set_published(&self, article_id: &str, published: bool) -> Result<()> {
+        let url = format!("{}/articles/{}", self.base_url, article_id);
+        let body = serde_json::json!({ "article": { "published": published } });
+
+        let response = DEVTO_RATE_LIMIT
+            .send(
+                self.client
+                    .put(&url)
+                    .header("api-key", &self.api_key)
+                    .header("Accept", "application/vnd.forem.api-v1+json")
+                    .header("User-Agent", "article-cross-poster/0.1.0")
+                    .json(&body),
+            )
+            .await
+            .context("Failed to send article update to dev.to API")?;
+
+        if !response.status().is_success() {
+            let status = response.status();
+            let error_text = response.text().await.unwrap_or_default();
+            anyhow::bail!(
+                "Failed to change publication status of dev.to article {} (status {}): {}",
+                article_id,
+                status,
+                error_text
+            );
+        }
+
+        Ok(())
+    }
+
+    /// Send an article create (`create`) or update request and parse the resulting article
+    async fn send_article(
+        &self,
+        request: reqwest::RequestBuilder,
+        request_body: &DevToPublishRequest,
+        create: bool,
+    ) -> Result<PublishedArticle> {
+        let data = &request_body.article;
+        let tags_str = data.tags.join(", ");
+
+        let request = request
             .header("api-key", &self.api_key)
             .header("Accept", "application/vnd.forem.api-v1+json")
             .header("Content-Type", "application/json")
             .header("User-Agent", "article-cross-poster/0.1.0")
-            .json(&request_body)
-            .send()
-            .await
-            .context("Failed to send publish request to dev.to API")?;
+            .json(request_body);
+
+        let response = if create {
+            let sent_at = Utc::now();
+            let sent = DEVTO_RATE_LIMIT
+                .send_create(request, || self.check_created(data, sent_at))
+                .await
+                .context("Failed to send article request to dev.to API")?;
+            match sent {
+                CreateResponse::Response(response) => response,
+                CreateResponse::AlreadyCreated(existing) => return Ok(existing),
+            }
+        } else {
+            DEVTO_RATE_LIMIT
+                .send(request)
+                .await
+                .context("Failed to send article request to dev.to API")?
+        };
 
         if !response.status().is_success() {
             let status = response.status();
@@ -228,7 +759,9 @@ impl DevToClient {
             } else if status == 403 {
                 "Access forbidden - API key may lack write permissions or article creation rights"
             } else if status == 429 {
-                "Rate limit exceeded - please try again later"
+                "Rate limit exceeded (still limited after retrying) - please try again later"
+            } else if status == 404 {
+                "Article not found - it may have been deleted on dev.to"
             } else if status == 422 {
                 "Article validation failed - check title, content, and tags"
             } else {
@@ -253,16 +786,17 @@ impl DevToClient {
                 } else {
                     &error_text
                 },
-                sanitized_article.title,
-                tags_len,
+                data.title,
+                data.tags.len(),
                 tags_str,
-                sanitized_article.content.len(),
-                sanitized_article.published
+                data.body_markdown.len(),
+                data.published
             );
         }
 
         #[derive(Deserialize)]
         struct PublishResponse {
+            id: u64,
             url: String,
         }
 
@@ -271,6 +805,9 @@ impl DevToClient {
             .await
             .context("Failed to parse dev.to publish response")?;
 
-        Ok(publish_response.url)
+        Ok(PublishedArticle {
+            id: publish_response.id.to_string(),
+            url: publish_response.url,
+        })
     }
 }
@@ -0,0 +1,138 @@
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::Path;
+
+/// Response headers kept in fixtures; everything else (cookies, tracing IDs) is dropped
+const RECORDED_HEADERS: [&str; 7] = [
+    "content-type",
+    "etag",
+    "last-modified",
+    "retry-after",
+    "x-ratelimit-limit",
+    "x-ratelimit-remaining",
+    "x-ratelimit-reset",
+];
+
+/// Platform API exchanges recorded with `--record-http`, replayed by tests against a mock server
+///
+/// Only the path of each request is stored, so a fixture replays against any base URL.
+/// Request headers (and with them the credentials) are never recorded.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct Fixture {
+    /// Exchanges in the order they happened
+    pub exchanges: Vec<RecordedExchange>,
+}
+
+/// One request and the response it got
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct RecordedExchange {
+    /// HTTP method (`GET`, `POST`, ...)
+    pub method: String,
+
+    /// URL path, with the query string if there was one (`/api/articles/me?page=1`)
+    pub path: String,
+
+    /// Request body, if it was JSON
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub request_body: Option<serde_json::Value>,
+
+    /// Response status code
+    pub status: u16,
+
+    /// Response headers relevant to the clients (content type, caching, rate limits)
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub response_headers: BTreeMap<String, String>,
+
+    /// Response body
+    pub response_body: String,
+}
+
+impl Fixture {
+    /// Read a fixture file
+    pub fn load(path: &Path) -> Result<Self> {
+        let content = fs::read_to_string(path)
+            .context(format!("Failed to read fixture {}", path.display()))?;
+        serde_json::from_str(&content)
+            .context(format!("Failed to parse fixture {}", path.display()))
+    }
+
+    /// Write the fixture as pretty-printed JSON
+    pub fn save(&self, path: &Path) -> Result<()> {
+        let json = serde_json::to_string_pretty(self).context("Failed to serialize fixture")?;
+        fs::write(path, json + "\n").context(format!("Failed to write fixture {}", path.display()))
+    }
+
+    /// Add an exchange
+    pub fn record(
+        &mut self,
+        method: &str,
+        url: &reqwest::Url,
+        request_body: Option<&[u8]>,
+        status: u16,
+        headers: &reqwest::header::HeaderMap,
+        response_body: &[u8],
+    ) {
+        let path = match url.query() {
+            Some(query) => format!("{}?{}", url.path(), query),
+            None => url.path().to_string(),
+        };
+        let response_headers = RECORDED_HEADERS
+            .iter()
+            .filter_map(|name| {
+                let value = headers.get(*name)?.to_str().ok()?;
+                Some((name.to_string(), value.to_string()))
+            })
+            .collect();
+
+        self.exchanges.push(RecordedExchange {
+            method: method.to_string(),
+            path,
+            request_body: request_body.and_then(|body| serde_json::from_slice(body).ok()),
+            status,
+            response_headers,
+            response_body: String::from_utf8_lossy(response_body).into_owned(),
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use reqwest::header::{HeaderMap, HeaderValue};
+
+    #[test]
+    fn test_fixture_record_and_round_trip() {
+        let mut headers = HeaderMap::new();
+        headers.insert("content-type", HeaderValue::from_static("application/json"));
+        headers.insert("set-cookie", HeaderValue::from_static("session=secret"));
+
+        let mut fixture = Fixture::default();
+        fixture.record(
+            "POST",
+            &"https://dev.to/api/articles?draft=1".parse().unwrap(),
+            Some(b"{\"article\":{\"title\":\"T\"}}"),
+            201,
+            &headers,
+            b"{\"id\":1}",
+        );
+
+        let exchange = &fixture.exchanges[0];
+        assert_eq!(exchange.path, "/api/articles?draft=1");
+        assert_eq!(
+            exchange.request_body,
+            Some(serde_json::json!({"article": {"title": "T"}}))
+        );
+        assert_eq!(exchange.response_headers.len(), 1);
+        assert_eq!(
+            exchange.response_headers["content-type"],
+            "application/json"
+        );
+
+        let dir = tempfile::TempDir::new().unwrap();
+        let path = dir.path().join("fixture.json");
+        fixture.save(&path).unwrap();
+        assert_eq!(Fixture::load(&path).unwrap(), fixture);
+    }
+}
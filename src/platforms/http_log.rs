@@ -0,0 +1,345 @@
+use anyhow::{Context, Result};
+use chrono::Utc;
+use once_cell::sync::OnceCell;
+use reqwest::header::HeaderMap;
+use reqwest::{Request, RequestBuilder, Response};
+use std::fs::{self, File, OpenOptions};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+
+use crate::cli::Config;
+use crate::platforms::fixtures::Fixture;
+
+/// HTTP trace (`--debug-http`) and fixture recording (`--record-http`); set once at startup
+static HTTP_LOG: OnceCell<HttpLog> = OnceCell::new();
+
+/// Headers whose values are replaced with `[REDACTED]` in the trace
+const CREDENTIAL_HEADERS: [&str; 5] = [
+    "api-key",
+    "authorization",
+    "cookie",
+    "set-cookie",
+    "x-api-key",
+];
+
+/// Record platform requests and responses for this run
+///
+/// `trace` gets a readable log of every exchange (`--debug-http`), `fixture` a
+/// replayable [`Fixture`] (`--record-http`).
+pub fn init(trace: Option<&Path>, fixture: Option<&Path>) -> Result<()> {
+    let mut log = HttpLog::new();
+    if let Some(path) = trace {
+        log = log.with_trace(path)?;
+    }
+    if let Some(path) = fixture {
+        log = log.with_fixture(path);
+    }
+
+    let _ = HTTP_LOG.set(log);
+    Ok(())
+}
+
+/// Default trace file (`http-debug.log` next to the config file)
+pub fn default_log_path() -> Result<PathBuf> {
+    let config_path = Config::config_path()?;
+    let config_dir = config_path
+        .parent()
+        .context("Failed to get config directory")?;
+
+    Ok(config_dir.join("http-debug.log"))
+}
+
+/// Send a request, recording it and its response when `--debug-http` or `--record-http` is on
+///
+/// Platform client requests go through here (via the rate limiters), as do the
+/// `doctor` reachability checks. Requests outside the platform APIs use [`send_traced`].
+pub async fn send(request: RequestBuilder) -> reqwest::Result<Response> {
+    match HTTP_LOG.get() {
+        Some(log) => log.send(request).await,
+        None => request.send().await,
+    }
+}
+
+/// Send a request that is not a platform API call (e.g. a cover image download),
+/// recording it in the `--debug-http` trace but not in a `--record-http` fixture
+pub async fn send_traced(request: RequestBuilder) -> reqwest::Result<Response> {
+    match HTTP_LOG.get() {
+        Some(log) => log.exchange(request, false).await,
+        None => request.send().await,
+    }
+}
+
+/// Recorder of HTTP exchanges: a trace with credentials redacted and/or a fixture
+#[derive(Debug, Default)]
+pub struct HttpLog {
+    trace: Option<Mutex<File>>,
+    fixture: Option<(PathBuf, Mutex<Fixture>)>,
+}
+
+impl HttpLog {
+    /// Recorder that records nothing until a trace or fixture is added
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Append a trace to a file (created if missing), after the traces of earlier runs
+    ///
+    /// The trace holds article content and API responses, so on Unix the file is only
+    /// readable by the user (0600), like the config file.
+    pub fn with_trace(mut self, path: &Path) -> Result<Self> {
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)
+                .context(format!("Failed to create directory {}", dir.display()))?;
+        }
+
+        let mut options = OpenOptions::new();
+        options.create(true).append(true);
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::OpenOptionsExt;
+            options.mode(0o600);
+        }
+        let file = options
+            .open(path)
+            .context(format!("Failed to open HTTP log {}", path.display()))?;
+
+        // Logs from earlier versions may have been created readable by others
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            file.set_permissions(fs::Permissions::from_mode(0o600))
+                .context("Failed to set HTTP log permissions")?;
+        }
+
+        self.trace = Some(Mutex::new(file));
+        Ok(self)
+    }
+
+    /// Record exchanges into a fixture file, rewritten after every response
+    pub fn with_fixture(mut self, path: &Path) -> Self {
+        self.fixture = Some((path.to_path_buf(), Mutex::new(Fixture::default())));
+        self
+    }
+
+    /// Send a request and record both sides of the exchange
+    ///
+    /// The response body is read in full for the records and handed back unchanged.
+    pub async fn send(&self, request: RequestBuilder) -> reqwest::Result<Response> {
+        self.exchange(request, true).await
+    }
+
+    /// Send a request, recording it in the trace and, if `fixture` is set, the fixture
+    async fn exchange(&self, request: RequestBuilder, fixture: bool) -> reqwest::Result<Response> {
+        let (client, request) = request.build_split();
+        let request = request?;
+        self.write_trace(&format_request(&request));
+
+        let method = request.method().to_string();
+        let url = request.url().clone();
+        let request_body = request
+            .body()
+            .and_then(|body| body.as_bytes())
+            .map(<[u8]>::to_vec);
+
+        let response = match client.execute(request).await {
+            Ok(response) => response,
+            Err(e) => {
+                self.write_trace(&format!("<<< request failed: {}\n\n", e));
+                return Err(e);
+            }
+        };
+
+        let status = response.status();
+        let version = response.version();
+        let headers = response.headers().clone();
+        let body = response.bytes().await?;
+        self.write_trace(&format_response(status, &headers, &body));
+
+        if let Some((path, recording)) = self.fixture.as_ref().filter(|_| fixture) {
+            if let Ok(mut fixture) = recording.lock() {
+                fixture.record(
+                    &method,
+                    &url,
+                    request_body.as_deref(),
+                    status.as_u16(),
+                    &headers,
+                    &body,
+                );
+                if let Err(e) = fixture.save(path) {
+                    eprintln!("⚠️  Warning: {:#}", e);
+                }
+            }
+        }
+
+        let mut rebuilt = http::Response::builder().status(status).version(version);
+        if let Some(rebuilt_headers) = rebuilt.headers_mut() {
+            *rebuilt_headers = headers;
+        }
+        let rebuilt = rebuilt
+            .body(body)
+            .expect("status and headers come from a valid response");
+
+        Ok(Response::from(rebuilt))
+    }
+
+    fn write_trace(&self, entry: &str) {
+        if let Some(Ok(mut file)) = self.trace.as_ref().map(Mutex::lock) {
+            let _ = file.write_all(entry.as_bytes());
+        }
+    }
+}
+
+/// Trace entry for a request: method, URL, headers and body
+fn format_request(request: &Request) -> String {
+    let body = match request.body() {
+        None => String::new(),
+        Some(body) => match body.as_bytes() {
+            Some(bytes) => String::from_utf8_lossy(bytes).into_owned(),
+            None => "<streamed body (multipart upload) not recorded>".to_string(),
+        },
+    };
+
+    format!(
+        ">>> {} {} {}\n{}\n{}\n",
+        Utc::now().to_rfc3339(),
+        request.method(),
+        request.url(),
+        format_headers(request.headers()),
+        body
+    )
+}
+
+/// Trace entry for a response: status, headers and body
+fn format_response(status: reqwest::StatusCode, headers: &HeaderMap, body: &[u8]) -> String {
+    format!(
+        "<<< {} {}\n{}\n{}\n\n",
+        Utc::now().to_rfc3339(),
+        status,
+        format_headers(headers),
+        match std::str::from_utf8(body) {
+            Ok(text) => text.to_string(),
+            Err(_) => format!("<{} bytes of binary data>", body.len()),
+        }
+    )
+}
+
+/// One `name: value` line per header, with credential values redacted
+fn format_headers(headers: &HeaderMap) -> String {
+    let mut lines = String::new();
+    for (name, value) in headers {
+        let value = if CREDENTIAL_HEADERS.contains(&name.as_str()) {
+            "[REDACTED]"
+        } else {
+            value.to_str().unwrap_or("<binary>")
+        };
+        lines.push_str(&format!("{}: {}\n", name, value));
+    }
+    lines
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_http_log_records_exchange_and_redacts_credentials() {
+        use std::io::Read;
+
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}/api/articles", listener.local_addr().unwrap());
+        let server = std::thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+            let mut buffer = [0; 1024];
+            let _ = stream.read(&mut buffer).unwrap();
+            stream
+                .write_all(
+                    b"HTTP/1.1 422 Unprocessable Entity\r\nContent-Length: 17\r\n\
+                      Connection: close\r\n\r\n{\"error\":\"title\"}",
+                )
+                .unwrap();
+        });
+
+        let dir = tempfile::TempDir::new().unwrap();
+        let path = dir.path().join("http-debug.log");
+        let fixture_path = dir.path().join("fixture.json");
+        let log = HttpLog::new()
+            .with_trace(&path)
+            .unwrap()
+            .with_fixture(&fixture_path);
+        let response = log
+            .send(
+                reqwest::Client::new()
+                    .post(&url)
+                    .header("api-key", "secret-key")
+                    .body("{\"article\":{}}"),
+            )
+            .await
+            .unwrap();
+
+        // The caller still gets the full response
+        assert_eq!(response.status(), reqwest::StatusCode::UNPROCESSABLE_ENTITY);
+        assert_eq!(response.text().await.unwrap(), "{\"error\":\"title\"}");
+        server.join().unwrap();
+
+        let trace = fs::read_to_string(&path).unwrap();
+        assert!(trace.contains(&format!("POST {}", url)));
+        assert!(trace.contains("api-key: [REDACTED]"));
+        assert!(!trace.contains("secret-key"));
+        assert!(trace.contains("{\"article\":{}}"));
+        assert!(trace.contains("422 Unprocessable Entity"));
+        assert!(trace.contains("{\"error\":\"title\"}"));
+
+        let fixture = Fixture::load(&fixture_path).unwrap();
+        assert_eq!(fixture.exchanges.len(), 1);
+        assert_eq!(fixture.exchanges[0].path, "/api/articles");
+        assert_eq!(fixture.exchanges[0].status, 422);
+
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            let mode = fs::metadata(&path).unwrap().permissions().mode();
+            assert_eq!(mode & 0o777, 0o600);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_http_log_traces_downloads_without_fixture() {
+        use std::io::Read;
+
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}/cover.png", listener.local_addr().unwrap());
+        let server = std::thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+            let mut buffer = [0; 1024];
+            let _ = stream.read(&mut buffer).unwrap();
+            stream
+                .write_all(
+                    b"HTTP/1.1 200 OK\r\nContent-Length: 4\r\n\
+                      Connection: close\r\n\r\n\x89PNG",
+                )
+                .unwrap();
+        });
+
+        let dir = tempfile::TempDir::new().unwrap();
+        let path = dir.path().join("http-debug.log");
+        let fixture_path = dir.path().join("fixture.json");
+        let log = HttpLog::new()
+            .with_trace(&path)
+            .unwrap()
+            .with_fixture(&fixture_path);
+        let response = log
+            .exchange(reqwest::Client::new().get(&url), false)
+            .await
+            .unwrap();
+
+        assert_eq!(response.bytes().await.unwrap().as_ref(), b"\x89PNG");
+        server.join().unwrap();
+
+        let trace = fs::read_to_string(&path).unwrap();
+        assert!(trace.contains(&format!("GET {}", url)));
+        assert!(trace.contains("<4 bytes of binary data>"));
+        // Nothing was recorded, so the fixture file was never written
+        assert!(!fixture_path.exists());
+    }
+}
@@ -1,19 +1,41 @@
 use anyhow::{Context, Result};
+use chrono::{DateTime, Utc};
 use reqwest::Client;
 use serde::{Deserialize, Serialize};
 
-use crate::cli::ContentFormat;
-use crate::models::{Article, ArticleSummary};
-use crate::parsers::{ensure_title_in_content, markdown_to_html};
+use crate::cli::{ContentFormat, MediumConfig};
+use crate::models::{Article, ArticleSummary, Asset, MediumLicense, Syndication};
+use crate::parsers::medium_import::{medium_post_id, strip_title_heading};
+use crate::parsers::sanitizer::{
+    sanitize_for_platform, Platform as SanitizerPlatform, SanitizeOptions,
+};
+use crate::parsers::{
+    body_size_issue, ensure_title_in_content, extract_tables, html_to_markdown, markdown_to_html,
+    tables_to_images, TableStyle, MEDIUM_LIMITS,
+};
+use crate::platforms::rate_limit::{
+    CreateCheck, CreateResponse, CREATE_CLOCK_TOLERANCE_SECS, MEDIUM_RATE_LIMIT,
+};
+use crate::platforms::{
+    http_client, ImportedArticle, PreparedRequest, PreviewRequest, PublishedArticle,
+    RequestPreview, ResolveAssets,
+};
 
 /// Maximum number of tags allowed by Medium
-const MEDIUM_MAX_TAGS: usize = 5;
+const MEDIUM_MAX_TAGS: usize = MEDIUM_LIMITS.max_tags;
+
+/// Medium API base URL
+pub const MEDIUM_API_URL: &str = "https://api.medium.com/v1";
+
+/// Base URL of Medium's RSS feeds (`<base>/feed/@username`)
+pub const MEDIUM_FEED_URL: &str = "https://medium.com";
 
 /// Medium API client
 pub struct MediumClient {
     client: Client,
     access_token: String,
     base_url: String,
+    feed_base_url: String,
 }
 
 /// Response from Medium GET /v1/me
@@ -32,7 +54,7 @@ struct MediumUser {
 /// Request body for Medium POST /v1/users/{userId}/posts
 #[derive(Debug, Serialize)]
 #[serde(rename_all = "camelCase")]
-struct MediumPublishRequest {
+pub struct MediumPublishRequest {
     title: String,
     content_format: MediumContentFormat,
     content: String,
@@ -41,6 +63,10 @@ struct MediumPublishRequest {
     #[serde(skip_serializing_if = "Vec::is_empty")]
     tags: Vec<String>,
     publish_status: PublishStatus,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    license: Option<MediumLicense>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    notify_followers: Option<bool>,
 }
 
 /// Content format for Medium API
@@ -52,6 +78,15 @@ enum MediumContentFormat {
     Html,
 }
 
+impl std::fmt::Display for MediumContentFormat {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            MediumContentFormat::Markdown => write!(f, "markdown"),
+            MediumContentFormat::Html => write!(f, "html"),
+        }
+    }
+}
+
 /// Publication status for Medium articles
 #[derive(Debug, Serialize)]
 #[serde(rename_all = "lowercase")]
@@ -71,28 +106,103 @@ struct MediumPublishResponse {
 /// Medium post data
 #[derive(Debug, Deserialize)]
 struct MediumPost {
+    id: String,
+    url: String,
+}
+
+/// Response from Medium POST /v1/images
+#[derive(Debug, Deserialize)]
+struct MediumImageResponse {
+    data: MediumImage,
+}
+
+/// Medium uploaded image data
+#[derive(Debug, Deserialize)]
+struct MediumImage {
     url: String,
 }
 
+impl ResolveAssets for MediumPublishRequest {
+    fn resolve_asset(&mut self, placeholder: &str, url: &str) {
+        self.content = self.content.replace(placeholder, url);
+    }
+}
+
+impl PreviewRequest for MediumPublishRequest {
+    fn preview(&self) -> RequestPreview {
+        let mut fields = vec![("Title", self.title.clone())];
+        if !self.tags.is_empty() {
+            fields.push(("Tags", self.tags.join(", ")));
+        }
+        if let Some(ref canonical) = self.canonical_url {
+            fields.push(("Canonical URL", canonical.clone()));
+        }
+        let status = match self.publish_status {
+            PublishStatus::Public => "public",
+            PublishStatus::Draft => "draft",
+            PublishStatus::Unlisted => "unlisted",
+        };
+        fields.push(("Publish Status", status.to_string()));
+        if let Some(license) = self.license {
+            fields.push(("License", license.to_string()));
+        }
+        if let Some(notify) = self.notify_followers {
+            fields.push(("Notify Followers", notify.to_string()));
+        }
+
+        RequestPreview {
+            fields,
+            format: self.content_format.to_string(),
+            body: self.content.clone(),
+        }
+    }
+}
+
 impl MediumClient {
     /// Create a new Medium client
     pub fn new(access_token: String) -> Self {
         Self {
-            client: Client::new(),
+            client: http_client(),
             access_token,
-            base_url: "https://api.medium.com/v1".to_string(),
+            base_url: MEDIUM_API_URL.to_string(),
+            feed_base_url: MEDIUM_FEED_URL.to_string(),
+        }
+    }
+
+    /// Create a client from the `[medium]` config section
+    pub fn from_config(config: &MediumConfig) -> Self {
+        let mut client = Self::new(config.access_token.clone());
+        if let Some(ref base_url) = config.base_url {
+            client = client.with_base_url(base_url);
+        }
+        if let Some(ref feed_url) = config.feed_url {
+            client = client.with_feed_base_url(feed_url);
         }
+        client
+    }
+
+    /// Send API requests to another base URL (a mock server in tests)
+    pub fn with_base_url(mut self, base_url: impl Into<String>) -> Self {
+        self.base_url = base_url.into().trim_end_matches('/').to_string();
+        self
+    }
+
+    /// Read RSS feeds from another base URL
+    pub fn with_feed_base_url(mut self, feed_base_url: impl Into<String>) -> Self {
+        self.feed_base_url = feed_base_url.into().trim_end_matches('/').to_string();
+        self
     }
 
     /// Get the authenticated user info
     async fn get_user(&self) -> Result<MediumUser> {
         let url = format!("{}/me", self.base_url);
 
-        let response = self
-            .client
-            .get(&url)
-            .header("Authorization", format!("Bearer {}", self.access_token))
-            .send()
+        let response = MEDIUM_RATE_LIMIT
+            .send(
+                self.client
+                    .get(&url)
+                    .header("Authorization", format!("Bearer {}", self.access_token)),
+            )
             .await
             .context("Failed to send request to Medium API")?;
 
@@ -119,29 +229,7 @@ impl MediumClient {
 
     /// List recent articles from Medium via RSS feed
     pub async fn list_articles(&self) -> Result<Vec<ArticleSummary>> {
-        let user = self.get_user().await?;
-
-        let feed_url = format!("https://medium.com/feed/@{}", user.username);
-
-        let response = self
-            .client
-            .get(&feed_url)
-            .header("User-Agent", "article-cross-poster/0.1.0")
-            .send()
-            .await
-            .context("Failed to fetch Medium RSS feed")?;
-
-        if !response.status().is_success() {
-            let status = response.status();
-            anyhow::bail!("Failed to fetch Medium RSS feed (status {})", status);
-        }
-
-        let body = response
-            .bytes()
-            .await
-            .context("Failed to read Medium RSS feed body")?;
-
-        let feed = feed_rs::parser::parse(&body[..]).context("Failed to parse Medium RSS feed")?;
+        let feed = self.fetch_feed().await?;
 
         Ok(feed
             .entries
@@ -171,34 +259,151 @@ impl MediumClient {
             .collect())
     }
 
-    /// Publish an article to Medium with specified format
-    pub async fn publish_article(
-        &self,
-        article: &Article,
-        format: &ContentFormat,
-    ) -> Result<String> {
-        // First, get the user info
+    /// Download recent posts as markdown via the RSS feed
+    ///
+    /// The feed only has the 10 most recent posts; older ones need Medium's export
+    /// archive (see `read_medium_export`).
+    pub async fn import_articles(&self) -> Result<Vec<ImportedArticle>> {
+        let feed = self.fetch_feed().await?;
+
+        Ok(feed
+            .entries
+            .into_iter()
+            .map(|entry| {
+                let title = entry.title.map(|t| t.content).unwrap_or_default();
+                let html = entry
+                    .content
+                    .and_then(|content| content.body)
+                    .or_else(|| entry.summary.map(|summary| summary.content))
+                    .unwrap_or_default();
+                let content = strip_title_heading(&html_to_markdown(&html), &title);
+
+                // Feed links carry tracking parameters (`?source=rss-...`)
+                let url = entry.links.first().map(|link| {
+                    link.href
+                        .split('?')
+                        .next()
+                        .unwrap_or(&link.href)
+                        .to_string()
+                });
+                let tags = entry.categories.into_iter().map(|c| c.term).collect();
+
+                let article = Article::new(title, content)
+                    .with_tags(tags)
+                    .with_syndication(Syndication {
+                        medium_id: medium_post_id(&entry.id),
+                        medium_url: url,
+                        ..Default::default()
+                    });
+
+                ImportedArticle {
+                    article,
+                    published_at: entry.published.map(|date| date.to_rfc3339()),
+                    engagement: None,
+                }
+            })
+            .collect())
+    }
+
+    /// Fetch and parse the user's RSS feed
+    async fn fetch_feed(&self) -> Result<feed_rs::model::Feed> {
         let user = self.get_user().await?;
 
-        let url = format!("{}/users/{}/posts", self.base_url, user.id);
+        let feed_url = format!("{}/feed/@{}", self.feed_base_url, user.username);
 
-        // Medium has a max of 5 tags - warn if truncating
-        let tags: Vec<String> = article.tags.iter().take(MEDIUM_MAX_TAGS).cloned().collect();
-        let tags_str = tags.join(", "); // Save before moving
-        let tags_len = tags.len();
+        let response = MEDIUM_RATE_LIMIT
+            .send(
+                self.client
+                    .get(&feed_url)
+                    .header("User-Agent", "article-cross-poster/0.1.0"),
+            )
+            .await
+            .context("Failed to fetch Medium RSS feed")?;
 
+        if !response.status().is_success() {
+            let status = response.status();
+            anyhow::bail!("Failed to fetch Medium RSS feed (status {})", status);
+        }
+
+        let body = response
+            .bytes()
+            .await
+            .context("Failed to read Medium RSS feed body")?;
+
+        feed_rs::parser::parse(&body[..]).context("Failed to parse Medium RSS feed")
+    }
+
+    /// Find a recent article with the same title as the given one
+    ///
+    /// Medium only exposes the 10 most recent posts via RSS and no canonical URLs,
+    /// so older duplicates cannot be detected.
+    pub async fn find_existing_article(&self, article: &Article) -> Result<Option<ArticleSummary>> {
+        let articles = self.list_articles().await?;
+
+        Ok(articles
+            .into_iter()
+            .find(|a| article.is_duplicate_of(&a.title, None)))
+    }
+
+    /// Build the Medium publish request for an article
+    ///
+    /// Applies tag truncation, sanitization (liquid tag removal, footnote conversion),
+    /// table handling, title prepending and format conversion exactly as
+    /// `publish_article` does, without touching the network. Tables rendered as
+    /// images are returned as assets to upload.
+    pub fn build_publish_request(
+        article: &Article,
+        format: &ContentFormat,
+        options: &SanitizeOptions,
+    ) -> Result<PreparedRequest<MediumPublishRequest>> {
+        let mut warnings = Vec::new();
+
+        // Medium has a max of 5 tags - warn if truncating
         if article.tags.len() > MEDIUM_MAX_TAGS {
-            eprintln!(
-                "⚠️  Warning: Medium only supports {} tags. Truncating from {} to {} tags.",
+            warnings.push(format!(
+                "Medium only supports {} tags. Truncating from {} to {} tags (excluded: {})",
                 MEDIUM_MAX_TAGS,
                 article.tags.len(),
-                MEDIUM_MAX_TAGS
-            );
-            eprintln!("   Included: {}", tags_str);
-            eprintln!(
-                "   Excluded: {}",
+                MEDIUM_MAX_TAGS,
                 article.tags[MEDIUM_MAX_TAGS..].join(", ")
-            );
+            ));
+        }
+
+        // Sanitize the truncated article so the tag limit is handled by the warning above
+        let mut article = article.clone();
+        article.tags.truncate(MEDIUM_MAX_TAGS);
+        warnings.extend(
+            sanitize_for_platform(&mut article, SanitizerPlatform::Medium, options)
+                .context("Failed to sanitize article for Medium")?,
+        );
+
+        // Medium has no table support
+        let mut assets = Vec::new();
+        match options.tables.unwrap_or_default() {
+            TableStyle::Image => {
+                let (content, images) = tables_to_images(&article.content)?;
+                article.content = content;
+                assets = images;
+            }
+            TableStyle::Html => {
+                let count = extract_tables(&article.content).len();
+                if count > 0 && matches!(format, ContentFormat::Markdown) {
+                    warnings.push(format!(
+                        "Medium does not render markdown tables ({} found). Use --format html, \
+                         or set tables = \"image\" in the [medium] config section",
+                        count
+                    ));
+                }
+            }
+            TableStyle::Keep => {}
+        }
+
+        if let Some(date) = article.backdate().filter(|_| article.published) {
+            warnings.push(format!(
+                "Medium's API cannot set a publication date; the post will be dated today, \
+                 not {}",
+                date.format("%Y-%m-%d")
+            ));
         }
 
         let publish_status = if article.published {
@@ -216,31 +421,172 @@ impl MediumClient {
             ContentFormat::Html => {
                 let html = markdown_to_html(&content_with_title)
                     .context("Failed to convert markdown to HTML")?;
+                if let Some(message) = body_size_issue(&html, "Converted HTML", &MEDIUM_LIMITS) {
+                    anyhow::bail!("Article exceeds Medium limits:\n  - {}", message);
+                }
                 (MediumContentFormat::Html, html)
             }
         };
 
-        // Save content length for error reporting before moving content
-        let content_len = content.len();
-
-        let request_body = MediumPublishRequest {
-            title: article.title.clone(),
+        let request = MediumPublishRequest {
+            title: article.title,
             content_format,
             content,
-            canonical_url: article.canonical_url.clone(),
-            tags,
+            canonical_url: article.canonical_url,
+            tags: article.tags,
             publish_status,
+            license: article.medium_license,
+            notify_followers: article.notify_followers,
         };
 
-        let response = self
+        Ok(PreparedRequest {
+            request,
+            warnings,
+            assets,
+        })
+    }
+
+    /// Publish an article to Medium with specified format
+    pub async fn publish_article(
+        &self,
+        article: &Article,
+        format: &ContentFormat,
+    ) -> Result<String> {
+        let prepared = Self::build_publish_request(article, format, &SanitizeOptions::default())?;
+        prepared.print_warnings();
+
+        let mut request = prepared.request;
+        self.upload_assets(&mut request, &prepared.assets).await?;
+        Ok(self.publish_request(&request).await?.url)
+    }
+
+    /// Upload an image to Medium and return its hosted URL
+    pub async fn upload_image(&self, asset: &Asset) -> Result<String> {
+        let url = format!("{}/images", self.base_url);
+
+        let part = reqwest::multipart::Part::bytes(asset.data.clone())
+            .file_name(asset.file_name.clone())
+            .mime_str(&asset.content_type)
+            .context("Invalid image content type")?;
+        let form = reqwest::multipart::Form::new().part("image", part);
+
+        let response = MEDIUM_RATE_LIMIT
+            .send(
+                self.client
+                    .post(&url)
+                    .header("Authorization", format!("Bearer {}", self.access_token))
+                    .multipart(form),
+            )
+            .await
+            .context("Failed to send image upload request to Medium API")?;
+
+        if !response.status().is_success() {
+            let status = response.status();
+            let error_text = response.text().await.unwrap_or_default();
+            anyhow::bail!(
+                "Failed to upload image '{}' to Medium (status {}): {}",
+                asset.file_name,
+                status,
+                error_text
+            );
+        }
+
+        let image_response: MediumImageResponse = response
+            .json()
+            .await
+            .context("Failed to parse Medium image upload response")?;
+
+        Ok(image_response.data.url)
+    }
+
+    /// Upload assets and replace their placeholder URLs in a publish request
+    ///
+    /// Medium's image hosting also serves as the image backend for other platforms.
+    pub async fn upload_assets<T: ResolveAssets>(
+        &self,
+        request: &mut T,
+        assets: &[Asset],
+    ) -> Result<()> {
+        for asset in assets {
+            let url = self.upload_image(asset).await?;
+            request.resolve_asset(&asset.placeholder(), &url);
+        }
+
+        Ok(())
+    }
+
+    /// Look for the post a failed create request may have made
+    ///
+    /// Only a feed entry with exactly the request's title that was published after
+    /// the request was sent (`sent_at`) counts; an older post with the same title is
+    /// not the new one. Only public posts appear in the RSS feed, so a post that
+    /// isn't found may still exist (as a draft or unlisted, or not yet in the feed).
+    async fn check_created(
+        &self,
+        request_body: &MediumPublishRequest,
+        sent_at: DateTime<Utc>,
+    ) -> Result<CreateCheck<PublishedArticle>> {
+        let earliest = sent_at - chrono::Duration::seconds(CREATE_CLOCK_TOLERANCE_SECS);
+        let feed = self.fetch_feed().await?;
+
+        let created = feed.entries.into_iter().find(|entry| {
+            entry
+                .title
+                .as_ref()
+                .is_some_and(|title| title.content == request_body.title)
+                && entry.published.is_some_and(|date| date >= earliest)
+        });
+
+        Ok(match created {
+            Some(entry) => {
+                // Feed links carry tracking parameters (`?source=rss-...`)
+                let url = entry
+                    .links
+                    .first()
+                    .and_then(|link| link.href.split('?').next())
+                    .unwrap_or_default();
+                CreateCheck::Created(PublishedArticle {
+                    id: medium_post_id(url).unwrap_or(entry.id),
+                    url: url.to_string(),
+                })
+            }
+            None => CreateCheck::Unknown,
+        })
+    }
+
+    /// Send a prepared publish request to Medium
+    ///
+    /// If the request fails in transit, it is not sent again unless the post is
+    /// found (see `RateLimiter::send_create`).
+    pub async fn publish_request(
+        &self,
+        request_body: &MediumPublishRequest,
+    ) -> Result<PublishedArticle> {
+        // Save details for error reporting before sending
+        let tags_str = request_body.tags.join(", ");
+        let tags_len = request_body.tags.len();
+        let content_len = request_body.content.len();
+
+        // First, get the user info
+        let user = self.get_user().await?;
+
+        let url = format!("{}/users/{}/posts", self.base_url, user.id);
+
+        let request = self
             .client
             .post(&url)
             .header("Authorization", format!("Bearer {}", self.access_token))
             .header("Content-Type", "application/json")
-            .json(&request_body)
-            .send()
+            .json(request_body);
+        let sent_at = Utc::now();
+        let sent = MEDIUM_RATE_LIMIT
+            .send_create(request, || self.check_created(request_body, sent_at))
             .await
             .context("Failed to send publish request to Medium API")?;
+        let response = match sent {
+            CreateResponse::Response(response) => response,
+            CreateResponse::AlreadyCreated(existing) => return Ok(existing),
+        };
 
         if !response.status().is_success() {
             let status = response.status();
@@ -249,8 +595,8 @@ impl MediumClient {
             let error_msg = if status == 401 {
                 "Invalid access token - check your Medium credentials"
             } else if status == 429 {
-                "Rate limit exceeded - please try again later"
-            } else if status == 400 {
+                "Rate limit exceeded (still limited after retrying) - please try again later"
+            } else if status == 400 || status == 422 {
                 "Article validation failed - check title and content"
             } else {
                 "API request failed"
@@ -274,8 +620,8 @@ impl MediumClient {
                 } else {
                     &error_text
                 },
-                article.title,
-                format,
+                request_body.title,
+                request_body.content_format,
                 tags_len,
                 tags_str,
                 content_len
@@ -287,6 +633,9 @@ impl MediumClient {
             .await
             .context("Failed to parse Medium publish response")?;
 
-        Ok(publish_response.data.url)
+        Ok(PublishedArticle {
+            id: publish_response.data.id,
+            url: publish_response.data.url,
+        })
     }
 }
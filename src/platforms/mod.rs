@@ -1,5 +1,116 @@
 pub mod devto;
+pub mod fixtures;
+pub mod http_log;
 pub mod medium;
+pub mod rate_limit;
 
 pub use devto::DevToClient;
 pub use medium::MediumClient;
+
+use std::time::Duration;
+
+use crate::cli::ci;
+use crate::models::{Article, Asset};
+
+/// Longest a platform request may take before it fails (and counts as a transient failure)
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(120);
+
+/// HTTP client for the platform (and translation) clients and other downloads, with the
+/// request timeout applied
+pub fn http_client() -> reqwest::Client {
+    reqwest::Client::builder()
+        .timeout(REQUEST_TIMEOUT)
+        .build()
+        .unwrap_or_default()
+}
+
+/// A publish request whose asset placeholder URLs can be replaced after upload
+pub trait ResolveAssets {
+    /// Replace every occurrence of an asset placeholder with the hosted URL
+    fn resolve_asset(&mut self, placeholder: &str, url: &str);
+}
+
+/// A publish request that can describe what it will create, for `preview --to`
+pub trait PreviewRequest {
+    /// Title, metadata and body exactly as they will be sent
+    fn preview(&self) -> RequestPreview;
+}
+
+/// Human-readable view of a publish request
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RequestPreview {
+    /// Labelled metadata fields (title, tags, ...), in display order
+    pub fields: Vec<(&'static str, String)>,
+
+    /// Content format of `body` (`markdown`, `html`)
+    pub format: String,
+
+    /// Article body as sent
+    pub body: String,
+}
+
+/// An article as created on a platform
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PublishedArticle {
+    /// Platform article ID
+    pub id: String,
+
+    /// Public article URL
+    pub url: String,
+}
+
+/// An article downloaded from a platform, ready to be written as a local file
+#[derive(Debug, Clone)]
+pub struct ImportedArticle {
+    /// Article content and metadata (with the platform URL and ID in `syndication`)
+    pub article: Article,
+
+    /// Publication date (ISO 8601), if the article is published
+    pub published_at: Option<String>,
+
+    /// Reaction and comment counts, if the platform reports them
+    pub engagement: Option<Engagement>,
+}
+
+/// Reader engagement with an article, as counted by the platform
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Engagement {
+    /// Public reactions (likes, unicorns, bookmarks, ...)
+    pub reactions: u64,
+
+    /// Comments, including replies
+    pub comments: u64,
+}
+
+/// A platform publish request together with the warnings raised while building it
+#[derive(Debug)]
+pub struct PreparedRequest<T> {
+    /// Request body exactly as it will be sent
+    pub request: T,
+
+    /// Non-fatal issues found while preparing the request (sanitized tags, truncation, etc.)
+    pub warnings: Vec<String>,
+
+    /// Files referenced by placeholder URLs in the request, to upload before publishing
+    pub assets: Vec<Asset>,
+}
+
+impl<T> PreparedRequest<T> {
+    /// Print warnings (as GitHub Actions annotations in CI mode)
+    pub fn print_warnings(&self) {
+        for warning in &self.warnings {
+            ci::warning(None, warning);
+        }
+    }
+
+    /// Print the assets that will be uploaded before publishing
+    pub fn print_assets(&self) {
+        for asset in &self.assets {
+            println!(
+                "Asset to upload: {} ({} bytes)",
+                asset.file_name,
+                asset.data.len()
+            );
+        }
+    }
+}
@@ -0,0 +1,390 @@
+use reqwest::header::HeaderMap;
+use reqwest::{RequestBuilder, Response, StatusCode};
+use std::future::Future;
+use std::sync::Mutex;
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+use crate::platforms::http_log;
+
+/// Rate limiter shared by every dev.to client in the process
+pub static DEVTO_RATE_LIMIT: RateLimiter = RateLimiter::new("dev.to", Duration::from_secs(30));
+
+/// Rate limiter shared by every Medium client in the process
+pub static MEDIUM_RATE_LIMIT: RateLimiter = RateLimiter::new("Medium", Duration::from_secs(10));
+
+/// Times a request is retried after a 429 response
+const MAX_RETRIES: u32 = 3;
+
+/// Longest pause taken for a single rate-limit window
+const MAX_WAIT: Duration = Duration::from_secs(300);
+
+/// Times a request is retried after a transient failure (timeout, dropped connection, 502-504)
+const MAX_TRANSIENT_RETRIES: u32 = 2;
+
+/// Pause before the first retry after a transient failure, doubled for each further one
+const TRANSIENT_BACKOFF: Duration = Duration::from_secs(1);
+
+/// How far a platform's clock may lag behind ours when dating a post created by a
+/// request that failed in transit
+pub const CREATE_CLOCK_TOLERANCE_SECS: i64 = 60;
+
+/// Whether a create request that failed in transit created the article anyway
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CreateCheck<T> {
+    /// The article exists on the platform
+    Created(T),
+
+    /// The article does not exist; the request can be sent again
+    NotCreated,
+
+    /// The platform can't tell (e.g. Medium drafts are not listed anywhere)
+    Unknown,
+}
+
+/// Result of `RateLimiter::send_create`
+#[derive(Debug)]
+pub enum CreateResponse<T> {
+    /// The platform's response to the request
+    Response(Response),
+
+    /// An earlier attempt failed in transit but had created the article
+    AlreadyCreated(T),
+}
+
+/// Paces the requests sent to one platform
+///
+/// Every response is checked for rate-limit headers (`Retry-After`,
+/// `X-RateLimit-Remaining` / `X-RateLimit-Reset`). Once the platform says the
+/// limit is used up, later requests wait for the window to reset instead of
+/// failing, and a 429 response is retried after the advertised delay (or
+/// `backoff` when the platform gives none).
+#[derive(Debug)]
+pub struct RateLimiter {
+    platform: &'static str,
+    backoff: Duration,
+    blocked_until: Mutex<Option<Instant>>,
+}
+
+impl RateLimiter {
+    /// Create a limiter; `backoff` is the pause after a 429 without `Retry-After`
+    pub const fn new(platform: &'static str, backoff: Duration) -> Self {
+        Self {
+            platform,
+            backoff,
+            blocked_until: Mutex::new(None),
+        }
+    }
+
+    /// Send a request, waiting out the platform's rate limit first
+    ///
+    /// Idempotent requests (GET, PUT, DELETE) are also retried after transient
+    /// failures. Requests with a streaming body (multipart uploads) cannot be
+    /// cloned, so failures for them are returned to the caller instead of being retried.
+    pub async fn send(&self, request: RequestBuilder) -> reqwest::Result<Response> {
+        let idempotent = request
+            .try_clone()
+            .and_then(|clone| clone.build().ok())
+            .is_some_and(|built| built.method().is_idempotent());
+        let mut failures = 0;
+
+        loop {
+            let retry = if idempotent && failures < MAX_TRANSIENT_RETRIES {
+                request.try_clone()
+            } else {
+                None
+            };
+            let Some(next) = retry else {
+                return self.send_rate_limited(request).await;
+            };
+
+            match self.send_rate_limited(next).await {
+                Err(e) if is_transient_error(&e) => {}
+                Ok(response) if is_transient_status(response.status()) => {}
+                result => return result,
+            }
+            failures += 1;
+            self.pause_after_failure(failures).await;
+        }
+    }
+
+    /// Send a request that creates an article, retrying transient failures without duplicates
+    ///
+    /// A timeout or gateway error can arrive after the platform created the article,
+    /// so before each retry `check` looks for it. A found article is returned instead
+    /// of sending the request again; if `check` can't tell, the original failure is
+    /// returned with a warning that the article may exist.
+    pub async fn send_create<T, F, Fut>(
+        &self,
+        request: RequestBuilder,
+        check: F,
+    ) -> anyhow::Result<CreateResponse<T>>
+    where
+        F: Fn() -> Fut,
+        Fut: Future<Output = anyhow::Result<CreateCheck<T>>>,
+    {
+        let mut failures = 0;
+
+        loop {
+            let retry = if failures < MAX_TRANSIENT_RETRIES {
+                request.try_clone()
+            } else {
+                None
+            };
+            let Some(next) = retry else {
+                let response = self.send_rate_limited(request).await?;
+                return Ok(CreateResponse::Response(response));
+            };
+
+            let failure = match self.send_rate_limited(next).await {
+                // The request never reached the platform
+                Err(e) if e.is_connect() => None,
+                Err(e) if is_transient_error(&e) => Some(e.to_string()),
+                Ok(response) if is_transient_status(response.status()) => {
+                    Some(format!("status {}", response.status()))
+                }
+                result => return Ok(CreateResponse::Response(result?)),
+            };
+            failures += 1;
+            self.pause_after_failure(failures).await;
+
+            let Some(failure) = failure else {
+                continue;
+            };
+            match check().await {
+                Ok(CreateCheck::Created(found)) => {
+                    println!(
+                        "The {} request failed ({}) but the article was created; not sending it again",
+                        self.platform, failure
+                    );
+                    return Ok(CreateResponse::AlreadyCreated(found));
+                }
+                Ok(CreateCheck::NotCreated) => {
+                    println!(
+                        "The {} request failed ({}); the article was not created, retrying...",
+                        self.platform, failure
+                    );
+                }
+                Ok(CreateCheck::Unknown) => anyhow::bail!(
+                    "The {} request failed ({}) and it could not be verified whether the \
+                     article was created - check {} before posting again",
+                    self.platform,
+                    failure,
+                    self.platform
+                ),
+                Err(e) => anyhow::bail!(
+                    "The {} request failed ({}) and checking whether the article was \
+                     created failed too ({:#}) - check {} before posting again",
+                    self.platform,
+                    failure,
+                    e,
+                    self.platform
+                ),
+            }
+        }
+    }
+
+    /// Sleep before retrying after the given number of transient failures
+    async fn pause_after_failure(&self, failures: u32) {
+        tokio::time::sleep(TRANSIENT_BACKOFF * 2u32.pow(failures - 1)).await;
+    }
+
+    /// Send a request, waiting out the rate limit and retrying 429 responses
+    async fn send_rate_limited(&self, request: RequestBuilder) -> reqwest::Result<Response> {
+        let mut attempt = 0;
+
+        loop {
+            self.wait().await;
+
+            let retry = if attempt < MAX_RETRIES {
+                request.try_clone()
+            } else {
+                None
+            };
+            let Some(next) = retry else {
+                let response = http_log::send(request).await?;
+                self.observe(response.status(), response.headers());
+                return Ok(response);
+            };
+
+            let response = http_log::send(next).await?;
+            self.observe(response.status(), response.headers());
+            if response.status() != StatusCode::TOO_MANY_REQUESTS {
+                return Ok(response);
+            }
+            attempt += 1;
+        }
+    }
+
+    /// Sleep until the current rate-limit window is over
+    async fn wait(&self) {
+        let until = self.blocked_until.lock().ok().and_then(|until| *until);
+
+        if let Some(wait) = until.and_then(|until| until.checked_duration_since(Instant::now())) {
+            if wait >= Duration::from_secs(1) {
+                println!(
+                    "Waiting {}s for the {} rate limit...",
+                    wait.as_secs().max(1),
+                    self.platform
+                );
+            }
+            tokio::time::sleep(wait).await;
+        }
+    }
+
+    /// Record the pause a response asks for
+    fn observe(&self, status: StatusCode, headers: &HeaderMap) {
+        if let Some(delay) = rate_limit_delay(status, headers, self.backoff) {
+            if let Ok(mut until) = self.blocked_until.lock() {
+                let new_until = Instant::now() + delay.min(MAX_WAIT);
+                *until = Some(until.map_or(new_until, |current| current.max(new_until)));
+            }
+        }
+    }
+}
+
+/// True for request errors after which the request may or may not have been processed
+fn is_transient_error(error: &reqwest::Error) -> bool {
+    error.is_timeout() || error.is_connect() || error.is_request()
+}
+
+/// True for gateway errors, which platforms' CDNs return while the backend is struggling
+fn is_transient_status(status: StatusCode) -> bool {
+    matches!(
+        status,
+        StatusCode::BAD_GATEWAY | StatusCode::SERVICE_UNAVAILABLE | StatusCode::GATEWAY_TIMEOUT
+    )
+}
+
+/// How long to pause before the next request, based on a response
+///
+/// `Retry-After` wins; otherwise an exhausted `X-RateLimit-Remaining` waits for
+/// `X-RateLimit-Reset` (seconds from now, or a Unix timestamp). A 429 without
+/// either header falls back to `backoff`.
+pub fn rate_limit_delay(
+    status: StatusCode,
+    headers: &HeaderMap,
+    backoff: Duration,
+) -> Option<Duration> {
+    let header = |name: &str| {
+        headers
+            .get(name)
+            .and_then(|value| value.to_str().ok())
+            .and_then(|value| value.trim().parse::<f64>().ok())
+            .filter(|value| value.is_finite() && *value >= 0.0)
+    };
+
+    if let Some(seconds) = header("retry-after") {
+        return Some(Duration::from_secs_f64(seconds));
+    }
+
+    if header("x-ratelimit-remaining") == Some(0.0) {
+        if let Some(reset) = header("x-ratelimit-reset") {
+            return Some(reset_delay(reset));
+        }
+    }
+
+    (status == StatusCode::TOO_MANY_REQUESTS).then_some(backoff)
+}
+
+/// Seconds until a reset value, which is either a delay or a Unix timestamp
+fn reset_delay(reset: f64) -> Duration {
+    // Anything past 2001 is a timestamp rather than a delay
+    if reset > 1_000_000_000.0 {
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_secs_f64();
+        Duration::from_secs_f64((reset - now).max(0.0))
+    } else {
+        Duration::from_secs_f64(reset)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use reqwest::header::HeaderValue;
+
+    fn headers(pairs: &[(&'static str, &str)]) -> HeaderMap {
+        let mut map = HeaderMap::new();
+        for (name, value) in pairs {
+            map.insert(*name, HeaderValue::from_str(value).unwrap());
+        }
+        map
+    }
+
+    #[test]
+    fn test_rate_limit_delay() {
+        let backoff = Duration::from_secs(30);
+
+        assert_eq!(
+            rate_limit_delay(
+                StatusCode::TOO_MANY_REQUESTS,
+                &headers(&[("retry-after", "5")]),
+                backoff
+            ),
+            Some(Duration::from_secs(5))
+        );
+        assert_eq!(
+            rate_limit_delay(StatusCode::TOO_MANY_REQUESTS, &HeaderMap::new(), backoff),
+            Some(backoff)
+        );
+        assert_eq!(
+            rate_limit_delay(
+                StatusCode::CREATED,
+                &headers(&[("x-ratelimit-remaining", "0"), ("x-ratelimit-reset", "12")]),
+                backoff
+            ),
+            Some(Duration::from_secs(12))
+        );
+        assert_eq!(
+            rate_limit_delay(
+                StatusCode::OK,
+                &headers(&[("x-ratelimit-remaining", "7"), ("x-ratelimit-reset", "12")]),
+                backoff
+            ),
+            None
+        );
+    }
+
+    #[test]
+    fn test_reset_timestamp() {
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap()
+            .as_secs_f64();
+
+        let delay = reset_delay(now + 20.0);
+        assert!(delay > Duration::from_secs(18) && delay <= Duration::from_secs(20));
+        assert_eq!(reset_delay(now - 20.0), Duration::ZERO);
+    }
+
+    #[tokio::test]
+    async fn test_send_retries_after_429() {
+        use std::io::{Read, Write};
+
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}/", listener.local_addr().unwrap());
+        let server = std::thread::spawn(move || {
+            let responses = [
+                "HTTP/1.1 429 Too Many Requests\r\nRetry-After: 0\r\nContent-Length: 0\r\nConnection: close\r\n\r\n",
+                "HTTP/1.1 200 OK\r\nContent-Length: 2\r\nConnection: close\r\n\r\nok",
+            ];
+            for response in responses {
+                let (mut stream, _) = listener.accept().unwrap();
+                let mut buffer = [0; 1024];
+                let _ = stream.read(&mut buffer).unwrap();
+                stream.write_all(response.as_bytes()).unwrap();
+            }
+        });
+
+        let limiter = RateLimiter::new("test", Duration::from_secs(30));
+        let response = limiter
+            .send(reqwest::Client::new().get(&url))
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+        server.join().unwrap();
+    }
+}
@@ -0,0 +1,153 @@
+use anyhow::{Context, Result};
+use reqwest::Client;
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+
+use crate::cli::TranslateConfig;
+use crate::platforms::http_client;
+use crate::platforms::rate_limit::RateLimiter;
+
+/// DeepL API base URL for paid ("Pro") keys
+pub const DEEPL_API_URL: &str = "https://api.deepl.com/v2";
+
+/// DeepL API base URL for free keys (those ending in `:fx`)
+pub const DEEPL_FREE_API_URL: &str = "https://api-free.deepl.com/v2";
+
+/// Shared by every DeepL request of a run; DeepL answers 429 when sent too much at once
+static DEEPL_RATE_LIMIT: RateLimiter = RateLimiter::new("DeepL", Duration::from_secs(5));
+
+/// DeepL translation API client
+pub struct DeepLClient {
+    client: Client,
+    api_key: String,
+    base_url: String,
+}
+
+/// Request to DeepL POST /v2/translate
+#[derive(Debug, Serialize)]
+struct DeepLRequest<'a> {
+    text: &'a [String],
+    target_lang: String,
+    /// Parse the texts as XML so the `<x/>` placeholders are kept as they are
+    tag_handling: &'static str,
+    ignore_tags: [&'static str; 1],
+    /// Split on punctuation and newlines, so markdown line structure survives
+    split_sentences: &'static str,
+    preserve_formatting: bool,
+}
+
+/// Response from DeepL POST /v2/translate
+#[derive(Debug, Deserialize)]
+struct DeepLResponse {
+    translations: Vec<DeepLTranslation>,
+}
+
+#[derive(Debug, Deserialize)]
+struct DeepLTranslation {
+    text: String,
+}
+
+impl DeepLClient {
+    /// Create a client for an API key, using the free or paid endpoint to match the key
+    pub fn new(api_key: String) -> Self {
+        let base_url = if api_key.ends_with(":fx") {
+            DEEPL_FREE_API_URL
+        } else {
+            DEEPL_API_URL
+        };
+
+        Self {
+            client: http_client(),
+            base_url: base_url.to_string(),
+            api_key,
+        }
+    }
+
+    /// Create a client from the `[translate]` config section
+    pub fn from_config(config: &TranslateConfig) -> Result<Self> {
+        if config.api_key.trim().is_empty() {
+            anyhow::bail!(
+                "Translation is not configured - add your DeepL API key as `api_key` in the \
+                 [translate] section of the config file"
+            );
+        }
+
+        let client = Self::new(config.api_key.trim().to_string());
+        Ok(match config.base_url {
+            Some(ref url) => client.with_base_url(url.clone()),
+            None => client,
+        })
+    }
+
+    /// Send requests to another server (e.g. a mock server in tests)
+    pub fn with_base_url(mut self, base_url: String) -> Self {
+        self.base_url = base_url.trim_end_matches('/').to_string();
+        self
+    }
+
+    /// Translate texts into a language (`de`, `pt-br`), returning them in the same order
+    ///
+    /// Texts are handled as XML: `<x/>` elements are left untouched and `&`, `<`, `>`
+    /// must be escaped.
+    pub async fn translate(&self, texts: &[String], language: &str) -> Result<Vec<String>> {
+        let url = format!("{}/translate", self.base_url);
+        let body = DeepLRequest {
+            text: texts,
+            target_lang: language.to_uppercase(),
+            tag_handling: "xml",
+            ignore_tags: ["x"],
+            split_sentences: "1",
+            preserve_formatting: true,
+        };
+
+        let response = DEEPL_RATE_LIMIT
+            .send(
+                self.client
+                    .post(&url)
+                    .header("Authorization", format!("DeepL-Auth-Key {}", self.api_key))
+                    .json(&body),
+            )
+            .await
+            .context("Failed to send request to DeepL API")?;
+
+        let status = response.status();
+        if !status.is_success() {
+            let error_body = response
+                .text()
+                .await
+                .unwrap_or_else(|_| "Unable to read error response".to_string());
+
+            let message = match status.as_u16() {
+                403 => "Invalid DeepL API key",
+                456 => "DeepL translation quota exceeded",
+                400 => "DeepL rejected the request (check the language code)",
+                _ => "DeepL API error",
+            };
+            anyhow::bail!(
+                "{} (status {}): {}",
+                message,
+                status.as_u16(),
+                error_body.trim()
+            );
+        }
+
+        let response: DeepLResponse = response
+            .json()
+            .await
+            .context("Failed to parse DeepL API response")?;
+
+        if response.translations.len() != texts.len() {
+            anyhow::bail!(
+                "DeepL returned {} translations for {} texts",
+                response.translations.len(),
+                texts.len()
+            );
+        }
+
+        Ok(response
+            .translations
+            .into_iter()
+            .map(|translation| translation.text)
+            .collect())
+    }
+}
@@ -0,0 +1,248 @@
+pub mod deepl;
+
+pub use deepl::DeepLClient;
+
+use anyhow::{Context, Result};
+use once_cell::sync::Lazy;
+use pulldown_cmark::{Event, Options, Parser, Tag};
+use regex::Regex;
+use std::ops::Range;
+use std::path::{Path, PathBuf};
+
+use crate::models::Article;
+
+/// Link and image destinations (`](url "title")`), kept out of the translation
+static LINK_DESTINATION: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r#"\]\([^)\s]*(?:\s+"[^"\n]*")?\)"#).unwrap());
+
+/// Reference link definitions (`[id]: https://...`)
+static REFERENCE_DEFINITION: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"(?m)^ {0,3}\[[^\]\n]+\]:[^\n]*$").unwrap());
+
+/// Liquid tags (`{% embed ... %}`) and bare URLs
+static LIQUID_OR_URL: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"\{%.*?%\}|https?://[^\s<>()\[\]]+").unwrap());
+
+/// Placeholder left in the text sent for translation, one per protected span
+static PLACEHOLDER: Lazy<Regex> = Lazy::new(|| Regex::new(r#"<x i="(\d+)"\s*/>"#).unwrap());
+
+/// Language code accepted by `--translate` (`de`, `pt-br`, `en-gb`)
+static LANGUAGE_CODE: Lazy<Regex> = Lazy::new(|| Regex::new(r"^[a-z]{2}(-[a-z]{2,4})?$").unwrap());
+
+/// Markdown prepared for translation: spans that must not change replaced by XML
+/// placeholders, everything else escaped for XML tag handling
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ProtectedText {
+    /// Text to send (`<x i="0"/>` placeholders, `&`, `<`, `>` escaped)
+    pub text: String,
+
+    /// Original spans, indexed by placeholder number
+    pub spans: Vec<String>,
+}
+
+impl ProtectedText {
+    /// Put the original spans back into a translated text and unescape it
+    ///
+    /// Fails if the translation dropped a placeholder, since code would be lost.
+    pub fn restore(&self, translated: &str) -> Result<String> {
+        let mut output = String::with_capacity(translated.len());
+        let mut cursor = 0;
+        let mut restored = 0;
+
+        for captures in PLACEHOLDER.captures_iter(translated) {
+            let whole = captures.get(0).expect("match has a whole group");
+            let span = captures[1]
+                .parse::<usize>()
+                .ok()
+                .and_then(|i| self.spans.get(i))
+                .context("Translation contains an unknown placeholder")?;
+
+            output.push_str(&unescape_xml(&translated[cursor..whole.start()]));
+            output.push_str(span);
+            cursor = whole.end();
+            restored += 1;
+        }
+        output.push_str(&unescape_xml(&translated[cursor..]));
+
+        if restored != self.spans.len() {
+            anyhow::bail!(
+                "Translation lost {} of {} code blocks, links or URLs",
+                self.spans.len().saturating_sub(restored),
+                self.spans.len()
+            );
+        }
+
+        Ok(output)
+    }
+}
+
+/// Prepare markdown for translation
+///
+/// Code blocks, inline code, raw HTML, link destinations, reference definitions,
+/// Liquid tags and URLs are replaced with placeholders so they come back unchanged.
+pub fn protect_markdown(markdown: &str) -> ProtectedText {
+    let mut ranges: Vec<Range<usize>> = Vec::new();
+
+    let mut options = Options::empty();
+    options.insert(Options::ENABLE_TABLES);
+    options.insert(Options::ENABLE_FOOTNOTES);
+    options.insert(Options::ENABLE_STRIKETHROUGH);
+    options.insert(Options::ENABLE_TASKLISTS);
+    for (event, range) in Parser::new_ext(markdown, options).into_offset_iter() {
+        match event {
+            Event::Start(Tag::CodeBlock(_))
+            | Event::Code(_)
+            | Event::Html(_)
+            | Event::InlineHtml(_) => ranges.push(range),
+            _ => {}
+        }
+    }
+    for pattern in [&*LINK_DESTINATION, &*REFERENCE_DEFINITION, &*LIQUID_OR_URL] {
+        ranges.extend(pattern.find_iter(markdown).map(|m| m.range()));
+    }
+
+    // Code blocks contain their code spans and URLs; keep the outermost range
+    ranges.sort_by_key(|range| (range.start, std::cmp::Reverse(range.end)));
+    let mut text = String::with_capacity(markdown.len());
+    let mut spans = Vec::new();
+    let mut cursor = 0;
+    for range in ranges {
+        if range.start < cursor || range.is_empty() {
+            continue;
+        }
+        text.push_str(&escape_xml(&markdown[cursor..range.start]));
+        text.push_str(&format!("<x i=\"{}\"/>", spans.len()));
+        spans.push(markdown[range.clone()].to_string());
+        cursor = range.end;
+    }
+    text.push_str(&escape_xml(&markdown[cursor..]));
+
+    ProtectedText { text, spans }
+}
+
+/// Escape text for XML tag handling
+pub fn escape_xml(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+}
+
+/// Undo XML escaping in translated text
+pub fn unescape_xml(text: &str) -> String {
+    text.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&apos;", "'")
+        .replace("&amp;", "&")
+}
+
+/// Normalize a `--translate` language code (`DE` -> `de`), rejecting anything else
+pub fn parse_language(code: &str) -> Result<String> {
+    let code = code.trim().to_lowercase();
+    if !LANGUAGE_CODE.is_match(&code) {
+        anyhow::bail!(
+            "Invalid language code '{}' (expected e.g. de, es or pt-br)",
+            code
+        );
+    }
+
+    Ok(code)
+}
+
+/// File a translation of an article is kept in: `<name>.<lang>.md` next to the original
+pub fn translation_path(original: &Path, language: &str) -> PathBuf {
+    let stem = original
+        .file_stem()
+        .map(|stem| stem.to_string_lossy().into_owned())
+        .unwrap_or_default();
+
+    original.with_file_name(format!("{}.{}.md", stem, language))
+}
+
+/// Translate an article's title, description and body into a language
+///
+/// Tags and metadata are kept; the slug gets a `-<lang>` suffix, `lang` is set and the
+/// canonical URL is set to `canonical_url` (the original's), so the copy is a separate
+/// article.
+pub async fn translate_article(
+    client: &DeepLClient,
+    article: &Article,
+    language: &str,
+    canonical_url: &str,
+) -> Result<Article> {
+    let body = protect_markdown(&article.content);
+    let mut texts = vec![escape_xml(&article.title), body.text.clone()];
+    if let Some(ref description) = article.description {
+        texts.push(escape_xml(description));
+    }
+
+    let translated = client.translate(&texts, language).await.context(format!(
+        "Failed to translate '{}' into {}",
+        article.title, language
+    ))?;
+    let [title, content, rest @ ..] = translated.as_slice() else {
+        anyhow::bail!("Translation service returned fewer texts than it was sent");
+    };
+
+    Ok(Article {
+        title: unescape_xml(title).trim().to_string(),
+        content: body.restore(content).context(format!(
+            "Failed to translate '{}' into {}",
+            article.title, language
+        ))?,
+        description: rest.first().map(|description| unescape_xml(description)),
+        canonical_url: Some(canonical_url.to_string()),
+        slug: article
+            .slug
+            .as_ref()
+            .map(|slug| format!("{}-{}", slug, language)),
+        syndication: Default::default(),
+        series: None,
+        series_part: None,
+        lang: Some(language.to_string()),
+        ..article.clone()
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_protect_markdown_keeps_code_and_links() {
+        let markdown = "# Using `Vec<T>`\n\nSee [the docs](https://doc.rust-lang.org \"Docs\") & more.\n\n\
+                        ```rust\nlet url = \"https://example.com\";\n```\n\n{% embed https://x.dev %}\n";
+        let protected = protect_markdown(markdown);
+
+        assert_eq!(
+            protected.text,
+            "# Using <x i=\"0\"/>\n\nSee [the docs<x i=\"1\"/> &amp; more.\n\n<x i=\"2\"/>\n\n<x i=\"3\"/>\n"
+        );
+        assert_eq!(protected.spans[0], "`Vec<T>`");
+        assert_eq!(protected.spans[1], "](https://doc.rust-lang.org \"Docs\")");
+        assert!(protected.spans[2].starts_with("```rust\n"));
+        assert_eq!(protected.restore(&protected.text).unwrap(), markdown);
+    }
+
+    #[test]
+    fn test_restore_translated_text() {
+        let protected = protect_markdown("Call `run()` & wait.");
+        let restored = protected
+            .restore("Rufe <x i=\"0\" /> auf &amp; warte.")
+            .unwrap();
+        assert_eq!(restored, "Rufe `run()` auf & warte.");
+
+        assert!(protected.restore("Rufe auf und warte.").is_err());
+    }
+
+    #[test]
+    fn test_language_and_path() {
+        assert_eq!(parse_language("DE").unwrap(), "de");
+        assert_eq!(parse_language("pt-BR").unwrap(), "pt-br");
+        assert!(parse_language("german").is_err());
+        assert_eq!(
+            translation_path(Path::new("posts/hello.md"), "de"),
+            PathBuf::from("posts/hello.de.md")
+        );
+    }
+}
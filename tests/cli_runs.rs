@@ -0,0 +1,265 @@
+//! End-to-end runs of the binary against a mock server
+//!
+//! Each test gets its own config directory (config, state and articles), with both
+//! platforms pointed at the mock server.
+
+use std::fs;
+use std::path::PathBuf;
+use std::process::Output;
+use tempfile::TempDir;
+use wiremock::matchers::{body_partial_json, method, path};
+use wiremock::{Mock, MockServer, Request, ResponseTemplate};
+
+/// Medium user ID served by `/v1/me`
+const MEDIUM_USER: &str = "5303d74c64f66366f00cb9b2a94f3251bf5";
+
+/// A throwaway home with a config pointing at the mock server
+struct Sandbox {
+    dir: TempDir,
+}
+
+impl Sandbox {
+    fn new(server: &MockServer) -> Self {
+        let dir = TempDir::new().unwrap();
+        let config_dir = dir.path().join("config/article-cross-poster");
+        fs::create_dir_all(&config_dir).unwrap();
+        fs::write(
+            config_dir.join("config.toml"),
+            format!(
+                "[dev_to]\napi_key = \"test-key\"\nbase_url = \"{uri}/api\"\n\n\
+                 [medium]\naccess_token = \"test-token\"\nbase_url = \"{uri}/v1\"\n\
+                 feed_url = \"{uri}\"\n",
+                uri = server.uri()
+            ),
+        )
+        .unwrap();
+
+        Self { dir }
+    }
+
+    /// Write a file relative to the sandbox
+    fn write(&self, name: &str, content: &str) -> PathBuf {
+        let path = self.dir.path().join(name);
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(&path, content).unwrap();
+        path
+    }
+
+    /// Append text to a file relative to the sandbox
+    fn append(&self, name: &str, text: &str) {
+        let path = self.dir.path().join(name);
+        let content = fs::read_to_string(&path).unwrap();
+        fs::write(&path, content + text).unwrap();
+    }
+
+    fn path(&self, name: &str) -> PathBuf {
+        self.dir.path().join(name)
+    }
+
+    /// Run the binary with the sandbox as home and config directory
+    async fn run(&self, args: &[&str]) -> Output {
+        let home = self.dir.path().to_path_buf();
+        let args: Vec<String> = args.iter().map(|arg| arg.to_string()).collect();
+        tokio::task::spawn_blocking(move || {
+            std::process::Command::new(env!("CARGO_BIN_EXE_article-cross-poster"))
+                .args(&args)
+                .current_dir(&home)
+                .env("HOME", &home)
+                .env("XDG_CONFIG_HOME", home.join("config"))
+                .env_remove("GITHUB_ACTIONS")
+                .output()
+                .unwrap()
+        })
+        .await
+        .unwrap()
+    }
+}
+
+/// Write a two-part series into `series/`
+fn write_series(sandbox: &Sandbox) -> PathBuf {
+    sandbox.write(
+        "series/part-one.md",
+        "---\ntitle: Part One\nseries: Demo\nseries_part: 1\n---\n\nFirst part.\n",
+    );
+    sandbox.write(
+        "series/part-two.md",
+        "---\ntitle: Part Two\nseries: Demo\nseries_part: 2\n---\n\nSecond part.\n",
+    );
+    sandbox.path("series")
+}
+
+/// Answer dev.to creates of an article with `title`
+async fn devto_creates(server: &MockServer, title: &str, id: u64) {
+    Mock::given(method("POST"))
+        .and(path("/api/articles"))
+        .and(body_partial_json(
+            serde_json::json!({ "article": { "title": title } }),
+        ))
+        .respond_with(ResponseTemplate::new(201).set_body_json(serde_json::json!({
+            "id": id,
+            "url": format!("https://dev.to/jane/article-{}", id)
+        })))
+        .mount(server)
+        .await;
+}
+
+/// True for a dev.to request that turns an article back into a draft
+fn is_unpublish(request: &Request) -> bool {
+    let body: serde_json::Value = serde_json::from_slice(&request.body).unwrap_or_default();
+    request.method.as_str() == "PUT"
+        && body["article"]["published"] == false
+        && body["article"].get("title").is_none()
+}
+
+fn assert_success(output: &Output) {
+    assert!(
+        output.status.success(),
+        "{}\n{}",
+        String::from_utf8_lossy(&output.stdout),
+        String::from_utf8_lossy(&output.stderr)
+    );
+}
+
+#[tokio::test]
+async fn test_series_rerun_keeps_live_parts_published() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/api/articles/me/all"))
+        .respond_with(ResponseTemplate::new(200).set_body_string("[]"))
+        .mount(&server)
+        .await;
+    devto_creates(&server, "Part One", 1).await;
+    devto_creates(&server, "Part Two", 2).await;
+    Mock::given(method("PUT"))
+        .and(path("/api/articles/1"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "id": 1,
+            "url": "https://dev.to/jane/article-1"
+        })))
+        .mount(&server)
+        .await;
+    Mock::given(method("PUT"))
+        .and(path("/api/articles/2"))
+        .respond_with(ResponseTemplate::new(422).set_body_string("{\"error\":\"Body is invalid\"}"))
+        .mount(&server)
+        .await;
+    let sandbox = Sandbox::new(&server);
+    let series = write_series(&sandbox);
+    let series = series.to_str().unwrap();
+
+    assert_success(&sandbox.run(&["post-series", series, "--to", "devto"]).await);
+
+    // Both parts change; the update of part 2 is rejected
+    sandbox.append("series/part-one.md", "\nMore.\n");
+    sandbox.append("series/part-two.md", "\nMore.\n");
+    let output = sandbox.run(&["post-series", series, "--to", "devto"]).await;
+
+    assert!(!output.status.success());
+    let requests = server.received_requests().await.unwrap();
+    assert!(!requests.iter().any(is_unpublish));
+    let part_one_updates = requests
+        .iter()
+        .filter(|r| r.method.as_str() == "PUT" && r.url.path() == "/api/articles/1")
+        .count();
+    // Linked after run 1, updated in run 2, and never unpublished
+    assert_eq!(part_one_updates, 2);
+}
+
+#[tokio::test]
+async fn test_series_rerun_keeps_medium_posts() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/v1/me"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "data": { "id": MEDIUM_USER, "username": "jane", "name": "Jane Doe" }
+        })))
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/feed/@jane"))
+        .respond_with(ResponseTemplate::new(200).set_body_string(
+            "<?xml version=\"1.0\"?><rss version=\"2.0\"><channel><title>Stories</title></channel></rss>",
+        ))
+        .mount(&server)
+        .await;
+    for (title, id) in [("Part One", "p1"), ("Part Two", "p2")] {
+        Mock::given(method("POST"))
+            .and(path(format!("/v1/users/{}/posts", MEDIUM_USER)))
+            .and(body_partial_json(serde_json::json!({ "title": title })))
+            .respond_with(ResponseTemplate::new(201).set_body_json(serde_json::json!({
+                "data": { "id": id, "url": format!("https://medium.com/@jane/{}", id) }
+            })))
+            .mount(&server)
+            .await;
+    }
+    let sandbox = Sandbox::new(&server);
+    let series = write_series(&sandbox);
+    let series = series.to_str().unwrap();
+
+    assert_success(
+        &sandbox
+            .run(&["post-series", series, "--to", "medium"])
+            .await,
+    );
+    // Part 1 now links to part 2, but Medium posts can't be updated
+    let output = sandbox
+        .run(&["post-series", series, "--to", "medium"])
+        .await;
+
+    assert_success(&output);
+    assert!(String::from_utf8_lossy(&output.stdout).contains("already published"));
+    let posts = server
+        .received_requests()
+        .await
+        .unwrap()
+        .iter()
+        .filter(|r| r.method.as_str() == "POST")
+        .count();
+    assert_eq!(posts, 2);
+}
+
+#[tokio::test]
+async fn test_failed_translation_is_recorded_per_platform() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/api/articles/me/all"))
+        .respond_with(ResponseTemplate::new(200).set_body_string("[]"))
+        .mount(&server)
+        .await;
+    devto_creates(&server, "Original", 1).await;
+    let sandbox = Sandbox::new(&server);
+    // No DeepL key is configured, so the translation can't be made
+    let article = sandbox.write(
+        "original.md",
+        "---\ntitle: Original\ncanonical_url: https://blog.example.com/original/\n---\n\nBody.\n",
+    );
+    let manifest = sandbox.path("manifest.json");
+
+    sandbox
+        .run(&[
+            "post",
+            article.to_str().unwrap(),
+            "--to",
+            "devto",
+            "--translate",
+            "de",
+            "--manifest",
+            manifest.to_str().unwrap(),
+        ])
+        .await;
+
+    let manifest: serde_json::Value =
+        serde_json::from_str(&fs::read_to_string(&manifest).unwrap()).unwrap();
+    let translation = &manifest["articles"][1];
+    assert!(translation["source"]
+        .as_str()
+        .unwrap()
+        .ends_with("original.de.md"));
+    assert_eq!(translation["platforms"][0]["platform"], "devto");
+    assert_eq!(translation["platforms"][0]["status"], "failed");
+    assert!(translation["platforms"][0]["message"]
+        .as_str()
+        .unwrap()
+        .starts_with("Translation into de failed: "));
+    assert_eq!(manifest["success"], false);
+}
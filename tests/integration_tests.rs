@@ -1,6 +1,6 @@
 use article_cross_poster::cli::{ArticleState, Config};
 use article_cross_poster::models::{Article, ArticleSummary};
-use article_cross_poster::parsers::{clean_ai_artifacts, parse_markdown};
+use article_cross_poster::parsers::{clean_ai_artifacts, parse_markdown, SanitizeOptions};
 use std::fs;
 use std::path::PathBuf;
 use tempfile::TempDir;
@@ -271,6 +271,33 @@ fn test_article_builder_pattern() {
     assert_eq!(article.description, Some("Description".to_string()));
 }
 
+#[test]
+fn test_article_duplicate_detection() {
+    let article = Article::new("My Article".to_string(), "Content".to_string())
+        .with_canonical_url("https://blog.example.com/my-article/".to_string());
+
+    // Same title, different case and surrounding whitespace
+    assert!(article.is_duplicate_of("  my article ", None));
+
+    // Different title but same canonical URL (trailing slash ignored)
+    assert!(article.is_duplicate_of(
+        "Renamed Article",
+        Some("https://blog.example.com/my-article")
+    ));
+
+    // Neither title nor canonical URL match
+    assert!(!article.is_duplicate_of("Other Article", Some("https://blog.example.com/other")));
+    assert!(!article.is_duplicate_of("Other Article", None));
+
+    // A translation shares the original's canonical URL but is a separate article
+    let translation = article.clone().with_lang("de".to_string());
+    assert!(!translation.is_duplicate_of(
+        "Other Article",
+        Some("https://blog.example.com/my-article/")
+    ));
+    assert!(translation.is_duplicate_of("My Article", None));
+}
+
 #[test]
 fn test_markdown_with_complex_content() {
     let markdown = r#"---
@@ -424,6 +451,131 @@ fn test_title_prepended_when_only_h2() {
     assert!(result.contains("## Introduction"));
 }
 
+// Content statistics tests
+
+#[test]
+fn test_content_stats_for_parsed_article() {
+    use article_cross_poster::parsers::compute_stats;
+
+    let markdown = r#"---
+title: Stats Article
+tags: [rust]
+---
+
+Some intro text with a [link](https://example.com).
+
+## Example
+
+```rust
+fn main() {}
+```
+
+![diagram](https://example.com/diagram.png)
+"#;
+
+    let article = parse_markdown(markdown).unwrap();
+    let stats = compute_stats(&article.content);
+
+    assert_eq!(stats.word_count, 7);
+    assert_eq!(stats.headings.len(), 1);
+    assert_eq!(stats.headings[0].text, "Example");
+    assert_eq!(stats.code_block_count, 1);
+    assert_eq!(stats.image_count, 1);
+    assert_eq!(stats.link_count, 1);
+}
+
+#[test]
+fn test_publish_request_payloads_build_offline() {
+    use article_cross_poster::cli::ContentFormat;
+    use article_cross_poster::platforms::{DevToClient, MediumClient};
+
+    let article = Article::new("Payload".to_string(), "Body text".to_string())
+        .with_tags(vec!["web-dev".to_string()]);
+
+    let devto = DevToClient::build_publish_request(&article, &SanitizeOptions::default()).unwrap();
+    let devto_json = serde_json::to_value(&devto.request).unwrap();
+    assert_eq!(devto_json["article"]["tags"][0], "webdev");
+    assert_eq!(devto.warnings.len(), 1);
+
+    let medium = MediumClient::build_publish_request(
+        &article,
+        &ContentFormat::Html,
+        &SanitizeOptions::default(),
+    )
+    .unwrap();
+    let medium_json = serde_json::to_value(&medium.request).unwrap();
+    assert_eq!(medium_json["contentFormat"], "html");
+    assert!(medium_json["content"]
+        .as_str()
+        .unwrap()
+        .contains("<h1>Payload</h1>"));
+}
+
+#[test]
+fn test_backdated_article_payloads() {
+    use article_cross_poster::cli::ContentFormat;
+    use article_cross_poster::platforms::{DevToClient, MediumClient};
+
+    let article =
+        parse_markdown("---\ntitle: Archived\ndate: 2019-05-04\n---\n\nBody text").unwrap();
+
+    let devto = DevToClient::build_publish_request(&article, &SanitizeOptions::default()).unwrap();
+    let devto_json = serde_json::to_value(&devto.request).unwrap();
+    assert_eq!(
+        devto_json["article"]["published_at"],
+        "2019-05-04T00:00:00Z"
+    );
+
+    let medium = MediumClient::build_publish_request(
+        &article,
+        &ContentFormat::Markdown,
+        &SanitizeOptions::default(),
+    )
+    .unwrap();
+    assert!(medium.warnings[0].contains("not 2019-05-04"));
+
+    // Drafts and articles dated today are sent without a date
+    let draft = article.clone().with_published(false);
+    let devto = DevToClient::build_publish_request(&draft, &SanitizeOptions::default()).unwrap();
+    assert!(serde_json::to_value(&devto.request).unwrap()["article"]
+        .get("published_at")
+        .is_none());
+    let today = article.with_published_at(chrono::Utc::now());
+    assert_eq!(today.backdate(), None);
+}
+
+#[test]
+fn test_request_previews_show_transformed_content() {
+    use article_cross_poster::cli::ContentFormat;
+    use article_cross_poster::platforms::{DevToClient, MediumClient, PreviewRequest};
+
+    let article = Article::new(
+        "Preview".to_string(),
+        "Intro {% embed https://example.com %} text.".to_string(),
+    )
+    .with_tags(vec!["web-dev".to_string()]);
+
+    let devto = DevToClient::build_publish_request(&article, &SanitizeOptions::default())
+        .unwrap()
+        .request
+        .preview();
+    assert_eq!(devto.format, "markdown");
+    assert!(devto.fields.contains(&("Tags", "webdev".to_string())));
+    assert!(devto.body.contains("{% embed"));
+
+    let medium = MediumClient::build_publish_request(
+        &article,
+        &ContentFormat::Markdown,
+        &SanitizeOptions::default(),
+    )
+    .unwrap()
+    .request
+    .preview();
+    assert_eq!(medium.fields[0], ("Title", "Preview".to_string()));
+    assert!(medium.body.starts_with("# Preview"));
+    assert!(!medium.body.contains("{%"));
+}
+
 // ArticleSummary tests
 
 #[test]
@@ -541,3 +693,67 @@ fn test_medium_rss_parsing() {
     assert_eq!(second.categories.len(), 1);
     assert_eq!(second.categories[0].term, "web");
 }
+
+#[test]
+fn test_medium_payload_converts_footnotes() {
+    use article_cross_poster::cli::ContentFormat;
+    use article_cross_poster::platforms::{DevToClient, MediumClient};
+
+    let content = "---\ntitle: Notes\n---\n\nClaim[^src].\n\n[^src]: The source.\n";
+    let article = parse_markdown(content).unwrap();
+
+    let medium = MediumClient::build_publish_request(
+        &article,
+        &ContentFormat::Markdown,
+        &SanitizeOptions::default(),
+    )
+    .unwrap();
+    let medium_json = serde_json::to_value(&medium.request).unwrap();
+    let body = medium_json["content"].as_str().unwrap();
+    assert!(body.contains("Claim[1]."));
+    assert!(body.contains("**Notes**\n\n1. The source."));
+
+    let devto = DevToClient::build_publish_request(&article, &SanitizeOptions::default()).unwrap();
+    let devto_json = serde_json::to_value(&devto.request).unwrap();
+    assert!(devto_json["article"]["body_markdown"]
+        .as_str()
+        .unwrap()
+        .contains("[^src]: The source."));
+}
+
+#[test]
+fn test_local_cover_image_becomes_upload_asset() {
+    use article_cross_poster::platforms::{DevToClient, ResolveAssets};
+
+    let temp_dir = TempDir::new().unwrap();
+    fs::create_dir_all(temp_dir.path().join("img")).unwrap();
+    fs::write(temp_dir.path().join("img/cover.png"), b"not really a png").unwrap();
+
+    let mut article =
+        parse_markdown("---\ntitle: Cover\ncover_image: img/cover.png\n---\n\nBody").unwrap();
+    article.resolve_cover_path(temp_dir.path());
+
+    let prepared =
+        DevToClient::build_publish_request(&article, &SanitizeOptions::default()).unwrap();
+    assert_eq!(prepared.assets.len(), 1);
+    assert_eq!(prepared.assets[0].content_type, "image/png");
+
+    let placeholder = prepared.assets[0].placeholder();
+    let mut request = prepared.request;
+    let json = serde_json::to_value(&request).unwrap();
+    assert_eq!(json["article"]["main_image"], placeholder.as_str());
+
+    request.resolve_asset(&placeholder, "https://cdn.example.com/cover.png");
+    let json = serde_json::to_value(&request).unwrap();
+    assert_eq!(
+        json["article"]["main_image"],
+        "https://cdn.example.com/cover.png"
+    );
+
+    // Hosted URLs are passed through untouched
+    let hosted = Article::new("Hosted".to_string(), "Body".to_string())
+        .with_cover_image("https://example.com/cover.png".to_string());
+    let prepared =
+        DevToClient::build_publish_request(&hosted, &SanitizeOptions::default()).unwrap();
+    assert!(prepared.assets.is_empty());
+}
@@ -0,0 +1,779 @@
+//! Platform, translation and LLM client tests against a local mock server
+//!
+//! Success flows replay fixtures from `tests/fixtures/`, recorded with `--record-http`;
+//! error responses are mocked inline.
+
+use article_cross_poster::cli::ContentFormat;
+use article_cross_poster::llm::{suggest_metadata, LlmClient};
+use article_cross_poster::models::Article;
+use article_cross_poster::parsers::SanitizeOptions;
+use article_cross_poster::platforms::fixtures::Fixture;
+use article_cross_poster::platforms::{DevToClient, MediumClient};
+use article_cross_poster::translate::{translate_article, DeepLClient};
+use std::path::Path;
+use wiremock::matchers::{body_partial_json, header, method, path, query_param};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+/// Load a fixture from `tests/fixtures/`
+fn fixture(name: &str) -> Fixture {
+    Fixture::load(
+        &Path::new(env!("CARGO_MANIFEST_DIR"))
+            .join("tests/fixtures")
+            .join(name),
+    )
+    .unwrap()
+}
+
+/// Serve a fixture's responses, each exactly once and in recording order
+async fn replay(server: &MockServer, fixture: &Fixture) {
+    for exchange in &fixture.exchanges {
+        let url = reqwest::Url::parse(&format!("http://fixture{}", exchange.path)).unwrap();
+
+        let mut response =
+            ResponseTemplate::new(exchange.status).set_body_string(exchange.response_body.clone());
+        for (name, value) in &exchange.response_headers {
+            response = response.insert_header(name.as_str(), value.as_str());
+        }
+
+        let mut mock = Mock::given(method(exchange.method.as_str())).and(path(url.path()));
+        for (name, value) in url.query_pairs() {
+            mock = mock.and(query_param(name, value));
+        }
+        mock.respond_with(response)
+            .up_to_n_times(1)
+            .expect(1)
+            .mount(server)
+            .await;
+    }
+}
+
+/// Check that the requests sent match the fixture, including the recorded JSON bodies
+async fn assert_replayed(server: &MockServer, fixture: &Fixture) {
+    let received = server.received_requests().await.unwrap();
+    assert_eq!(received.len(), fixture.exchanges.len());
+
+    for (request, exchange) in received.iter().zip(&fixture.exchanges) {
+        assert_eq!(request.method.as_str(), exchange.method);
+        if let Some(ref expected) = exchange.request_body {
+            let body: serde_json::Value = serde_json::from_slice(&request.body).unwrap();
+            assert_eq!(&body, expected, "request body of {}", exchange.path);
+        }
+    }
+}
+
+fn test_article() -> Article {
+    Article::new("Hello Fixtures".to_string(), "Recorded body.\n".to_string())
+        .with_tags(vec!["rust".to_string(), "testing".to_string()])
+        .with_canonical_url("https://blog.example.com/hello-fixtures/".to_string())
+}
+
+fn devto_request() -> article_cross_poster::platforms::devto::DevToPublishRequest {
+    DevToClient::build_publish_request(&test_article(), &SanitizeOptions::default())
+        .unwrap()
+        .request
+}
+
+fn medium_request() -> article_cross_poster::platforms::medium::MediumPublishRequest {
+    MediumClient::build_publish_request(
+        &test_article(),
+        &ContentFormat::Markdown,
+        &SanitizeOptions::default(),
+    )
+    .unwrap()
+    .request
+}
+
+/// An entry of dev.to's `/articles/me/all` list with `test_article`'s canonical URL
+fn listed_article(id: u64, title: &str, url: &str, published_at: &str) -> serde_json::Value {
+    serde_json::json!({
+        "id": id,
+        "title": title,
+        "url": url,
+        "published": true,
+        "published_at": published_at,
+        "tag_list": ["rust", "testing"],
+        "canonical_url": "https://blog.example.com/hello-fixtures/",
+        "body_markdown": "Recorded body.\n"
+    })
+}
+
+fn devto_client(server: &MockServer) -> DevToClient {
+    DevToClient::new("test-key".to_string()).with_base_url(format!("{}/api", server.uri()))
+}
+
+fn medium_client(server: &MockServer) -> MediumClient {
+    MediumClient::new("test-token".to_string())
+        .with_base_url(format!("{}/v1", server.uri()))
+        .with_feed_base_url(server.uri())
+}
+
+/// Mount a single response for a path
+async fn respond(
+    server: &MockServer,
+    http_method: &str,
+    url_path: &str,
+    response: ResponseTemplate,
+) {
+    Mock::given(method(http_method))
+        .and(path(url_path))
+        .respond_with(response)
+        .mount(server)
+        .await;
+}
+
+#[tokio::test]
+async fn test_devto_publish_and_list_fixture() {
+    let server = MockServer::start().await;
+    let fixture = fixture("devto/publish_and_list.json");
+    replay(&server, &fixture).await;
+    let client = devto_client(&server);
+
+    let published = client.publish_request(&devto_request()).await.unwrap();
+    assert_eq!(published.id, "101");
+    assert_eq!(published.url, "https://dev.to/jane/hello-fixtures-1a2b");
+
+    let articles = client.list_articles(1, 30, "published").await.unwrap();
+    assert_eq!(articles.len(), 1);
+    assert_eq!(articles[0].title, "Hello Fixtures");
+    assert_eq!(articles[0].tags, vec!["rust", "testing"]);
+
+    assert_replayed(&server, &fixture).await;
+}
+
+#[tokio::test]
+async fn test_devto_unauthorized() {
+    let server = MockServer::start().await;
+    respond(
+        &server,
+        "POST",
+        "/api/articles",
+        ResponseTemplate::new(401).set_body_string("{\"error\":\"unauthorized\",\"status\":401}"),
+    )
+    .await;
+
+    let error = devto_client(&server)
+        .publish_request(&devto_request())
+        .await
+        .unwrap_err()
+        .to_string();
+
+    assert!(error.contains("Invalid API key"));
+    assert!(error.contains("status 401"));
+}
+
+#[tokio::test]
+async fn test_devto_rate_limit_is_retried() {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/api/articles"))
+        .respond_with(ResponseTemplate::new(429).insert_header("retry-after", "0"))
+        .up_to_n_times(1)
+        .mount(&server)
+        .await;
+    respond(
+        &server,
+        "POST",
+        "/api/articles",
+        ResponseTemplate::new(201)
+            .set_body_string("{\"id\":7,\"url\":\"https://dev.to/jane/retried-7\"}"),
+    )
+    .await;
+
+    let published = devto_client(&server)
+        .publish_request(&devto_request())
+        .await
+        .unwrap();
+
+    assert_eq!(published.id, "7");
+    assert_eq!(server.received_requests().await.unwrap().len(), 2);
+}
+
+#[tokio::test]
+async fn test_devto_validation_error() {
+    let server = MockServer::start().await;
+    respond(
+        &server,
+        "POST",
+        "/api/articles",
+        ResponseTemplate::new(422)
+            .set_body_string("{\"error\":\"Tag \\\"rust-lang\\\" contains non-alphanumeric characters\",\"status\":422}"),
+    )
+    .await;
+
+    let error = devto_client(&server)
+        .publish_request(&devto_request())
+        .await
+        .unwrap_err()
+        .to_string();
+
+    assert!(error.contains("Article validation failed"));
+    assert!(error.contains("contains non-alphanumeric characters"));
+    assert!(error.contains("Title: 'Hello Fixtures'"));
+}
+
+#[tokio::test]
+async fn test_devto_comments_are_threaded() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/api/comments"))
+        .and(query_param("a_id", "42"))
+        .respond_with(ResponseTemplate::new(200).set_body_string(
+            r#"[{"type_of":"comment","id_code":"m1","created_at":"2025-01-03T10:00:00Z",
+                 "body_html":"<p>Great post</p>","user":{"name":"Jane","username":"jane"},
+                 "children":[{"type_of":"comment","id_code":"m2","body_html":"<p>Thanks!</p>",
+                              "user":{"name":"Sam","username":"sam"},"children":[]}]},
+                {"type_of":"comment","id_code":"m3","body_html":"<p>[deleted]</p>","children":[]}]"#,
+        ))
+        .mount(&server)
+        .await;
+
+    let comments = devto_client(&server).fetch_comments("42").await.unwrap();
+
+    assert_eq!(comments.len(), 2);
+    assert_eq!(comments[0].user.as_ref().unwrap().username, "jane");
+    assert_eq!(comments[0].children[0].id_code, "m2");
+    assert!(comments[1].user.is_none());
+}
+
+#[tokio::test]
+async fn test_medium_publish_and_list_fixture() {
+    let server = MockServer::start().await;
+    let fixture = fixture("medium/publish_and_list.json");
+    replay(&server, &fixture).await;
+    let client = medium_client(&server);
+
+    let published = client.publish_request(&medium_request()).await.unwrap();
+    assert_eq!(published.id, "e6f36a");
+    assert_eq!(
+        published.url,
+        "https://medium.com/@jane/hello-fixtures-e6f36a"
+    );
+
+    let articles = client.list_articles().await.unwrap();
+    assert_eq!(articles.len(), 1);
+    assert_eq!(articles[0].title, "Hello Fixtures");
+    assert_eq!(articles[0].published_at, "2026-10-16");
+
+    assert_replayed(&server, &fixture).await;
+}
+
+#[tokio::test]
+async fn test_medium_unauthorized() {
+    let server = MockServer::start().await;
+    respond(
+        &server,
+        "GET",
+        "/v1/me",
+        ResponseTemplate::new(401)
+            .set_body_string("{\"errors\":[{\"message\":\"Token was invalid.\",\"code\":6003}]}"),
+    )
+    .await;
+
+    let error = medium_client(&server)
+        .publish_request(&medium_request())
+        .await
+        .unwrap_err()
+        .to_string();
+
+    assert!(error.contains("Invalid access token"));
+    assert!(error.contains("Token was invalid."));
+}
+
+#[tokio::test]
+async fn test_medium_rate_limit_is_retried() {
+    let server = MockServer::start().await;
+    let fixture = fixture("medium/publish_and_list.json");
+    let user = &fixture.exchanges[0];
+    respond(
+        &server,
+        "GET",
+        "/v1/me",
+        ResponseTemplate::new(200).set_body_string(user.response_body.clone()),
+    )
+    .await;
+    let posts = &fixture.exchanges[1];
+    Mock::given(method("POST"))
+        .and(path(posts.path.as_str()))
+        .respond_with(ResponseTemplate::new(429).insert_header("retry-after", "0"))
+        .up_to_n_times(1)
+        .mount(&server)
+        .await;
+    respond(
+        &server,
+        "POST",
+        &posts.path,
+        ResponseTemplate::new(201).set_body_string(posts.response_body.clone()),
+    )
+    .await;
+
+    let published = medium_client(&server)
+        .publish_request(&medium_request())
+        .await
+        .unwrap();
+
+    assert_eq!(published.id, "e6f36a");
+    assert_eq!(server.received_requests().await.unwrap().len(), 3);
+}
+
+#[tokio::test]
+async fn test_medium_validation_error() {
+    let server = MockServer::start().await;
+    let fixture = fixture("medium/publish_and_list.json");
+    respond(
+        &server,
+        "GET",
+        "/v1/me",
+        ResponseTemplate::new(200).set_body_string(fixture.exchanges[0].response_body.clone()),
+    )
+    .await;
+    respond(
+        &server,
+        "POST",
+        &fixture.exchanges[1].path,
+        ResponseTemplate::new(422)
+            .set_body_string("{\"errors\":[{\"message\":\"Title is too long.\",\"code\":2004}]}"),
+    )
+    .await;
+
+    let error = medium_client(&server)
+        .publish_request(&medium_request())
+        .await
+        .unwrap_err()
+        .to_string();
+
+    assert!(error.contains("Article validation failed"));
+    assert!(error.contains("Title is too long."));
+}
+
+#[tokio::test]
+async fn test_devto_create_after_gateway_timeout_is_not_duplicated() {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/api/articles"))
+        .respond_with(ResponseTemplate::new(504))
+        .expect(1)
+        .mount(&server)
+        .await;
+    // The article was created despite the 504
+    respond(
+        &server,
+        "GET",
+        "/api/articles/me/all",
+        ResponseTemplate::new(200).set_body_json(serde_json::json!([listed_article(
+            101,
+            "Hello Fixtures",
+            "https://dev.to/jane/hello-fixtures-1a2b",
+            &chrono::Utc::now().to_rfc3339()
+        )])),
+    )
+    .await;
+
+    let published = devto_client(&server)
+        .publish_request(&devto_request())
+        .await
+        .unwrap();
+
+    assert_eq!(published.id, "101");
+    assert_eq!(published.url, "https://dev.to/jane/hello-fixtures-1a2b");
+}
+
+#[tokio::test]
+async fn test_devto_create_after_gateway_timeout_ignores_older_copies() {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/api/articles"))
+        .respond_with(ResponseTemplate::new(504))
+        .up_to_n_times(1)
+        .mount(&server)
+        .await;
+    // Only older articles share the title or canonical URL: the request made nothing
+    respond(
+        &server,
+        "GET",
+        "/api/articles/me/all",
+        ResponseTemplate::new(200).set_body_json(serde_json::json!([
+            listed_article(
+                50,
+                "Hello Fixtures",
+                "https://dev.to/jane/hello-fixtures-old",
+                "2024-05-01T09:00:00Z"
+            ),
+            listed_article(
+                51,
+                "Hallo Fixtures",
+                "https://dev.to/jane/hallo-fixtures-old",
+                &chrono::Utc::now().to_rfc3339()
+            )
+        ])),
+    )
+    .await;
+    respond(
+        &server,
+        "POST",
+        "/api/articles",
+        ResponseTemplate::new(201)
+            .set_body_string("{\"id\":102,\"url\":\"https://dev.to/jane/hello-fixtures-new\"}"),
+    )
+    .await;
+
+    let published = devto_client(&server)
+        .publish_request(&devto_request())
+        .await
+        .unwrap();
+
+    assert_eq!(published.id, "102");
+    let posts = server
+        .received_requests()
+        .await
+        .unwrap()
+        .iter()
+        .filter(|request| request.method.as_str() == "POST")
+        .count();
+    assert_eq!(posts, 2);
+}
+
+#[tokio::test]
+async fn test_devto_create_is_retried_when_not_created() {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/api/articles"))
+        .respond_with(ResponseTemplate::new(503))
+        .up_to_n_times(1)
+        .mount(&server)
+        .await;
+    respond(
+        &server,
+        "GET",
+        "/api/articles/me/all",
+        ResponseTemplate::new(200).set_body_string("[]"),
+    )
+    .await;
+    respond(
+        &server,
+        "POST",
+        "/api/articles",
+        ResponseTemplate::new(201)
+            .set_body_string("{\"id\":8,\"url\":\"https://dev.to/jane/retried-8\"}"),
+    )
+    .await;
+
+    let published = devto_client(&server)
+        .publish_request(&devto_request())
+        .await
+        .unwrap();
+
+    assert_eq!(published.id, "8");
+}
+
+#[tokio::test]
+async fn test_medium_unverifiable_create_is_not_retried() {
+    let server = MockServer::start().await;
+    let fixture = fixture("medium/publish_and_list.json");
+    respond(
+        &server,
+        "GET",
+        "/v1/me",
+        ResponseTemplate::new(200).set_body_string(fixture.exchanges[0].response_body.clone()),
+    )
+    .await;
+    Mock::given(method("POST"))
+        .and(path(fixture.exchanges[1].path.as_str()))
+        .respond_with(ResponseTemplate::new(502))
+        .expect(1)
+        .mount(&server)
+        .await;
+    // The feed doesn't list the post (yet)
+    respond(
+        &server,
+        "GET",
+        "/feed/@jane",
+        ResponseTemplate::new(200).set_body_string(
+            "<?xml version=\"1.0\"?><rss version=\"2.0\"><channel><title>Stories</title></channel></rss>",
+        ),
+    )
+    .await;
+
+    let error = format!(
+        "{:#}",
+        medium_client(&server)
+            .publish_request(&medium_request())
+            .await
+            .unwrap_err()
+    );
+
+    assert!(error.contains("could not be verified"));
+    assert!(error.contains("status 502"));
+}
+
+/// An RSS feed with one post titled like `test_article`, published at `published`
+fn medium_feed_with(published: chrono::DateTime<chrono::Utc>) -> String {
+    format!(
+        "<?xml version=\"1.0\"?><rss version=\"2.0\"><channel><title>Stories</title>\
+         <item><title>Hello Fixtures</title>\
+         <link>https://medium.com/@jane/hello-fixtures-abc123def456?source=rss-jane</link>\
+         <guid>https://medium.com/p/abc123def456</guid>\
+         <pubDate>{}</pubDate></item></channel></rss>",
+        published.to_rfc2822()
+    )
+}
+
+#[tokio::test]
+async fn test_medium_create_after_gateway_timeout_is_not_duplicated() {
+    let server = MockServer::start().await;
+    let fixture = fixture("medium/publish_and_list.json");
+    respond(
+        &server,
+        "GET",
+        "/v1/me",
+        ResponseTemplate::new(200).set_body_string(fixture.exchanges[0].response_body.clone()),
+    )
+    .await;
+    Mock::given(method("POST"))
+        .and(path(fixture.exchanges[1].path.as_str()))
+        .respond_with(ResponseTemplate::new(504))
+        .expect(1)
+        .mount(&server)
+        .await;
+    respond(
+        &server,
+        "GET",
+        "/feed/@jane",
+        ResponseTemplate::new(200).set_body_string(medium_feed_with(chrono::Utc::now())),
+    )
+    .await;
+
+    let published = medium_client(&server)
+        .publish_request(&medium_request())
+        .await
+        .unwrap();
+
+    assert_eq!(published.id, "abc123def456");
+    assert_eq!(
+        published.url,
+        "https://medium.com/@jane/hello-fixtures-abc123def456"
+    );
+}
+
+#[tokio::test]
+async fn test_medium_create_after_gateway_timeout_ignores_older_posts() {
+    let server = MockServer::start().await;
+    let fixture = fixture("medium/publish_and_list.json");
+    respond(
+        &server,
+        "GET",
+        "/v1/me",
+        ResponseTemplate::new(200).set_body_string(fixture.exchanges[0].response_body.clone()),
+    )
+    .await;
+    Mock::given(method("POST"))
+        .and(path(fixture.exchanges[1].path.as_str()))
+        .respond_with(ResponseTemplate::new(504))
+        .expect(1)
+        .mount(&server)
+        .await;
+    // A post with the same title from last week, e.g. the original of a repost
+    respond(
+        &server,
+        "GET",
+        "/feed/@jane",
+        ResponseTemplate::new(200).set_body_string(medium_feed_with(
+            chrono::Utc::now() - chrono::Duration::days(7),
+        )),
+    )
+    .await;
+
+    let error = format!(
+        "{:#}",
+        medium_client(&server)
+            .publish_request(&medium_request())
+            .await
+            .unwrap_err()
+    );
+
+    assert!(error.contains("could not be verified"));
+    assert!(error.contains("status 504"));
+}
+
+#[tokio::test]
+async fn test_deepl_translation_keeps_code() {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/v2/translate"))
+        .and(header("authorization", "DeepL-Auth-Key test-key:fx"))
+        .and(body_partial_json(serde_json::json!({
+            "text": ["Hello Fixtures", "Run <x i=\"0\"/> &amp; relax.\n"],
+            "target_lang": "DE",
+            "tag_handling": "xml",
+            "ignore_tags": ["x"]
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "translations": [
+                { "detected_source_language": "EN", "text": "Hallo Fixtures" },
+                { "detected_source_language": "EN", "text": "Führe <x i=\"0\"/> aus &amp; entspanne.\n" }
+            ]
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+    let client =
+        DeepLClient::new("test-key:fx".to_string()).with_base_url(format!("{}/v2", server.uri()));
+    let article = Article::new(
+        "Hello Fixtures".to_string(),
+        "Run `cargo test` & relax.\n".to_string(),
+    )
+    .with_slug("hello-fixtures".to_string());
+
+    let translated = translate_article(&client, &article, "de", "https://blog.example.com/hello/")
+        .await
+        .unwrap();
+
+    assert_eq!(translated.title, "Hallo Fixtures");
+    assert_eq!(translated.content, "Führe `cargo test` aus & entspanne.\n");
+    assert_eq!(translated.slug.as_deref(), Some("hello-fixtures-de"));
+    assert_eq!(
+        translated.canonical_url.as_deref(),
+        Some("https://blog.example.com/hello/")
+    );
+}
+
+#[tokio::test]
+async fn test_devto_original_and_translation_in_one_run() {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/v2/translate"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "translations": [
+                { "detected_source_language": "EN", "text": "Hallo Fixtures" },
+                { "detected_source_language": "EN", "text": "Aufgezeichneter Text.\n" }
+            ]
+        })))
+        .mount(&server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/api/articles"))
+        .and(body_partial_json(
+            serde_json::json!({ "article": { "title": "Hello Fixtures" } }),
+        ))
+        .respond_with(
+            ResponseTemplate::new(201)
+                .set_body_string("{\"id\":1,\"url\":\"https://dev.to/jane/hello-fixtures-1\"}"),
+        )
+        .expect(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/api/articles"))
+        .and(body_partial_json(
+            serde_json::json!({ "article": { "title": "Hallo Fixtures" } }),
+        ))
+        .respond_with(
+            ResponseTemplate::new(201)
+                .set_body_string("{\"id\":2,\"url\":\"https://dev.to/jane/hallo-fixtures-2\"}"),
+        )
+        .expect(1)
+        .mount(&server)
+        .await;
+    // Once posted, the original is listed with its canonical URL
+    respond(
+        &server,
+        "GET",
+        "/api/articles/me/all",
+        ResponseTemplate::new(200).set_body_json(serde_json::json!([{
+            "id": 1,
+            "title": "Hello Fixtures",
+            "url": "https://dev.to/jane/hello-fixtures-1",
+            "published_at": "2025-01-02T03:04:05Z",
+            "tag_list": ["rust", "testing"],
+            "canonical_url": "https://blog.example.com/hello-fixtures/",
+            "body_markdown": "Recorded body.\n",
+            "published": true
+        }])),
+    )
+    .await;
+    let devto = devto_client(&server);
+    let deepl =
+        DeepLClient::new("test-key:fx".to_string()).with_base_url(format!("{}/v2", server.uri()));
+    let original = test_article();
+
+    devto.publish_request(&devto_request()).await.unwrap();
+    let translation = translate_article(
+        &deepl,
+        &original,
+        "de",
+        original.canonical_url.as_deref().unwrap(),
+    )
+    .await
+    .unwrap();
+
+    // The translation shares the canonical URL, but is not a copy of the original
+    assert!(devto
+        .find_existing_article(&original)
+        .await
+        .unwrap()
+        .is_some());
+    assert!(devto
+        .find_existing_article(&translation)
+        .await
+        .unwrap()
+        .is_none());
+    let request = DevToClient::build_publish_request(&translation, &SanitizeOptions::default())
+        .unwrap()
+        .request;
+    let published = devto.publish_request(&request).await.unwrap();
+
+    assert_eq!(published.id, "2");
+    assert_eq!(translation.lang.as_deref(), Some("de"));
+}
+
+#[tokio::test]
+async fn test_deepl_quota_exceeded() {
+    let server = MockServer::start().await;
+    respond(
+        &server,
+        "POST",
+        "/v2/translate",
+        ResponseTemplate::new(456).set_body_string("{\"message\":\"Quota exceeded\"}"),
+    )
+    .await;
+
+    let error = DeepLClient::new("test-key".to_string())
+        .with_base_url(format!("{}/v2", server.uri()))
+        .translate(&["Hello".to_string()], "es")
+        .await
+        .unwrap_err()
+        .to_string();
+
+    assert!(error.contains("quota exceeded"));
+    assert!(error.contains("status 456"));
+}
+
+#[tokio::test]
+async fn test_llm_suggestions() {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/v1/chat/completions"))
+        .and(header("authorization", "Bearer test-key"))
+        .and(body_partial_json(serde_json::json!({ "model": "test-model" })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "choices": [{
+                "message": {
+                    "role": "assistant",
+                    "content": "```json\n{\"title\": \"Hello, Fixtures\", \"description\": \"Recorded.\", \"tags\": [\"Testing\", \"rust\"]}\n```"
+                }
+            }]
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+    let client = LlmClient::new(
+        format!("{}/v1/chat/completions", server.uri()),
+        "test-model".to_string(),
+    )
+    .with_api_key("test-key".to_string());
+
+    let suggestions = suggest_metadata(&client, &test_article()).await.unwrap();
+
+    assert_eq!(suggestions.title.as_deref(), Some("Hello, Fixtures"));
+    assert_eq!(suggestions.description.as_deref(), Some("Recorded."));
+    assert_eq!(suggestions.tags, vec!["testing", "rust"]);
+}